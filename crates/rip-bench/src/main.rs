@@ -10,12 +10,13 @@ use std::time::Instant;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(not(test))]
-use rip_kernel::{Event, EventKind, ProviderEventStatus};
+use rip_kernel::{Event, EventKind, ProviderEventBuilder, ProviderEventStatus};
 #[cfg(not(test))]
 use rip_log::write_snapshot;
 #[cfg(not(test))]
 use rip_provider_openresponses::{
-    CreateResponseBuilder, ItemParam, ParsedEvent, ParsedEventKind, SseDecoder,
+    warm_validators, CreateResponseBuilder, ItemParam, ParsedEvent, ParsedEventKind, SseDecoder,
+    ValidationOptions,
 };
 #[cfg(not(test))]
 use rip_tools::{
@@ -93,28 +94,23 @@ fn emit(session_id: &str, seq: &mut u64, kind: EventKind) -> Event {
 
 #[cfg(not(test))]
 fn map_openresponses_event(session_id: &str, seq: &mut u64, parsed: &ParsedEvent) -> Vec<Event> {
-    let (status, data, raw) = match parsed.kind {
-        ParsedEventKind::Done => (ProviderEventStatus::Done, None, Some(parsed.raw.clone())),
-        ParsedEventKind::InvalidJson => (
-            ProviderEventStatus::InvalidJson,
-            None,
-            Some(parsed.raw.clone()),
-        ),
-        ParsedEventKind::Event => (ProviderEventStatus::Event, parsed.data.clone(), None),
+    let status = match parsed.kind {
+        ParsedEventKind::Done => ProviderEventStatus::Done,
+        ParsedEventKind::InvalidJson => ProviderEventStatus::InvalidJson,
+        ParsedEventKind::Event => ProviderEventStatus::Event,
     };
+    let data = (status == ProviderEventStatus::Event)
+        .then(|| parsed.data.clone())
+        .flatten();
 
     let mut events = vec![emit(
         session_id,
         seq,
-        EventKind::ProviderEvent {
-            provider: "openresponses".to_string(),
-            status,
-            event_name: parsed.event.clone(),
-            data: data.clone(),
-            raw,
-            errors: parsed.errors.clone(),
-            response_errors: parsed.response_errors.clone(),
-        },
+        ProviderEventBuilder::new("openresponses", status)
+            .frame(parsed.event.clone(), data.clone(), parsed.raw.clone())
+            .errors(parsed.errors.clone())
+            .response_errors(parsed.response_errors.clone())
+            .build(),
     )];
 
     if let Some(delta) = data
@@ -131,7 +127,15 @@ fn map_openresponses_event(session_id: &str, seq: &mut u64, parsed: &ParsedEvent
                 .map(|value| value.to_string())
         })
     {
-        events.push(emit(session_id, seq, EventKind::OutputTextDelta { delta }));
+        events.push(emit(
+            session_id,
+            seq,
+            EventKind::OutputTextDelta {
+                delta,
+                actor_id: None,
+                origin: None,
+            },
+        ));
     }
 
     events
@@ -202,60 +206,16 @@ fn observe_function_call_done(parsed: &ParsedEvent) -> Option<FunctionCallItem>
 }
 
 #[cfg(not(test))]
-fn tool_events_to_function_call_output(tool_name: &str, events: &[Event]) -> serde_json::Value {
-    let mut stdout = String::new();
-    let mut stderr = String::new();
-    let mut exit_code: i32 = 1;
-    let mut artifacts: Option<serde_json::Value> = None;
-    let mut tool_error: Option<String> = None;
-
-    for event in events {
-        match &event.kind {
-            EventKind::ToolStdout { chunk, .. } => stdout.push_str(chunk),
-            EventKind::ToolStderr { chunk, .. } => stderr.push_str(chunk),
-            EventKind::ToolEnded {
-                exit_code: code,
-                artifacts: tool_artifacts,
-                ..
-            } => {
-                exit_code = *code;
-                artifacts = tool_artifacts.clone();
-            }
-            EventKind::ToolFailed { error, .. } => tool_error = Some(error.clone()),
-            _ => {}
-        }
-    }
-
-    let ok = exit_code == 0 && tool_error.is_none();
-    let mut obj = serde_json::Map::new();
-    obj.insert(
-        "tool".to_string(),
-        serde_json::Value::String(tool_name.to_string()),
-    );
-    obj.insert("ok".to_string(), serde_json::Value::Bool(ok));
-    obj.insert(
-        "exit_code".to_string(),
-        serde_json::Value::Number(serde_json::Number::from(exit_code as i64)),
-    );
-    obj.insert("stdout".to_string(), serde_json::Value::String(stdout));
-    obj.insert("stderr".to_string(), serde_json::Value::String(stderr));
-    if let Some(artifacts) = artifacts {
-        obj.insert("artifacts".to_string(), artifacts);
-    }
-    if let Some(error) = tool_error {
-        obj.insert("error".to_string(), serde_json::Value::String(error));
-    }
-    serde_json::Value::Object(obj)
-}
-
-#[cfg(not(test))]
-fn bench_sse_parse_us_per_event() -> std::io::Result<BenchResult> {
+fn bench_sse_parse_us_per_event(
+    id: &'static str,
+    validation: ValidationOptions,
+) -> std::io::Result<BenchResult> {
     let sse_path = PathBuf::from("fixtures/openresponses/stream_all.sse");
     let payload = fs::read_to_string(&sse_path)?;
 
     // Warm schema caches.
     {
-        let mut decoder = SseDecoder::new();
+        let mut decoder = SseDecoder::new_with_validation(validation);
         let _ = decoder.push(&payload);
         let _ = decoder.finish();
     }
@@ -263,7 +223,7 @@ fn bench_sse_parse_us_per_event() -> std::io::Result<BenchResult> {
     let mut samples = Vec::new();
     let iterations = 200usize;
     for _ in 0..iterations {
-        let mut decoder = SseDecoder::new();
+        let mut decoder = SseDecoder::new_with_validation(validation);
         let start = Instant::now();
         let events = decoder.push(&payload);
         let _ = decoder.finish();
@@ -273,7 +233,7 @@ fn bench_sse_parse_us_per_event() -> std::io::Result<BenchResult> {
     }
 
     Ok(BenchResult {
-        id: "sse_parse_us_per_event",
+        id,
         value: median(samples),
         unit: "us/event",
     })
@@ -507,18 +467,16 @@ async fn bench_e2e_loop_us() -> std::io::Result<BenchResult> {
             )
             .await;
         events.extend(tool_events.clone());
-        let output_value = tool_events_to_function_call_output(&call.name, &tool_events);
-        let output_json =
-            serde_json::to_string(&output_value).unwrap_or_else(|_| "{\"ok\":false}".to_string());
         let followup_payload = CreateResponseBuilder::new()
             .model("fixture-model")
             .insert_raw(
                 "previous_response_id",
                 serde_json::Value::String(response_id.unwrap_or_else(|| "resp_1".to_string())),
             )
-            .input_items(vec![ItemParam::function_call_output(
+            .input_items(vec![ItemParam::function_call_output_from_tool(
                 call.call_id,
-                serde_json::Value::String(output_json),
+                &call.name,
+                &tool_events,
             )])
             .insert_raw("stream", serde_json::Value::Bool(true))
             .build();
@@ -595,18 +553,16 @@ async fn bench_e2e_loop_us() -> std::io::Result<BenchResult> {
             .await;
         events.extend(tool_events.clone());
 
-        let output_value = tool_events_to_function_call_output(&call.name, &tool_events);
-        let output_json =
-            serde_json::to_string(&output_value).unwrap_or_else(|_| "{\"ok\":false}".to_string());
         let followup_payload = CreateResponseBuilder::new()
             .model("fixture-model")
             .insert_raw(
                 "previous_response_id",
                 serde_json::Value::String(response_id.unwrap_or_else(|| "resp_1".to_string())),
             )
-            .input_items(vec![ItemParam::function_call_output(
+            .input_items(vec![ItemParam::function_call_output_from_tool(
                 call.call_id,
-                serde_json::Value::String(output_json),
+                &call.name,
+                &tool_events,
             )])
             .insert_raw("stream", serde_json::Value::Bool(true))
             .build();
@@ -635,7 +591,7 @@ async fn bench_e2e_loop_us() -> std::io::Result<BenchResult> {
 #[cfg(not(test))]
 fn print_results(results: &[BenchResult], budgets: &[BudgetEntry]) {
     println!(
-        "{:<28} {:>12} {:>10} {:>12}",
+        "{:<36} {:>12} {:>10} {:>12}",
         "id", "value", "unit", "budget"
     );
     for result in results {
@@ -643,7 +599,7 @@ fn print_results(results: &[BenchResult], budgets: &[BudgetEntry]) {
             .map(|b| format!("{b:.0}"))
             .unwrap_or_else(|| "-".to_string());
         println!(
-            "{:<28} {:>12.0} {:>10} {:>12}",
+            "{:<36} {:>12.0} {:>10} {:>12}",
             result.id, result.value, result.unit, budget
         );
     }
@@ -688,10 +644,18 @@ fn parse_budgets_path() -> PathBuf {
 async fn main() -> std::io::Result<()> {
     let budgets_path = parse_budgets_path();
     let budgets = load_budgets(&budgets_path)?;
+    warm_validators();
 
     let start = Instant::now();
     let mut results = Vec::new();
-    results.push(bench_sse_parse_us_per_event()?);
+    results.push(bench_sse_parse_us_per_event(
+        "sse_parse_us_per_event",
+        ValidationOptions::default(),
+    )?);
+    results.push(bench_sse_parse_us_per_event(
+        "sse_parse_terminal_only_us_per_event",
+        ValidationOptions::default().with_terminal_response_validation_only(),
+    )?);
     results.push(bench_ttft_overhead_us());
     results.push(bench_tool_runner_noop_us().await);
     results.push(bench_workspace_apply_patch_us());
@@ -0,0 +1,90 @@
+use std::path::{Path, PathBuf};
+
+use clap::Subcommand;
+use reqwest::Client;
+use serde_json::Value;
+
+#[derive(Subcommand)]
+pub(crate) enum CheckpointsCommand {
+    /// Write every checkpoint of a session (metadata + file bodies) to a tar archive.
+    Export {
+        /// Session id the checkpoints were recorded for.
+        #[arg(long)]
+        session: String,
+        /// Destination tar file.
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Restore checkpoints from an archive written by `export` and print them as JSON.
+    Import {
+        /// Session id to restore the checkpoints under.
+        #[arg(long)]
+        session: String,
+        /// Source tar file.
+        #[arg(long = "in")]
+        input: PathBuf,
+    },
+}
+
+pub(crate) async fn run_checkpoints(
+    server: Option<String>,
+    command: CheckpointsCommand,
+) -> anyhow::Result<()> {
+    let server = match server {
+        Some(server) => server,
+        None => crate::local_authority::ensure_local_authority().await?,
+    };
+    let client = Client::new();
+    match command {
+        CheckpointsCommand::Export { session, out } => {
+            export_checkpoints(&client, &server, &session, &out).await
+        }
+        CheckpointsCommand::Import { session, input } => {
+            let value = import_checkpoints(&client, &server, &session, &input).await?;
+            println!("{}", serde_json::to_string_pretty(&value)?);
+            Ok(())
+        }
+    }
+}
+
+async fn export_checkpoints(
+    client: &Client,
+    server: &str,
+    session_id: &str,
+    out: &Path,
+) -> anyhow::Result<()> {
+    let url = format!("{server}/sessions/{session_id}/checkpoints/export");
+    let response = client.get(url).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("checkpoints export failed: {status}: {body}");
+    }
+    let archive = response.bytes().await?;
+    std::fs::write(out, &archive)
+        .map_err(|err| anyhow::anyhow!("failed to write {}: {err}", out.display()))?;
+    Ok(())
+}
+
+async fn import_checkpoints(
+    client: &Client,
+    server: &str,
+    session_id: &str,
+    input: &Path,
+) -> anyhow::Result<Value> {
+    let archive = std::fs::read(input)
+        .map_err(|err| anyhow::anyhow!("failed to read {}: {err}", input.display()))?;
+    let url = format!("{server}/sessions/{session_id}/checkpoints/import");
+    let response = client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/x-tar")
+        .body(archive)
+        .send()
+        .await?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("checkpoints import failed: {status}: {body}");
+    }
+    Ok(response.json().await?)
+}
@@ -25,9 +25,26 @@ pub(crate) fn default_workspace_root() -> PathBuf {
     std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
 }
 
+/// Resolves `rip run --workspace`, falling back to [`default_workspace_root`].
+pub(crate) fn resolve_workspace_root(workspace: Option<PathBuf>) -> anyhow::Result<PathBuf> {
+    let Some(workspace) = workspace else {
+        return Ok(default_workspace_root());
+    };
+    if !workspace.is_dir() {
+        anyhow::bail!(
+            "workspace {} is not an existing directory",
+            workspace.display()
+        );
+    }
+    Ok(std::path::absolute(&workspace)?)
+}
+
 pub(crate) async fn ensure_local_authority() -> anyhow::Result<String> {
+    ensure_local_authority_in(default_workspace_root()).await
+}
+
+pub(crate) async fn ensure_local_authority_in(workspace_root: PathBuf) -> anyhow::Result<String> {
     let data_dir = default_data_dir();
-    let workspace_root = default_workspace_root();
     ensure_local_authority_with_paths(data_dir, workspace_root).await
 }
 
@@ -12,9 +12,12 @@ mod tests;
 #[path = "main/run.rs"]
 mod run_impl;
 
+mod checkpoints;
 mod fullscreen;
 mod local_authority;
 mod metrics;
+mod prompt_template;
+mod responses;
 mod tasks_watch;
 #[cfg(test)]
 mod test_env;
@@ -42,7 +45,14 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     Run {
-        prompt: String,
+        #[arg(required_unless_present = "template")]
+        prompt: Option<String>,
+        /// Build the prompt from `<config dir>/templates/<name>.md` instead of `<prompt>`.
+        #[arg(long, conflicts_with = "prompt")]
+        template: Option<String>,
+        /// Fill a `{{key}}` template placeholder (`key=value`; repeatable).
+        #[arg(long = "var", requires = "template", conflicts_with = "prompt")]
+        vars: Vec<String>,
         #[arg(long)]
         server: Option<String>,
         #[arg(long, action = clap::ArgAction::SetTrue)]
@@ -80,6 +90,19 @@ enum Commands {
         headless: bool,
         #[arg(long, value_enum, default_value_t = OutputView::Output)]
         view: OutputView,
+        /// With `--view output`, print only the final assistant text (errors go to stderr).
+        /// Rejected with any other view.
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        quiet: bool,
+        /// Print a one-line JSON run summary to stderr once the session ends.
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        summary: bool,
+        /// Workspace root for this run (overrides `RIP_WORKSPACE_ROOT`; must be an existing directory).
+        #[arg(long, conflicts_with = "server")]
+        workspace: Option<std::path::PathBuf>,
+        /// Copy each provider request body (JSON, no headers) to this path as the run reports it.
+        #[arg(long, conflicts_with = "server")]
+        dump_request: Option<std::path::PathBuf>,
     },
     Serve,
     Tasks {
@@ -89,12 +112,22 @@ enum Commands {
         #[command(subcommand)]
         command: TaskCommand,
     },
+    #[command(alias = "continuities")]
     Threads {
-        #[arg(long)]
+        /// Server base URL for remote mode; accepted before or after the thread subcommand.
+        #[arg(long, global = true)]
         server: Option<String>,
         #[command(subcommand)]
         command: threads::ThreadsCommand,
     },
+    /// Export/import a session's checkpoints as a tar archive.
+    Checkpoints {
+        /// Server base URL for remote mode. If omitted, auto-start/auto-attach the local authority.
+        #[arg(long)]
+        server: Option<String>,
+        #[command(subcommand)]
+        command: checkpoints::CheckpointsCommand,
+    },
     Config {
         /// Server base URL for remote mode. If omitted, auto-start/auto-attach the local authority.
         #[arg(long)]
@@ -102,6 +135,11 @@ enum Commands {
         #[command(subcommand)]
         command: ConfigCommand,
     },
+    /// Offline OpenResponses contract tooling (no authority needed).
+    Responses {
+        #[command(subcommand)]
+        command: responses::ResponsesCommand,
+    },
 }
 
 #[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
@@ -111,8 +149,29 @@ enum OutputView {
     Metrics,
 }
 
+/// How `rip run` renders a session's frames.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct OutputOptions {
+    view: OutputView,
+    quiet: bool,
+    summary: bool,
+    dump_request: Option<RequestDumpTarget>,
+}
+
+/// `rip run --dump-request`: the authority stores each request body under
+/// `blobs_dir` and the CLI copies it to `path`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct RequestDumpTarget {
+    blobs_dir: std::path::PathBuf,
+    path: std::path::PathBuf,
+}
+
 #[derive(Default)]
 struct OutputState {
+    quiet: bool,
+    summary: bool,
+    summary_frames: Vec<FrameEvent>,
+    quiet_output: String,
     saw_output: bool,
     trailing_newline: bool,
     tool_stdout: String,
@@ -122,6 +181,18 @@ struct OutputState {
     provider_response_errors: Vec<String>,
     provider_invalid_json: Vec<String>,
     metrics: metrics::RunMetrics,
+    dump_request: Option<RequestDumpTarget>,
+}
+
+impl OutputState {
+    fn new(options: &OutputOptions) -> Self {
+        Self {
+            quiet: options.quiet,
+            summary: options.summary,
+            dump_request: options.dump_request.clone(),
+            ..Self::default()
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
@@ -221,6 +292,10 @@ enum TaskCommand {
         #[arg(long, requires = "text")]
         no_newline: bool,
     },
+    /// Close the task's stdin so the child reads EOF.
+    StdinClose {
+        id: String,
+    },
     Resize {
         id: String,
         #[arg(long)]
@@ -309,6 +384,8 @@ async fn run(cli: Cli) -> anyhow::Result<()> {
         },
         Some(Commands::Run {
             prompt,
+            template,
+            vars,
             server,
             detach,
             provider,
@@ -325,7 +402,18 @@ async fn run(cli: Cli) -> anyhow::Result<()> {
             reasoning_summary,
             headless,
             view,
+            quiet,
+            summary,
+            workspace,
+            dump_request,
         }) => {
+            if quiet && view != OutputView::Output {
+                anyhow::bail!("--quiet only applies to --view output");
+            }
+            let prompt = match template {
+                Some(name) => prompt_template::resolve_template_prompt(&name, &vars)?,
+                None => prompt.unwrap_or_default(),
+            };
             let has_openresponses_flags = provider.is_some()
                 || model.is_some()
                 || stateless_history
@@ -380,12 +468,22 @@ async fn run(cli: Cli) -> anyhow::Result<()> {
             } else {
                 None
             };
+            let openresponses_overrides = match dump_request {
+                Some(_) => Some(insert_dump_request_override(openresponses_overrides)),
+                None => openresponses_overrides,
+            };
+            let mut output = OutputOptions {
+                view,
+                quiet,
+                summary,
+                dump_request: None,
+            };
             if let Some(server) = server {
                 if headless {
                     run_impl::run_headless_remote(
                         prompt,
                         server,
-                        view,
+                        output,
                         openresponses_overrides,
                         detach,
                     )
@@ -394,30 +492,37 @@ async fn run(cli: Cli) -> anyhow::Result<()> {
                     run_impl::run_interactive_remote(
                         prompt,
                         server,
-                        view,
+                        output,
                         openresponses_overrides,
                         detach,
                     )
                     .await?;
                 }
             } else {
+                let workspace_root = local_authority::resolve_workspace_root(workspace)?;
+                output.dump_request = dump_request.map(|path| RequestDumpTarget {
+                    blobs_dir: workspace_root.join(".rip").join("artifacts").join("blobs"),
+                    path,
+                });
                 #[cfg(test)]
                 {
                     let _openresponses_overrides = openresponses_overrides;
                     if headless {
-                        run_impl::run_headless_local(prompt, view, detach).await?;
+                        run_impl::run_headless_local(prompt, workspace_root, output, detach)
+                            .await?;
                     } else {
-                        run_impl::run_interactive_local(prompt, view, detach).await?;
+                        run_impl::run_interactive_local(prompt, workspace_root, output, detach)
+                            .await?;
                     }
                 }
                 #[cfg(not(test))]
                 {
-                    let server = local_authority::ensure_local_authority().await?;
+                    let server = local_authority::ensure_local_authority_in(workspace_root).await?;
                     if headless {
                         run_impl::run_headless_remote(
                             prompt,
                             server,
-                            view,
+                            output,
                             openresponses_overrides,
                             detach,
                         )
@@ -426,7 +531,7 @@ async fn run(cli: Cli) -> anyhow::Result<()> {
                         run_impl::run_interactive_remote(
                             prompt,
                             server,
-                            view,
+                            output,
                             openresponses_overrides,
                             detach,
                         )
@@ -536,6 +641,14 @@ async fn run(cli: Cli) -> anyhow::Result<()> {
                         anyhow::bail!("task stdin failed: {status}");
                     }
                 }
+                TaskCommand::StdinClose { id } => {
+                    let url = format!("{server}/tasks/{id}/stdin/close");
+                    let response = client.post(url).send().await?;
+                    let status = response.status();
+                    if !status.is_success() {
+                        anyhow::bail!("task stdin close failed: {status}");
+                    }
+                }
                 TaskCommand::Resize { id, rows, cols } => {
                     let url = format!("{server}/tasks/{id}/resize");
                     let response = client
@@ -622,6 +735,12 @@ async fn run(cli: Cli) -> anyhow::Result<()> {
         Some(Commands::Threads { server, command }) => {
             threads::run_threads(server, command).await?;
         }
+        Some(Commands::Checkpoints { server, command }) => {
+            checkpoints::run_checkpoints(server, command).await?;
+        }
+        Some(Commands::Responses { command }) => {
+            responses::run_responses(command)?;
+        }
         Some(Commands::Config { server, command }) => {
             let server = match server {
                 Some(server) => server,
@@ -825,6 +944,17 @@ fn insert_reasoning_overrides(
     }
 }
 
+/// Asks the authority to store this run's request bodies as workspace
+/// artifacts; the CLI copies them out (see [`RequestDumpTarget`]).
+fn insert_dump_request_override(overrides: Option<Value>) -> Value {
+    let mut obj = match overrides {
+        Some(Value::Object(obj)) => obj,
+        _ => serde_json::Map::new(),
+    };
+    obj.insert("dump_request".to_string(), Value::Bool(true));
+    Value::Object(obj)
+}
+
 fn insert_include_overrides(
     obj: &mut serde_json::Map<String, Value>,
     include: &[String],
@@ -18,27 +18,27 @@ pub(super) struct DetachedRunInfo {
 pub(super) async fn run_headless_remote(
     prompt: String,
     server: String,
-    view: OutputView,
+    output: OutputOptions,
     openresponses_overrides: Option<Value>,
     detach: bool,
 ) -> anyhow::Result<()> {
-    run_remote(prompt, server, view, openresponses_overrides, detach).await
+    run_remote(prompt, server, output, openresponses_overrides, detach).await
 }
 
 pub(super) async fn run_interactive_remote(
     prompt: String,
     server: String,
-    view: OutputView,
+    output: OutputOptions,
     openresponses_overrides: Option<Value>,
     detach: bool,
 ) -> anyhow::Result<()> {
-    run_remote(prompt, server, view, openresponses_overrides, detach).await
+    run_remote(prompt, server, output, openresponses_overrides, detach).await
 }
 
 async fn run_remote(
     prompt: String,
     server: String,
-    view: OutputView,
+    output: OutputOptions,
     openresponses_overrides: Option<Value>,
     detach: bool,
 ) -> anyhow::Result<()> {
@@ -67,37 +67,39 @@ async fn run_remote(
                 response.session_id
             )),
         };
-        render_detached_run(view, &mut handle, &detached)?;
+        render_detached_run(output.view, &mut handle, &detached)?;
         return Ok(());
     }
-    stream_events(&client, &server, &response.session_id, view).await?;
+    stream_events(&client, &server, &response.session_id, output).await?;
     Ok(())
 }
 
 #[cfg(test)]
 pub(super) async fn run_headless_local(
     prompt: String,
-    view: OutputView,
+    workspace_root: std::path::PathBuf,
+    output: OutputOptions,
     detach: bool,
 ) -> anyhow::Result<()> {
-    let engine =
-        ripd::SessionEngine::new_default().map_err(|err| anyhow::anyhow!("engine init: {err}"))?;
+    let engine = ripd::SessionEngine::new_default_with_workspace_root(workspace_root)
+        .map_err(|err| anyhow::anyhow!("engine init: {err}"))?;
     let stdout = io::stdout();
     let mut handle = stdout.lock();
     if detach {
-        run_local_detached_with_engine(&engine, prompt, view, &mut handle).await
+        run_local_detached_with_engine(&engine, prompt, output.view, &mut handle).await
     } else {
-        run_local_with_engine(&engine, prompt, view, &mut handle).await
+        run_local_with_engine(&engine, prompt, output, &mut handle).await
     }
 }
 
 #[cfg(test)]
 pub(super) async fn run_interactive_local(
     prompt: String,
-    view: OutputView,
+    workspace_root: std::path::PathBuf,
+    output: OutputOptions,
     detach: bool,
 ) -> anyhow::Result<()> {
-    run_headless_local(prompt, view, detach).await
+    run_headless_local(prompt, workspace_root, output, detach).await
 }
 
 pub(super) async fn ensure_thread(client: &Client, server: &str) -> anyhow::Result<String> {
@@ -141,27 +143,27 @@ pub(super) async fn stream_events(
     client: &Client,
     server: &str,
     session_id: &str,
-    view: OutputView,
+    output: OutputOptions,
 ) -> anyhow::Result<()> {
     let url = format!("{server}/sessions/{session_id}/events");
     let mut stream = client.get(url).eventsource()?;
     let stdout = io::stdout();
     let mut handle = stdout.lock();
 
-    stream_events_with_writer(&mut stream, view, &mut handle).await
+    stream_events_with_writer(&mut stream, output, &mut handle).await
 }
 
 pub(super) async fn stream_events_with_writer(
     stream: &mut (impl futures_util::Stream<Item = Result<Event, EventSourceError>> + Unpin),
-    view: OutputView,
+    output: OutputOptions,
     out: &mut dyn Write,
 ) -> anyhow::Result<()> {
-    let mut state = OutputState::default();
+    let mut state = OutputState::new(&output);
     while let Some(next) = stream.next().await {
         match next {
             Ok(Event::Open) => {}
             Ok(Event::Message(msg)) => {
-                let should_stop = render_message(view, &msg.data, out, &mut state)?;
+                let should_stop = render_message(output.view, &msg.data, out, &mut state)?;
                 if should_stop {
                     break;
                 }
@@ -178,7 +180,7 @@ pub(super) async fn stream_events_with_writer(
 pub(super) async fn run_local_with_engine(
     engine: &ripd::SessionEngine,
     prompt: String,
-    view: OutputView,
+    output: OutputOptions,
     out: &mut dyn Write,
 ) -> anyhow::Result<()> {
     let continuities = engine.continuities();
@@ -214,7 +216,7 @@ pub(super) async fn run_local_with_engine(
         .map_err(|err| anyhow::anyhow!("continuity run spawned: {err}"))?;
     let mut receiver = handle.subscribe();
     engine.spawn_session(handle, prompt, Some(run_link), None);
-    stream_events_from_receiver(&mut receiver, view, out).await
+    stream_events_from_receiver(&mut receiver, output, out).await
 }
 
 #[cfg(test)]
@@ -264,16 +266,16 @@ pub(super) async fn run_local_detached_with_engine(
 #[cfg(test)]
 pub(super) async fn stream_events_from_receiver(
     receiver: &mut broadcast::Receiver<FrameEvent>,
-    view: OutputView,
+    output: OutputOptions,
     out: &mut dyn Write,
 ) -> anyhow::Result<()> {
-    let mut state = OutputState::default();
+    let mut state = OutputState::new(&output);
     loop {
         match receiver.recv().await {
             Ok(frame) => {
                 let payload = serde_json::to_string(&frame)
                     .map_err(|err| anyhow::anyhow!("event frame json: {err}"))?;
-                let should_stop = render_message(view, &payload, out, &mut state)?;
+                let should_stop = render_message(output.view, &payload, out, &mut state)?;
                 if should_stop {
                     break;
                 }
@@ -295,35 +297,41 @@ pub(super) fn render_message(
         .map_err(|err| anyhow::anyhow!("invalid event frame: {err}"))?;
     state.metrics.observe(&frame);
     let should_stop = matches!(frame.kind, EventKind::SessionEnded { .. });
+    if let (
+        Some(target),
+        EventKind::OpenResponsesRequest {
+            body_artifact_id, ..
+        },
+    ) = (state.dump_request.as_ref(), &frame.kind)
+    {
+        copy_request_dump(target, body_artifact_id)?;
+    }
 
     match view {
         OutputView::Raw => {
             writeln!(out, "{payload}")?;
             out.flush()?;
         }
+        OutputView::Output if state.quiet => {
+            render_quiet_output(&frame.kind, should_stop, out, state)?;
+        }
         OutputView::Output => {
             match &frame.kind {
-                EventKind::OutputTextDelta { delta } => {
+                EventKind::OutputTextDelta { delta, .. } => {
                     state.saw_output = true;
                     write!(out, "{delta}")?;
                     if let Some(last) = delta.chars().last() {
                         state.trailing_newline = last == '\n';
                     }
                 }
-                EventKind::ToolStdout { chunk, .. } => {
-                    if !state.saw_output {
-                        state.tool_stdout.push_str(chunk);
-                    }
+                EventKind::ToolStdout { chunk, .. } if !state.saw_output => {
+                    state.tool_stdout.push_str(chunk);
                 }
-                EventKind::ToolStderr { chunk, .. } => {
-                    if !state.saw_output {
-                        state.tool_stderr.push_str(chunk);
-                    }
+                EventKind::ToolStderr { chunk, .. } if !state.saw_output => {
+                    state.tool_stderr.push_str(chunk);
                 }
-                EventKind::ToolFailed { error, .. } => {
-                    if !state.saw_output {
-                        state.tool_failed.push(error.clone());
-                    }
+                EventKind::ToolFailed { error, .. } if !state.saw_output => {
+                    state.tool_failed.push(error.clone());
                 }
                 EventKind::ProviderEvent {
                     status,
@@ -331,20 +339,18 @@ pub(super) fn render_message(
                     response_errors,
                     raw,
                     ..
-                } => {
-                    if !state.saw_output {
-                        if !errors.is_empty() {
-                            state.provider_errors.extend(errors.iter().cloned());
-                        }
-                        if !response_errors.is_empty() {
-                            state
-                                .provider_response_errors
-                                .extend(response_errors.iter().cloned());
-                        }
-                        if *status == rip_kernel::ProviderEventStatus::InvalidJson {
-                            if let Some(raw) = raw.as_deref() {
-                                state.provider_invalid_json.push(raw.to_string());
-                            }
+                } if !state.saw_output => {
+                    if !errors.is_empty() {
+                        state.provider_errors.extend(errors.iter().cloned());
+                    }
+                    if !response_errors.is_empty() {
+                        state
+                            .provider_response_errors
+                            .extend(response_errors.iter().cloned());
+                    }
+                    if *status == rip_kernel::ProviderEventStatus::InvalidJson {
+                        if let Some(raw) = raw.as_deref() {
+                            state.provider_invalid_json.push(raw.to_string());
                         }
                     }
                 }
@@ -469,9 +475,129 @@ pub(super) fn render_message(
             out.flush()?;
         }
     }
+
+    if state.summary {
+        state.summary_frames.push(frame);
+        if should_stop {
+            render_run_summary(&state.summary_frames)?;
+        }
+    }
     Ok(should_stop)
 }
 
+fn render_quiet_output(
+    kind: &EventKind,
+    should_stop: bool,
+    out: &mut dyn Write,
+    state: &mut OutputState,
+) -> anyhow::Result<()> {
+    match kind {
+        EventKind::OutputTextDelta { delta, .. } => state.quiet_output.push_str(delta),
+        EventKind::ToolFailed { error, .. } => state.tool_failed.push(error.clone()),
+        EventKind::ProviderEvent {
+            status,
+            errors,
+            response_errors,
+            raw,
+            ..
+        } => {
+            state.provider_errors.extend(errors.iter().cloned());
+            state
+                .provider_response_errors
+                .extend(response_errors.iter().cloned());
+            if *status == rip_kernel::ProviderEventStatus::InvalidJson {
+                if let Some(raw) = raw.as_deref() {
+                    state.provider_invalid_json.push(raw.to_string());
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if !should_stop {
+        return Ok(());
+    }
+
+    let stderr = io::stderr();
+    let mut err = stderr.lock();
+    for error in &state.tool_failed {
+        writeln!(err, "tool_failed: {error}")?;
+    }
+    if !state.provider_errors.is_empty() {
+        writeln!(err, "provider_errors: {}", state.provider_errors.join("; "))?;
+    }
+    if !state.provider_response_errors.is_empty() {
+        writeln!(
+            err,
+            "provider_response_errors: {}",
+            state.provider_response_errors.join("; ")
+        )?;
+    }
+    for raw in &state.provider_invalid_json {
+        writeln!(err, "provider_invalid_json: {raw}")?;
+    }
+
+    if !state.quiet_output.is_empty() {
+        write!(out, "{}", state.quiet_output)?;
+        if !state.quiet_output.ends_with('\n') {
+            writeln!(out)?;
+        }
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Later requests in a run (tool follow-ups) overwrite earlier ones.
+fn copy_request_dump(target: &RequestDumpTarget, artifact_id: &str) -> anyhow::Result<()> {
+    let body = std::fs::read(target.blobs_dir.join(artifact_id))
+        .map_err(|err| anyhow::anyhow!("request dump read {artifact_id}: {err}"))?;
+    if let Some(parent) = target
+        .path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&target.path, body)
+        .map_err(|err| anyhow::anyhow!("request dump write {}: {err}", target.path.display()))
+}
+
+/// One-line digest printed to stderr by `rip run --summary`.
+#[derive(serde::Serialize)]
+struct RunSummary {
+    session_id: String,
+    output_len: usize,
+    tool_calls: usize,
+    errors: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_reason: Option<String>,
+    duration_ms: u64,
+}
+
+impl RunSummary {
+    fn from_frames(frames: &[FrameEvent]) -> Self {
+        let summary = rip_kernel::SessionSummary::from_events(frames);
+        Self {
+            session_id: summary.session_id,
+            output_len: summary.output_text.chars().count(),
+            tool_calls: summary.tool_calls.len(),
+            errors: summary.errors,
+            end_reason: summary.end_reason,
+            duration_ms: summary.duration_ms,
+        }
+    }
+}
+
+fn render_run_summary(frames: &[FrameEvent]) -> anyhow::Result<()> {
+    let payload = serde_json::to_string(&RunSummary::from_frames(frames))
+        .map_err(|err| anyhow::anyhow!("run summary json: {err}"))?;
+    let stderr = io::stderr();
+    let mut err = stderr.lock();
+    writeln!(err, "{payload}")?;
+    err.flush()?;
+    Ok(())
+}
+
 pub(super) fn render_detached_run(
     view: OutputView,
     out: &mut dyn Write,
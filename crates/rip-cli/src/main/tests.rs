@@ -8,6 +8,15 @@ use httpmock::Method::{GET, POST};
 use httpmock::MockServer;
 use std::sync::{Mutex, OnceLock};
 
+fn output_options(view: OutputView) -> OutputOptions {
+    OutputOptions {
+        view,
+        quiet: false,
+        summary: false,
+        dump_request: None,
+    }
+}
+
 fn fixture_path(rel: &str) -> std::path::PathBuf {
     std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(rel)
 }
@@ -229,7 +238,13 @@ async fn stream_events_reads_messages() {
             .body(format!("data: {payload}\n\n"));
     });
     let client = Client::new();
-    let result = stream_events(&client, &server.base_url(), "s1", OutputView::Raw).await;
+    let result = stream_events(
+        &client,
+        &server.base_url(),
+        "s1",
+        output_options(OutputView::Raw),
+    )
+    .await;
     assert!(result.is_ok());
 }
 
@@ -288,7 +303,7 @@ async fn stream_events_renders_output_view() {
     let url = format!("{}/sessions/s1/events", server.base_url());
     let mut stream = client.get(url).eventsource().unwrap();
     let mut buffer = Vec::new();
-    stream_events_with_writer(&mut stream, OutputView::Output, &mut buffer)
+    stream_events_with_writer(&mut stream, output_options(OutputView::Output), &mut buffer)
         .await
         .unwrap();
     let rendered = String::from_utf8(buffer).expect("utf8");
@@ -323,7 +338,9 @@ async fn run_headless_with_interactive_flag() {
         session: None,
         task: None,
         command: Some(Commands::Run {
-            prompt: "hello".to_string(),
+            prompt: Some("hello".to_string()),
+            template: None,
+            vars: Vec::new(),
             server: Some(server.base_url()),
             detach: false,
             provider: None,
@@ -340,6 +357,10 @@ async fn run_headless_with_interactive_flag() {
             web_search_external_web_access: None,
             headless: false,
             view: OutputView::Raw,
+            quiet: false,
+            summary: false,
+            workspace: None,
+            dump_request: None,
         }),
     };
     let result = run(cli).await;
@@ -374,7 +395,9 @@ async fn run_headless_remote() {
         session: None,
         task: None,
         command: Some(Commands::Run {
-            prompt: "hello".to_string(),
+            prompt: Some("hello".to_string()),
+            template: None,
+            vars: Vec::new(),
             server: Some(server.base_url()),
             detach: false,
             provider: None,
@@ -391,6 +414,10 @@ async fn run_headless_remote() {
             web_search_external_web_access: None,
             headless: true,
             view: OutputView::Raw,
+            quiet: false,
+            summary: false,
+            workspace: None,
+            dump_request: None,
         }),
     };
     let result = run(cli).await;
@@ -416,7 +443,7 @@ async fn run_headless_remote_detach_skips_event_stream() {
     let result = run_headless_remote_impl(
         "hello".to_string(),
         server.base_url(),
-        OutputView::Raw,
+        output_options(OutputView::Raw),
         None,
         true,
     )
@@ -449,7 +476,7 @@ async fn run_interactive_remote_smoke() {
     let result = run_interactive_remote(
         "hello".to_string(),
         server.base_url(),
-        OutputView::Raw,
+        output_options(OutputView::Raw),
         None,
         false,
     )
@@ -484,7 +511,7 @@ async fn run_interactive_remote_forwards_openresponses_overrides() {
     let result = run_interactive_remote(
         "hello".to_string(),
         server.base_url(),
-        OutputView::Raw,
+        output_options(OutputView::Raw),
         Some(serde_json::json!({
             "endpoint": "https://openrouter.ai/api/v1/responses",
             "model": "nvidia/nemotron-3-nano-30b-a3b:free",
@@ -512,7 +539,9 @@ async fn run_interactive_local_uses_env_paths() {
             session: None,
             task: None,
             command: Some(Commands::Run {
-                prompt: "hello".to_string(),
+                prompt: Some("hello".to_string()),
+                template: None,
+                vars: Vec::new(),
                 server: None,
                 detach: false,
                 provider: None,
@@ -529,6 +558,10 @@ async fn run_interactive_local_uses_env_paths() {
                 web_search_external_web_access: None,
                 headless: false,
                 view: OutputView::Raw,
+                quiet: false,
+                summary: false,
+                workspace: None,
+                dump_request: None,
             }),
         };
         let result = run(cli).await;
@@ -569,7 +602,9 @@ async fn run_accepts_openresponses_flags_with_server() {
         session: None,
         task: None,
         command: Some(Commands::Run {
-            prompt: "hello".to_string(),
+            prompt: Some("hello".to_string()),
+            template: None,
+            vars: Vec::new(),
             server: Some(server.base_url()),
             detach: false,
             provider: Some(Provider::Openai),
@@ -586,6 +621,10 @@ async fn run_accepts_openresponses_flags_with_server() {
             web_search_external_web_access: None,
             headless: true,
             view: OutputView::Raw,
+            quiet: false,
+            summary: false,
+            workspace: None,
+            dump_request: None,
         }),
     };
     let result = run(cli).await;
@@ -622,7 +661,9 @@ async fn run_allows_model_override_without_provider() {
         session: None,
         task: None,
         command: Some(Commands::Run {
-            prompt: "hello".to_string(),
+            prompt: Some("hello".to_string()),
+            template: None,
+            vars: Vec::new(),
             server: Some(server.base_url()),
             detach: false,
             provider: None,
@@ -639,6 +680,10 @@ async fn run_allows_model_override_without_provider() {
             web_search_external_web_access: None,
             headless: true,
             view: OutputView::Raw,
+            quiet: false,
+            summary: false,
+            workspace: None,
+            dump_request: None,
         }),
     };
     let result = run(cli).await;
@@ -654,13 +699,15 @@ fn cli_parses_run() {
     assert!(cli.task.is_none());
     match cli.command {
         Some(Commands::Run { prompt, server, .. }) => {
-            assert_eq!(prompt, "hello");
+            assert_eq!(prompt.as_deref(), Some("hello"));
             assert!(server.is_none());
         }
         Some(Commands::Serve) => panic!("expected run"),
         Some(Commands::Tasks { .. }) => panic!("expected run"),
         Some(Commands::Threads { .. }) => panic!("expected run"),
         Some(Commands::Config { .. }) => panic!("expected run"),
+        Some(Commands::Responses { .. }) => panic!("expected run"),
+        Some(Commands::Checkpoints { .. }) => panic!("expected run"),
         None => panic!("expected run"),
     }
 }
@@ -674,6 +721,28 @@ fn cli_parses_detach_flag() {
     }
 }
 
+#[test]
+fn cli_parses_quiet_flag() {
+    let cli = Cli::parse_from(["rip", "run", "hello", "--quiet"]);
+    match cli.command {
+        Some(Commands::Run { quiet, .. }) => assert!(quiet),
+        _ => panic!("expected run"),
+    }
+}
+
+#[tokio::test]
+async fn run_rejects_quiet_outside_output_view() {
+    for view in ["raw", "metrics"] {
+        let cli = Cli::parse_from(["rip", "run", "hello", "--quiet", "--view", view]);
+        let err = run(cli).await.expect_err("quiet with non-output view");
+        assert!(
+            err.to_string()
+                .contains("--quiet only applies to --view output"),
+            "{err}"
+        );
+    }
+}
+
 #[test]
 fn cli_defaults_headless() {
     let cli = Cli::parse_from(["rip", "run", "hello"]);
@@ -696,6 +765,8 @@ fn cli_defaults_headless() {
         Some(Commands::Tasks { .. }) => panic!("expected run"),
         Some(Commands::Threads { .. }) => panic!("expected run"),
         Some(Commands::Config { .. }) => panic!("expected run"),
+        Some(Commands::Responses { .. }) => panic!("expected run"),
+        Some(Commands::Checkpoints { .. }) => panic!("expected run"),
         None => panic!("expected run"),
     }
 }
@@ -759,10 +830,98 @@ fn cli_parses_openresponses_flags() {
         Some(Commands::Tasks { .. }) => panic!("expected run"),
         Some(Commands::Threads { .. }) => panic!("expected run"),
         Some(Commands::Config { .. }) => panic!("expected run"),
+        Some(Commands::Responses { .. }) => panic!("expected run"),
+        Some(Commands::Checkpoints { .. }) => panic!("expected run"),
         None => panic!("expected run"),
     }
 }
 
+#[test]
+fn cli_run_template_expands_vars() {
+    let _lock = crate::test_env::lock_env();
+    let saved = capture_env(&["RIP_CONFIG_HOME"]);
+    let temp_root = std::env::temp_dir().join(format!("rip_template_test_{}", std::process::id()));
+    std::fs::create_dir_all(temp_root.join("templates")).expect("templates dir");
+    std::fs::write(
+        temp_root.join("templates").join("review.md"),
+        "Review {{file}} for {{ focus }} issues.",
+    )
+    .expect("template");
+    std::env::set_var("RIP_CONFIG_HOME", &temp_root);
+
+    let cli = Cli::parse_from([
+        "rip",
+        "run",
+        "--template",
+        "review",
+        "--var",
+        "file=src/main.rs",
+        "--var",
+        "focus=concurrency",
+    ]);
+    let resolved = match cli.command {
+        Some(Commands::Run {
+            prompt,
+            template,
+            vars,
+            ..
+        }) => {
+            assert!(prompt.is_none());
+            prompt_template::resolve_template_prompt(&template.expect("template"), &vars)
+        }
+        _ => panic!("expected run"),
+    };
+    let missing = prompt_template::resolve_template_prompt("review", &["file=a.rs".to_string()]);
+
+    restore_env(saved);
+    let _ = std::fs::remove_dir_all(&temp_root);
+    assert_eq!(
+        resolved.expect("resolve"),
+        "Review src/main.rs for concurrency issues."
+    );
+    assert!(missing.expect_err("unfilled").to_string().contains("focus"));
+    assert!(Cli::try_parse_from(["rip", "run"]).is_err());
+    assert!(Cli::try_parse_from(["rip", "run", "hi", "--template", "review"]).is_err());
+    assert!(Cli::try_parse_from(["rip", "run", "hi", "--var", "a=b"]).is_err());
+}
+
+#[test]
+fn cli_parses_continuities_rotate_cursor_with_id_flag() {
+    let cli = Cli::parse_from([
+        "rip",
+        "continuities",
+        "rotate-cursor",
+        "--server",
+        "http://local",
+        "--id",
+        "t1",
+        "--reason",
+        "manual",
+    ]);
+    match cli.command {
+        Some(Commands::Threads {
+            server,
+            command:
+                threads::ThreadsCommand::ProviderCursorRotate {
+                    id,
+                    id_flag,
+                    reason,
+                    ..
+                },
+        }) => {
+            assert_eq!(server.as_deref(), Some("http://local"));
+            assert_eq!(id, None);
+            assert_eq!(id_flag.as_deref(), Some("t1"));
+            assert_eq!(reason.as_deref(), Some("manual"));
+        }
+        _ => panic!("expected threads provider-cursor-rotate"),
+    }
+    assert!(Cli::try_parse_from(["rip", "continuities", "rotate-cursor"]).is_err());
+    assert!(
+        Cli::try_parse_from(["rip", "continuities", "rotate-cursor", "t1", "--id", "t2"]).is_err()
+    );
+}
+
 #[test]
 fn cli_respects_server_flag() {
     let cli = Cli::parse_from(["rip", "run", "hello", "--server", "http://local"]);
@@ -778,6 +937,8 @@ fn cli_respects_server_flag() {
         Some(Commands::Tasks { .. }) => panic!("expected run"),
         Some(Commands::Threads { .. }) => panic!("expected run"),
         Some(Commands::Config { .. }) => panic!("expected run"),
+        Some(Commands::Responses { .. }) => panic!("expected run"),
+        Some(Commands::Checkpoints { .. }) => panic!("expected run"),
         None => panic!("expected run"),
     }
 }
@@ -797,9 +958,14 @@ async fn local_run_emits_frames() {
 
     let engine = ripd::SessionEngine::new(data_dir, workspace_dir, None).expect("engine");
     let mut buffer = Vec::new();
-    run_local_with_engine(&engine, "hello".to_string(), OutputView::Raw, &mut buffer)
-        .await
-        .expect("run");
+    run_local_with_engine(
+        &engine,
+        "hello".to_string(),
+        output_options(OutputView::Raw),
+        &mut buffer,
+    )
+    .await
+    .expect("run");
     let rendered = String::from_utf8(buffer).expect("utf8");
     assert!(rendered.contains("\"type\":\"session_started\""));
     assert!(rendered.contains("\"type\":\"session_ended\""));
@@ -968,6 +1134,66 @@ fn renders_tool_stdout_in_output_view() {
     assert_eq!(rendered.trim_end(), "a.txt");
 }
 
+#[test]
+fn quiet_output_view_prints_only_final_text() {
+    let mut buffer = Vec::new();
+    let mut state = OutputState::new(&OutputOptions {
+        view: OutputView::Output,
+        quiet: true,
+        summary: false,
+        dump_request: None,
+    });
+    let frames = [
+        serde_json::json!({
+            "id": "e1",
+            "session_id": "s1",
+            "timestamp_ms": 0,
+            "seq": 0,
+            "type": "tool_stdout",
+            "tool_id": "t1",
+            "chunk": "a.txt"
+        }),
+        serde_json::json!({
+            "id": "e2",
+            "session_id": "s1",
+            "timestamp_ms": 0,
+            "seq": 1,
+            "type": "output_text_delta",
+            "delta": "hello "
+        }),
+        serde_json::json!({
+            "id": "e3",
+            "session_id": "s1",
+            "timestamp_ms": 0,
+            "seq": 2,
+            "type": "output_text_delta",
+            "delta": "world"
+        }),
+    ];
+    for frame in &frames {
+        render_message(
+            OutputView::Output,
+            &frame.to_string(),
+            &mut buffer,
+            &mut state,
+        )
+        .expect("render");
+        assert!(buffer.is_empty(), "quiet output must wait for session end");
+    }
+    let end_payload = serde_json::json!({
+        "id": "e4",
+        "session_id": "s1",
+        "timestamp_ms": 0,
+        "seq": 3,
+        "type": "session_ended",
+        "reason": "completed"
+    })
+    .to_string();
+    render_message(OutputView::Output, &end_payload, &mut buffer, &mut state).expect("render");
+    let rendered = String::from_utf8(buffer).expect("utf8");
+    assert_eq!(rendered, "hello world\n");
+}
+
 #[test]
 fn renders_trailing_newline_when_missing() {
     let mut buffer = Vec::new();
@@ -1067,6 +1293,8 @@ fn cli_respects_headless_flag() {
         Some(Commands::Tasks { .. }) => panic!("expected run"),
         Some(Commands::Threads { .. }) => panic!("expected run"),
         Some(Commands::Config { .. }) => panic!("expected run"),
+        Some(Commands::Responses { .. }) => panic!("expected run"),
+        Some(Commands::Checkpoints { .. }) => panic!("expected run"),
         None => panic!("expected run"),
     }
 }
@@ -1084,6 +1312,8 @@ fn cli_parses_serve() {
         Some(Commands::Tasks { .. }) => panic!("expected serve"),
         Some(Commands::Threads { .. }) => panic!("expected serve"),
         Some(Commands::Config { .. }) => panic!("expected serve"),
+        Some(Commands::Responses { .. }) => panic!("expected serve"),
+        Some(Commands::Checkpoints { .. }) => panic!("expected serve"),
         None => panic!("expected serve"),
     }
 }
@@ -1206,7 +1436,8 @@ async fn tui_attach_stream_renders_like_basic_snapshot() {
 async fn stream_events_stops_on_stream_end() {
     let mut stream = futures_util::stream::iter(vec![Err(EventSourceError::StreamEnded)]);
     let mut buffer = Vec::new();
-    let result = stream_events_with_writer(&mut stream, OutputView::Raw, &mut buffer).await;
+    let result =
+        stream_events_with_writer(&mut stream, output_options(OutputView::Raw), &mut buffer).await;
     assert!(result.is_ok());
 }
 
@@ -1301,6 +1532,60 @@ fn insert_reasoning_overrides_builds_nested_reasoning_object() {
     );
 }
 
+#[test]
+fn insert_dump_request_override_merges_flag() {
+    let overrides = insert_dump_request_override(Some(serde_json::json!({"model": "gpt-5-mini"})));
+    assert_eq!(
+        overrides,
+        serde_json::json!({"model": "gpt-5-mini", "dump_request": true})
+    );
+    assert_eq!(
+        insert_dump_request_override(None),
+        serde_json::json!({"dump_request": true})
+    );
+}
+
+#[test]
+fn request_frames_copy_dumped_body_to_target() {
+    let root = std::env::temp_dir().join(format!("rip-cli-dump-{}", std::process::id()));
+    let blobs_dir = root.join("blobs");
+    std::fs::create_dir_all(&blobs_dir).expect("blobs");
+    std::fs::write(blobs_dir.join("a1"), br#"{"model":"m"}"#).expect("blob");
+    let target = root.join("out").join("request.json");
+    let mut state = OutputState::new(&OutputOptions {
+        view: OutputView::Output,
+        quiet: false,
+        summary: false,
+        dump_request: Some(RequestDumpTarget {
+            blobs_dir,
+            path: target.clone(),
+        }),
+    });
+    let payload = serde_json::json!({
+        "id": "e1",
+        "session_id": "s1",
+        "timestamp_ms": 0,
+        "seq": 0,
+        "type": "openresponses_request",
+        "endpoint": "http://localhost/v1/responses",
+        "model": "m",
+        "request_index": 0,
+        "kind": "initial",
+        "body_artifact_id": "a1",
+        "body_bytes": 13,
+        "total_bytes": 13,
+        "truncated": false
+    })
+    .to_string();
+    let mut buffer = Vec::new();
+    render_message(OutputView::Output, &payload, &mut buffer, &mut state).expect("render");
+    assert_eq!(
+        std::fs::read_to_string(&target).expect("dump"),
+        r#"{"model":"m"}"#
+    );
+    let _ = std::fs::remove_dir_all(&root);
+}
+
 #[test]
 fn insert_include_overrides_builds_include_array() {
     let mut obj = serde_json::Map::new();
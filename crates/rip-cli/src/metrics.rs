@@ -27,33 +27,25 @@ pub(crate) struct OpenResponsesMetrics {
 impl RunMetrics {
     pub(crate) fn observe(&mut self, event: &Event) {
         match &event.kind {
-            EventKind::SessionStarted { .. } => {
-                if self.session_started_ms.is_none() {
-                    self.session_started_ms = Some(event.timestamp_ms);
-                }
+            EventKind::SessionStarted { .. } if self.session_started_ms.is_none() => {
+                self.session_started_ms = Some(event.timestamp_ms);
             }
-            EventKind::OutputTextDelta { .. } => {
-                if self.first_output_ms.is_none() {
-                    self.first_output_ms = Some(event.timestamp_ms);
-                }
+            EventKind::OutputTextDelta { .. } if self.first_output_ms.is_none() => {
+                self.first_output_ms = Some(event.timestamp_ms);
             }
-            EventKind::SessionEnded { reason } => {
-                if self.session_ended_ms.is_none() {
-                    self.session_ended_ms = Some(event.timestamp_ms);
-                    self.session_end_reason = Some(reason.clone());
-                }
+            EventKind::SessionEnded { reason } if self.session_ended_ms.is_none() => {
+                self.session_ended_ms = Some(event.timestamp_ms);
+                self.session_end_reason = Some(reason.clone());
             }
             EventKind::OpenResponsesRequestStarted {
                 endpoint,
                 model,
                 request_index,
                 ..
-            } => {
-                if *request_index == 0 && self.openresponses.request_started_ms.is_none() {
-                    self.openresponses.request_started_ms = Some(event.timestamp_ms);
-                    self.openresponses.endpoint = Some(endpoint.clone());
-                    self.openresponses.model = model.clone();
-                }
+            } if *request_index == 0 && self.openresponses.request_started_ms.is_none() => {
+                self.openresponses.request_started_ms = Some(event.timestamp_ms);
+                self.openresponses.endpoint = Some(endpoint.clone());
+                self.openresponses.model = model.clone();
             }
             EventKind::OpenResponsesResponseHeaders {
                 request_index,
@@ -61,29 +53,25 @@ impl RunMetrics {
                 request_id,
                 content_type,
                 ..
-            } => {
-                if *request_index == 0 && self.openresponses.response_headers_ms.is_none() {
-                    self.openresponses.response_headers_ms = Some(event.timestamp_ms);
-                    self.openresponses.status = Some(*status);
-                    self.openresponses.request_id = request_id.clone();
-                    self.openresponses.content_type = content_type.clone();
-                }
+            } if *request_index == 0 && self.openresponses.response_headers_ms.is_none() => {
+                self.openresponses.response_headers_ms = Some(event.timestamp_ms);
+                self.openresponses.status = Some(*status);
+                self.openresponses.request_id = request_id.clone();
+                self.openresponses.content_type = content_type.clone();
             }
-            EventKind::OpenResponsesResponseFirstByte { request_index, .. } => {
-                if *request_index == 0 && self.openresponses.response_first_byte_ms.is_none() {
-                    self.openresponses.response_first_byte_ms = Some(event.timestamp_ms);
-                }
+            EventKind::OpenResponsesResponseFirstByte { request_index, .. }
+                if *request_index == 0 && self.openresponses.response_first_byte_ms.is_none() =>
+            {
+                self.openresponses.response_first_byte_ms = Some(event.timestamp_ms);
             }
             EventKind::ProviderEvent {
                 provider, status, ..
-            } => {
-                if provider == "openresponses" {
-                    if self.openresponses.first_provider_event_ms.is_none() {
-                        self.openresponses.first_provider_event_ms = Some(event.timestamp_ms);
-                    }
-                    if *status == ProviderEventStatus::InvalidJson {
-                        self.openresponses.invalid_json = true;
-                    }
+            } if provider == "openresponses" => {
+                if self.openresponses.first_provider_event_ms.is_none() {
+                    self.openresponses.first_provider_event_ms = Some(event.timestamp_ms);
+                }
+                if *status == ProviderEventStatus::InvalidJson {
+                    self.openresponses.invalid_json = true;
                 }
             }
             _ => {}
@@ -229,6 +217,8 @@ mod tests {
                 210,
                 EventKind::OutputTextDelta {
                     delta: "hello".to_string(),
+                    actor_id: None,
+                    origin: None,
                 },
             ),
             event(
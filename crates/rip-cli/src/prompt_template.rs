@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Loads `<config_dir>/templates/<name>.md` and fills its `{{var}}`
+/// placeholders from `key=value` pairs.
+pub(crate) fn resolve_template_prompt(name: &str, vars: &[String]) -> anyhow::Result<String> {
+    if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+        anyhow::bail!("invalid template name '{name}'");
+    }
+    let path = templates_dir()
+        .ok_or_else(|| anyhow::anyhow!("cannot locate config dir (set RIP_CONFIG_HOME or HOME)"))?
+        .join(format!("{name}.md"));
+    let template = std::fs::read_to_string(&path)
+        .map_err(|err| anyhow::anyhow!("failed to read template {}: {err}", path.display()))?;
+    expand_template(&template, &parse_vars(vars)?)
+}
+
+pub(crate) fn parse_vars(vars: &[String]) -> anyhow::Result<BTreeMap<String, String>> {
+    let mut parsed = BTreeMap::new();
+    for raw in vars {
+        let Some((key, value)) = raw.split_once('=') else {
+            anyhow::bail!("invalid --var '{raw}' (expected key=value)");
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            anyhow::bail!("invalid --var '{raw}' (empty key)");
+        }
+        parsed.insert(key.to_string(), value.to_string());
+    }
+    Ok(parsed)
+}
+
+/// Replaces every `{{ key }}` with its value; errors listing any placeholders
+/// left unfilled.
+pub(crate) fn expand_template(
+    template: &str,
+    vars: &BTreeMap<String, String>,
+) -> anyhow::Result<String> {
+    let mut out = String::with_capacity(template.len());
+    let mut missing = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let key = rest[start + 2..start + 2 + len].trim();
+        match vars.get(key) {
+            Some(value) => out.push_str(value),
+            None => {
+                if !missing.iter().any(|name| name == key) {
+                    missing.push(key.to_string());
+                }
+            }
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out.push_str(rest);
+    if !missing.is_empty() {
+        anyhow::bail!("unfilled template placeholders: {}", missing.join(", "));
+    }
+    Ok(out)
+}
+
+fn templates_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os("RIP_CONFIG_HOME") {
+        return Some(PathBuf::from(dir).join("templates"));
+    }
+    let home = std::env::var_os("HOME")?;
+    Some(PathBuf::from(home).join(".rip").join("templates"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_template_fills_placeholders() {
+        let vars =
+            parse_vars(&["lang=Rust".to_string(), "file = a=b.rs".to_string()]).expect("vars");
+        assert_eq!(vars.get("file").map(String::as_str), Some(" a=b.rs"));
+        let prompt =
+            expand_template("Review {{ file }} ({{lang}}); {{lang}} only.", &vars).expect("expand");
+        assert_eq!(prompt, "Review  a=b.rs (Rust); Rust only.");
+    }
+
+    #[test]
+    fn expand_template_rejects_unfilled_placeholders() {
+        let vars = parse_vars(&["a=1".to_string()]).expect("vars");
+        let err = expand_template("{{a}} {{b}} {{c}} {{b}}", &vars).expect_err("missing");
+        assert_eq!(err.to_string(), "unfilled template placeholders: b, c");
+        assert!(parse_vars(&["novalue".to_string()]).is_err());
+        assert!(parse_vars(&["=x".to_string()]).is_err());
+    }
+}
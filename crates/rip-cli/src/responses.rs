@@ -0,0 +1,118 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use rip_provider_openresponses::{ParsedEvent, ParsedEventKind, SseDecoder};
+
+#[derive(Subcommand)]
+pub(crate) enum ResponsesCommand {
+    /// Decode a recorded SSE file and report per-frame OpenResponses schema errors.
+    LintStream {
+        /// Path to the recorded `.sse` file.
+        #[arg(long)]
+        file: PathBuf,
+    },
+}
+
+/// One schema failure, anchored to the frame that produced it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StreamLintFailure {
+    /// 1-based index of the decoded frame.
+    pub frame: usize,
+    /// 1-based line on which the frame starts.
+    pub line: usize,
+    pub event: Option<String>,
+    pub error: String,
+}
+
+pub(crate) fn run_responses(command: ResponsesCommand) -> anyhow::Result<()> {
+    match command {
+        ResponsesCommand::LintStream { file } => {
+            let payload = std::fs::read_to_string(&file)
+                .map_err(|err| anyhow::anyhow!("read {}: {err}", file.display()))?;
+            let (frames, failures) = lint_sse(&payload);
+            for failure in &failures {
+                let event = failure.event.as_deref().unwrap_or("-");
+                println!(
+                    "{}:{}: frame {} ({event}): {}",
+                    file.display(),
+                    failure.line,
+                    failure.frame,
+                    failure.error
+                );
+            }
+            if !failures.is_empty() {
+                anyhow::bail!(
+                    "lint-stream: {} error(s) in {frames} frame(s)",
+                    failures.len()
+                );
+            }
+            println!("{}: {frames} frame(s) ok", file.display());
+            Ok(())
+        }
+    }
+}
+
+/// Decodes `payload` line by line so each frame can be traced back to the line
+/// it started on. Returns the number of decoded frames and every failure.
+pub(crate) fn lint_sse(payload: &str) -> (usize, Vec<StreamLintFailure>) {
+    let mut decoder = SseDecoder::new();
+    let mut frames = 0;
+    let mut failures = Vec::new();
+    let mut frame_start = None;
+
+    for (idx, line) in payload.split_inclusive('\n').enumerate() {
+        let blank = line.trim_end_matches(['\r', '\n']).is_empty();
+        if !blank {
+            frame_start.get_or_insert(idx + 1);
+        }
+        for event in decoder.push(line) {
+            frames += 1;
+            collect_failures(
+                &event,
+                frames,
+                frame_start.unwrap_or(idx + 1),
+                &mut failures,
+            );
+        }
+        if blank {
+            frame_start = None;
+        }
+    }
+    for event in decoder.finish() {
+        frames += 1;
+        let line = frame_start.unwrap_or_else(|| payload.lines().count().max(1));
+        collect_failures(&event, frames, line, &mut failures);
+    }
+    (frames, failures)
+}
+
+fn collect_failures(
+    event: &ParsedEvent,
+    frame: usize,
+    line: usize,
+    failures: &mut Vec<StreamLintFailure>,
+) {
+    let failure = |error: String| StreamLintFailure {
+        frame,
+        line,
+        event: event
+            .event
+            .clone()
+            .or_else(|| event.event_type().map(str::to_string)),
+        error,
+    };
+    let invalid_json = event.kind == ParsedEventKind::InvalidJson;
+    failures.extend(event.errors.iter().map(|error| {
+        if invalid_json {
+            failure(format!("invalid json: {error}"))
+        } else {
+            failure(error.clone())
+        }
+    }));
+    failures.extend(
+        event
+            .response_errors
+            .iter()
+            .map(|error| failure(format!("response: {error}"))),
+    );
+}
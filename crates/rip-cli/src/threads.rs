@@ -109,10 +109,18 @@ pub(crate) enum ThreadsCommand {
         id: String,
     },
     /// Rotate/reset the active provider cursor cache for a thread (truth-log only).
+    ///
+    /// Prints the rotation result with the refreshed cursor status under `cursor_status`.
+    #[command(alias = "rotate-cursor")]
     ProviderCursorRotate {
         /// Thread id (continuity id).
-        id: String,
+        #[arg(required_unless_present = "id_flag")]
+        id: Option<String>,
+        /// Thread id as a flag (`rip continuities rotate-cursor --id <id>`).
+        #[arg(long = "id", value_name = "ID", conflicts_with = "id")]
+        id_flag: Option<String>,
         /// Optional stable reason for the rotation/reset (logged as truth).
+        /// One of `manual`, `error_recovery`, `provider_switch`, `context_reset`.
         #[arg(long)]
         reason: Option<String>,
         /// Actor id (provenance).
@@ -250,6 +258,33 @@ pub(crate) struct ThreadCompactionCheckpointResponse {
     pub(crate) to_message_id: String,
 }
 
+/// Stable reasons accepted by `provider-cursor-rotate`.
+pub(crate) const PROVIDER_CURSOR_ROTATE_REASONS: &[&str] = &[
+    "manual",
+    "error_recovery",
+    "provider_switch",
+    "context_reset",
+];
+
+/// Resolves the thread id given either positionally or via `--id`.
+pub(crate) fn provider_cursor_rotate_id(
+    id: Option<String>,
+    id_flag: Option<String>,
+) -> anyhow::Result<String> {
+    id.or(id_flag)
+        .ok_or_else(|| anyhow::anyhow!("provider-cursor-rotate requires a thread id"))
+}
+
+pub(crate) fn validate_provider_cursor_rotate_reason(reason: Option<&str>) -> anyhow::Result<()> {
+    match reason {
+        Some(reason) if !PROVIDER_CURSOR_ROTATE_REASONS.contains(&reason) => anyhow::bail!(
+            "invalid provider cursor rotate reason '{reason}' (expected one of: {})",
+            PROVIDER_CURSOR_ROTATE_REASONS.join(", ")
+        ),
+        _ => Ok(()),
+    }
+}
+
 pub(crate) async fn run_threads(
     server: Option<String>,
     command: ThreadsCommand,
@@ -266,7 +301,7 @@ pub(crate) async fn run_threads(
 mod exec;
 use exec::run_threads_remote;
 #[cfg(test)]
-use exec::{run_threads_local_with_engine, stream_frames_local};
+use exec::{rotate_provider_cursor_remote, run_threads_local_with_engine, stream_frames_local};
 
 #[cfg(test)]
 mod tests;
@@ -7,6 +7,45 @@ use rip_kernel::Event as FrameEvent;
 
 use super::*;
 
+/// Rotates the provider cursor, then fetches the refreshed cursor status and returns the rotation
+/// response with that status attached as `cursor_status`.
+pub(super) async fn rotate_provider_cursor_remote(
+    client: &Client,
+    server: &str,
+    id: &str,
+    reason: Option<String>,
+    actor_id: String,
+    origin: String,
+) -> anyhow::Result<serde_json::Value> {
+    let url = format!("{server}/threads/{id}/provider-cursor-rotate");
+    let response = client
+        .post(url)
+        .json(&serde_json::json!({
+            "provider": null,
+            "endpoint": null,
+            "model": null,
+            "reason": reason,
+            "actor_id": actor_id,
+            "origin": origin,
+        }))
+        .send()
+        .await?;
+    let status = response.status();
+    if !status.is_success() {
+        anyhow::bail!("thread provider-cursor-rotate failed: {status}");
+    }
+    let mut out: serde_json::Value = response.json().await?;
+
+    let url = format!("{server}/threads/{id}/provider-cursor-status");
+    let response = client.post(url).json(&serde_json::json!({})).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        anyhow::bail!("thread provider-cursor-status failed: {status}");
+    }
+    out["cursor_status"] = response.json().await?;
+    Ok(out)
+}
+
 pub(super) async fn run_threads_remote(
     server: String,
     command: ThreadsCommand,
@@ -184,31 +223,19 @@ pub(super) async fn run_threads_remote(
         }
         ThreadsCommand::ProviderCursorRotate {
             id,
+            id_flag,
             reason,
             actor_id,
             origin,
         } => {
+            let id = provider_cursor_rotate_id(id, id_flag)?;
+            validate_provider_cursor_rotate_reason(reason.as_deref())?;
             let actor_id = actor_id.unwrap_or_else(|| "user".to_string());
             let origin = origin.unwrap_or_else(|| "cli".to_string());
-            let url = format!("{server}/threads/{id}/provider-cursor-rotate");
-            let response = client
-                .post(url)
-                .json(&serde_json::json!({
-                    "provider": null,
-                    "endpoint": null,
-                    "model": null,
-                    "reason": reason,
-                    "actor_id": actor_id,
-                    "origin": origin,
-                }))
-                .send()
-                .await?;
-            let status = response.status();
-            if !status.is_success() {
-                anyhow::bail!("thread provider-cursor-rotate failed: {status}");
-            }
-            let body = response.text().await?;
-            println!("{body}");
+            let out =
+                rotate_provider_cursor_remote(&client, &server, &id, reason, actor_id, origin)
+                    .await?;
+            println!("{out}");
         }
         ThreadsCommand::ContextSelectionStatus { id, limit } => {
             let url = format!("{server}/threads/{id}/context-selection-status");
@@ -481,10 +508,13 @@ pub(super) async fn run_threads_local_with_engine(
         }
         ThreadsCommand::ProviderCursorRotate {
             id,
+            id_flag,
             reason,
             actor_id,
             origin,
         } => {
+            let id = provider_cursor_rotate_id(id, id_flag)?;
+            validate_provider_cursor_rotate_reason(reason.as_deref())?;
             let actor_id = actor_id.unwrap_or_else(|| "user".to_string());
             let origin = origin.unwrap_or_else(|| "cli".to_string());
             let resp = store
@@ -500,7 +530,12 @@ pub(super) async fn run_threads_local_with_engine(
                     },
                 )
                 .map_err(|err| anyhow::anyhow!("thread provider-cursor-rotate failed: {err}"))?;
-            println!("{}", serde_json::to_string(&resp)?);
+            let cursor_status = store
+                .provider_cursor_status_v1(&id, ripd::ProviderCursorStatusV1Request {})
+                .map_err(|err| anyhow::anyhow!("thread provider-cursor-status failed: {err}"))?;
+            let mut out = serde_json::to_value(&resp)?;
+            out["cursor_status"] = serde_json::to_value(&cursor_status)?;
+            println!("{}", serde_json::to_string(&out)?);
         }
         ThreadsCommand::ContextSelectionStatus { id, limit } => {
             let resp = store
@@ -130,8 +130,9 @@ async fn run_threads_local_smoke() {
     run_threads_local_with_engine(
         &engine,
         ThreadsCommand::ProviderCursorRotate {
-            id: thread_id.clone(),
-            reason: Some("test".to_string()),
+            id: Some(thread_id.clone()),
+            id_flag: None,
+            reason: Some("manual".to_string()),
             actor_id: None,
             origin: None,
         },
@@ -270,12 +271,10 @@ async fn run_threads_local_post_message_defaults_provenance() {
                 actor_id,
                 origin,
                 content,
-            } => {
-                if content == "hello" {
-                    assert_eq!(actor_id, "user");
-                    assert_eq!(origin, "cli");
-                    saw_message = true;
-                }
+            } if content == "hello" => {
+                assert_eq!(actor_id, "user");
+                assert_eq!(origin, "cli");
+                saw_message = true;
             }
             EventKind::ContinuityRunSpawned {
                 run_session_id: id,
@@ -376,10 +375,8 @@ async fn run_threads_local_branch_defaults_provenance() {
     for _ in 0..2 {
         let event = receiver.recv().await.expect("recv");
         match event.kind {
-            EventKind::ContinuityCreated { title, .. } => {
-                if title.as_deref() == Some("child") {
-                    saw_created = true;
-                }
+            EventKind::ContinuityCreated { title, .. } if title.as_deref() == Some("child") => {
+                saw_created = true;
             }
             EventKind::ContinuityBranched {
                 parent_thread_id: event_parent_id,
@@ -465,10 +462,8 @@ async fn run_threads_local_handoff_defaults_provenance() {
     for _ in 0..2 {
         let event = receiver.recv().await.expect("recv");
         match event.kind {
-            EventKind::ContinuityCreated { title, .. } => {
-                if title.as_deref() == Some("handoff") {
-                    saw_created = true;
-                }
+            EventKind::ContinuityCreated { title, .. } if title.as_deref() == Some("handoff") => {
+                saw_created = true;
             }
             EventKind::ContinuityHandoffCreated {
                 from_thread_id: event_from_id,
@@ -930,8 +925,9 @@ async fn run_threads_remote_status_and_auto_surfaces_smoke() {
     run_threads(
         Some(server.base_url()),
         ThreadsCommand::ProviderCursorRotate {
-            id: "t1".to_string(),
-            reason: Some("test".to_string()),
+            id: Some("t1".to_string()),
+            id_flag: None,
+            reason: Some("manual".to_string()),
             actor_id: None,
             origin: None,
         },
@@ -996,6 +992,68 @@ async fn run_threads_remote_status_and_auto_surfaces_smoke() {
     .expect("remote compaction-auto-schedule");
 }
 
+#[tokio::test]
+async fn rotate_provider_cursor_remote_sends_reason_and_returns_status() {
+    let server = MockServer::start();
+    let rotate = server.mock(|when, then| {
+        when.method(POST)
+            .path("/threads/t1/provider-cursor-rotate")
+            .json_body_partial(r#"{"reason":"error_recovery","actor_id":"user","origin":"cli"}"#);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"thread_id":"t1","rotated":true,"cursor_event_id":"e1"}"#);
+    });
+    let status = server.mock(|when, then| {
+        when.method(POST).path("/threads/t1/provider-cursor-status");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"thread_id":"t1","active":null,"history":[]}"#);
+    });
+
+    let out = rotate_provider_cursor_remote(
+        &reqwest::Client::new(),
+        &server.base_url(),
+        "t1",
+        Some("error_recovery".to_string()),
+        "user".to_string(),
+        "cli".to_string(),
+    )
+    .await
+    .expect("rotate");
+    rotate.assert();
+    status.assert();
+    assert_eq!(out["rotated"], serde_json::json!(true));
+    assert_eq!(out["cursor_event_id"], serde_json::json!("e1"));
+    assert_eq!(out["cursor_status"]["thread_id"], serde_json::json!("t1"));
+    assert!(out["cursor_status"]["active"].is_null());
+}
+
+#[tokio::test]
+async fn run_threads_rejects_unknown_provider_cursor_rotate_reason() {
+    let server = MockServer::start();
+    let rotate = server.mock(|when, then| {
+        when.method(POST).path("/threads/t1/provider-cursor-rotate");
+        then.status(200);
+    });
+
+    let err = run_threads(
+        Some(server.base_url()),
+        ThreadsCommand::ProviderCursorRotate {
+            id: Some("t1".to_string()),
+            id_flag: None,
+            reason: Some("because".to_string()),
+            actor_id: None,
+            origin: None,
+        },
+    )
+    .await
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid provider cursor rotate reason 'because'"));
+    rotate.assert_hits(0);
+}
+
 #[tokio::test]
 async fn run_threads_remote_errors_on_non_success_status() {
     let server = MockServer::start();
@@ -0,0 +1,89 @@
+#![cfg(not(windows))]
+
+mod common;
+
+use std::path::Path;
+
+use common::{rip_bin, terminate_authority, unique_tmp_root};
+use rip_workspace::Workspace;
+use serde_json::Value;
+use tokio::process::Command;
+
+async fn rip_checkpoints(
+    data_dir: &Path,
+    workspace_dir: &Path,
+    args: &[&str],
+) -> std::process::Output {
+    let mut cmd = Command::new(rip_bin());
+    cmd.arg("checkpoints")
+        .args(args)
+        .env("RIP_DATA_DIR", data_dir)
+        .env("RIP_WORKSPACE_ROOT", workspace_dir);
+    cmd.output().await.expect("rip checkpoints")
+}
+
+#[tokio::test]
+async fn rip_checkpoints_export_then_import_into_fresh_workspace() {
+    let root = unique_tmp_root("rip-checkpoints-export-import");
+    let source_data = root.join("source-data");
+    let source_workspace = root.join("source-workspace");
+    let target_data = root.join("target-data");
+    let target_workspace = root.join("target-workspace");
+    std::fs::create_dir_all(&source_workspace).expect("source workspace");
+    std::fs::create_dir_all(&target_workspace).expect("target workspace");
+
+    let file = source_workspace.join("a.txt");
+    std::fs::write(&file, "one").expect("write");
+    let checkpoint = Workspace::new(&source_workspace)
+        .expect("workspace")
+        .create_checkpoint("s1", "manual", &[file])
+        .expect("checkpoint");
+
+    let archive = root.join("s1.tar");
+    let archive_arg = archive.to_string_lossy().to_string();
+    let out = rip_checkpoints(
+        &source_data,
+        &source_workspace,
+        &["export", "--session", "s1", "--out", &archive_arg],
+    )
+    .await;
+    terminate_authority(&source_data).await;
+    assert!(
+        out.status.success(),
+        "expected export exit=0; stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(
+        archive.exists(),
+        "expected archive at {}",
+        archive.display()
+    );
+
+    let out = rip_checkpoints(
+        &target_data,
+        &target_workspace,
+        &["import", "--session", "s2", "--in", &archive_arg],
+    )
+    .await;
+    terminate_authority(&target_data).await;
+    assert!(
+        out.status.success(),
+        "expected import exit=0; stderr={}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let payload: Value = serde_json::from_slice(&out.stdout).expect("import json");
+    assert_eq!(
+        payload.get("session_id").and_then(|value| value.as_str()),
+        Some("s2")
+    );
+
+    let listed = Workspace::new(&target_workspace)
+        .expect("workspace")
+        .list_checkpoints("s2")
+        .expect("list");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, checkpoint.id);
+    assert_eq!(listed[0].label, "manual");
+
+    let _ = std::fs::remove_dir_all(&root);
+}
@@ -0,0 +1,50 @@
+// Shared by several integration-test crates; each uses a different subset.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub fn unique_tmp_root(prefix: &str) -> PathBuf {
+    let unique = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time")
+        .as_nanos();
+    std::env::temp_dir().join(format!("{prefix}-{}-{unique}", std::process::id()))
+}
+
+pub fn rip_bin() -> PathBuf {
+    if let Some(path) = std::env::var_os("CARGO_BIN_EXE_rip") {
+        return PathBuf::from(path);
+    }
+
+    let exe = std::env::current_exe().expect("current_exe");
+    let debug_dir = exe
+        .parent()
+        .and_then(|path| path.parent())
+        .expect("debug dir");
+    let candidate = debug_dir.join("rip");
+    assert!(
+        candidate.exists(),
+        "expected rip binary at {}",
+        candidate.display()
+    );
+    candidate
+}
+
+pub async fn terminate_authority(data_dir: &Path) {
+    let Ok(Some(meta)) = ripd::read_authority_meta(data_dir) else {
+        return;
+    };
+    let _ = std::process::Command::new("kill")
+        .args(["-TERM", &meta.pid.to_string()])
+        .status();
+    for _ in 0..50 {
+        if matches!(ripd::pid_liveness(meta.pid), ripd::PidLiveness::Dead) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let _ = std::process::Command::new("kill")
+        .args(["-KILL", &meta.pid.to_string()])
+        .status();
+}
@@ -0,0 +1,56 @@
+#![cfg(not(windows))]
+
+mod common;
+
+use common::rip_bin;
+use tokio::process::Command;
+
+#[tokio::test]
+async fn rip_responses_lint_stream_reports_invalid_frame() {
+    let fixture = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/lint_stream_one_invalid.sse"
+    );
+    let out = Command::new(rip_bin())
+        .args(["responses", "lint-stream", "--file", fixture])
+        .output()
+        .await
+        .expect("rip responses lint-stream");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+
+    assert_eq!(
+        out.status.code(),
+        Some(1),
+        "stdout={stdout} stderr={stderr}"
+    );
+    let reports: Vec<&str> = stdout.lines().collect();
+    assert_eq!(reports.len(), 1, "stdout={stdout}");
+    assert!(
+        reports[0].contains(":4: frame 2 (response.output_text.delta): "),
+        "stdout={stdout}"
+    );
+    assert!(
+        stderr.contains("lint-stream: 1 error(s) in 4 frame(s)"),
+        "stderr={stderr}"
+    );
+}
+
+#[tokio::test]
+async fn rip_responses_lint_stream_accepts_valid_fixture() {
+    let fixture = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../rip-provider-openresponses/fixtures/openresponses/stream_all.sse"
+    );
+    let out = Command::new(rip_bin())
+        .args(["responses", "lint-stream", "--file", fixture])
+        .output()
+        .await
+        .expect("rip responses lint-stream");
+    assert!(
+        out.status.success(),
+        "stdout={} stderr={}",
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
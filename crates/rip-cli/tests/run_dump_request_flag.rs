@@ -0,0 +1,88 @@
+#![cfg(not(windows))]
+
+mod common;
+
+use common::{rip_bin, terminate_authority, unique_tmp_root};
+use httpmock::prelude::*;
+use serde_json::Value;
+use tokio::process::Command;
+
+#[tokio::test]
+async fn rip_run_dump_request_writes_provider_request_body() {
+    let rip = rip_bin();
+
+    let provider = MockServer::start_async().await;
+    let _responses = provider
+        .mock_async(|when, then| {
+            when.method(POST).path("/v1/responses");
+            then.status(200)
+                .header("content-type", "text/event-stream")
+                .body(
+                    "data: {\"type\":\"response.output_text.delta\",\"delta\":\"ok\"}\n\n\
+data: [DONE]\n\n",
+                );
+        })
+        .await;
+
+    let root = unique_tmp_root("rip-run-dump-request");
+    let data_dir = root.join("data");
+    let workspace = root.join("workspace");
+    std::fs::create_dir_all(&workspace).expect("workspace");
+    let dump_path = root.join("dumps").join("request.json");
+
+    let out = Command::new(&rip)
+        .args([
+            "run",
+            "hello from dump",
+            "--model",
+            "dump-model",
+            "--view",
+            "raw",
+            "--dump-request",
+        ])
+        .arg(&dump_path)
+        .env("RIP_DATA_DIR", &data_dir)
+        .env("RIP_WORKSPACE_ROOT", &workspace)
+        .env("RIP_OPENRESPONSES_ENDPOINT", provider.url("/v1/responses"))
+        .env("RIP_OPENRESPONSES_API_KEY", "sk-test-secret")
+        .env_remove("OPENAI_API_KEY")
+        .env_remove("OPENROUTER_API_KEY")
+        .output()
+        .await
+        .expect("rip run");
+    let stderr = String::from_utf8_lossy(&out.stderr).to_string();
+    terminate_authority(&data_dir).await;
+    assert!(
+        out.status.success(),
+        "expected rip run exit=0; stderr={stderr}"
+    );
+
+    let raw = std::fs::read_to_string(&dump_path).expect("dump file");
+    assert!(!raw.contains("sk-test-secret"));
+    let dumped: Value = serde_json::from_str(&raw).expect("dump json");
+    assert_eq!(
+        dumped.get("model").and_then(|value| value.as_str()),
+        Some("dump-model")
+    );
+    assert!(
+        dumped
+            .get("input")
+            .is_some_and(|input| input.to_string().contains("hello from dump")),
+        "dump={raw}"
+    );
+
+    let conflicting = Command::new(&rip)
+        .args([
+            "run",
+            "hello",
+            "--server",
+            "http://127.0.0.1:9",
+            "--dump-request",
+            "request.json",
+        ])
+        .output()
+        .await
+        .expect("rip run --server");
+    assert!(!conflicting.status.success());
+    let _ = std::fs::remove_dir_all(&root);
+}
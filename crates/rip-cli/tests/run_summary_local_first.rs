@@ -0,0 +1,57 @@
+#![cfg(not(windows))]
+
+mod common;
+
+use common::{rip_bin, terminate_authority, unique_tmp_root};
+use serde_json::Value;
+use tokio::process::Command;
+
+#[tokio::test]
+async fn rip_run_summary_prints_json_digest_on_stderr() {
+    let rip = rip_bin();
+
+    let root = unique_tmp_root("rip-run-summary");
+    let data_dir = root.join("data");
+    let workspace_dir = root.join("workspace");
+    std::fs::create_dir_all(&workspace_dir).expect("workspace");
+
+    let tool_prompt = r#"{"tool":"bash","args":{"command":"echo summary-test","cwd":"."}}"#;
+    let out = Command::new(&rip)
+        .args(["run", tool_prompt, "--view", "raw", "--summary"])
+        .env("RIP_DATA_DIR", &data_dir)
+        .env("RIP_WORKSPACE_ROOT", &workspace_dir)
+        .env_remove("RIP_OPENRESPONSES_ENDPOINT")
+        .env_remove("OPENAI_API_KEY")
+        .env_remove("OPENROUTER_API_KEY")
+        .output()
+        .await
+        .expect("rip run");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        out.status.success(),
+        "expected rip run exit=0; stderr={stderr}"
+    );
+
+    let summary: Value = stderr
+        .lines()
+        .rev()
+        .find_map(|line| serde_json::from_str(line).ok())
+        .unwrap_or_else(|| panic!("expected json summary on stderr: {stderr}"));
+    assert_eq!(summary["tool_calls"], 1, "summary: {summary}");
+    assert_eq!(
+        summary["errors"],
+        serde_json::json!([]),
+        "summary: {summary}"
+    );
+    assert!(summary["output_len"].is_u64(), "summary: {summary}");
+    assert!(summary["session_id"].is_string(), "summary: {summary}");
+
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        !stdout.contains("\"output_len\""),
+        "summary must not leak into stdout: {stdout}"
+    );
+
+    terminate_authority(&data_dir).await;
+    let _ = std::fs::remove_dir_all(&root);
+}
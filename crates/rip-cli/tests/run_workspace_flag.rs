@@ -0,0 +1,78 @@
+#![cfg(not(windows))]
+
+mod common;
+
+use std::path::PathBuf;
+
+use common::{rip_bin, terminate_authority, unique_tmp_root};
+use tokio::process::Command;
+
+#[tokio::test]
+async fn rip_run_workspace_flag_overrides_env_workspace_root() {
+    let rip = rip_bin();
+
+    let root = unique_tmp_root("rip-run-workspace");
+    let data_dir = root.join("data");
+    let env_workspace = root.join("env-workspace");
+    let flag_workspace = root.join("flag-workspace");
+    std::fs::create_dir_all(&env_workspace).expect("env workspace");
+    std::fs::create_dir_all(&flag_workspace).expect("flag workspace");
+
+    let tool_prompt = r#"{"tool":"write","args":{"path":"note.txt","content":"hi"}}"#;
+    let out = Command::new(&rip)
+        .args(["run", tool_prompt, "--view", "raw", "--workspace"])
+        .arg(&flag_workspace)
+        .env("RIP_DATA_DIR", &data_dir)
+        .env("RIP_WORKSPACE_ROOT", &env_workspace)
+        .env_remove("RIP_OPENRESPONSES_ENDPOINT")
+        .env_remove("OPENAI_API_KEY")
+        .env_remove("OPENROUTER_API_KEY")
+        .output()
+        .await
+        .expect("rip run");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        out.status.success(),
+        "expected rip run exit=0; stderr={stderr}"
+    );
+
+    assert_eq!(
+        std::fs::read_to_string(flag_workspace.join("note.txt")).expect("note"),
+        "hi"
+    );
+    assert!(!env_workspace.join("note.txt").exists());
+    let checkpoints = flag_workspace.join(".rip").join("checkpoints");
+    assert!(
+        std::fs::read_dir(&checkpoints)
+            .map(|mut entries| entries.next().is_some())
+            .unwrap_or(false),
+        "expected checkpoints under {}",
+        checkpoints.display()
+    );
+    assert!(!env_workspace.join(".rip").exists());
+
+    let meta = ripd::read_authority_meta(&data_dir)
+        .expect("authority meta")
+        .expect("authority meta present");
+    assert_eq!(
+        PathBuf::from(&meta.workspace_root),
+        std::path::absolute(&flag_workspace).expect("absolute")
+    );
+
+    let missing = Command::new(&rip)
+        .args(["run", "hello", "--workspace"])
+        .arg(root.join("missing"))
+        .env("RIP_DATA_DIR", &data_dir)
+        .output()
+        .await
+        .expect("rip run missing");
+    assert!(!missing.status.success());
+    assert!(
+        String::from_utf8_lossy(&missing.stderr).contains("is not an existing directory"),
+        "stderr={}",
+        String::from_utf8_lossy(&missing.stderr)
+    );
+
+    terminate_authority(&data_dir).await;
+    let _ = std::fs::remove_dir_all(&root);
+}
@@ -221,7 +221,7 @@ async fn rip_threads_local_first_covers_management_flows() {
             "provider-cursor-rotate",
             &thread_id,
             "--reason",
-            "coverage-test",
+            "manual",
             "--actor-id",
             "user",
             "--origin",
@@ -292,7 +292,7 @@ async fn rip_threads_remote_mode_covers_management_flows() {
             "provider-cursor-rotate",
             &thread_id,
             "--reason",
-            "coverage-test",
+            "manual",
             "--actor-id",
             "user",
             "--origin",
@@ -0,0 +1,152 @@
+use serde_json::Value;
+
+use crate::{EventKind, ProviderEventStatus, ToolTaskExecutionMode};
+
+/// Fluent constructor for [`EventKind::ToolTaskSpawned`]. Optional fields
+/// default to `None` and the execution mode to [`ToolTaskExecutionMode::Pipes`].
+#[derive(Debug, Clone)]
+pub struct ToolTaskSpawnedBuilder {
+    task_id: String,
+    tool_name: String,
+    args: Value,
+    cwd: Option<String>,
+    title: Option<String>,
+    execution_mode: ToolTaskExecutionMode,
+    origin_session_id: Option<String>,
+    artifacts: Option<Value>,
+}
+
+impl ToolTaskSpawnedBuilder {
+    pub fn new(task_id: impl Into<String>, tool_name: impl Into<String>, args: Value) -> Self {
+        Self {
+            task_id: task_id.into(),
+            tool_name: tool_name.into(),
+            args,
+            cwd: None,
+            title: None,
+            execution_mode: ToolTaskExecutionMode::Pipes,
+            origin_session_id: None,
+            artifacts: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn cwd(mut self, cwd: impl Into<String>) -> Self {
+        self.cwd = Some(cwd.into());
+        self
+    }
+
+    pub fn execution_mode(mut self, execution_mode: ToolTaskExecutionMode) -> Self {
+        self.execution_mode = execution_mode;
+        self
+    }
+
+    pub fn origin_session_id(mut self, origin_session_id: impl Into<String>) -> Self {
+        self.origin_session_id = Some(origin_session_id.into());
+        self
+    }
+
+    pub fn artifacts(mut self, artifacts: Value) -> Self {
+        self.artifacts = Some(artifacts);
+        self
+    }
+
+    pub fn build(self) -> EventKind {
+        EventKind::ToolTaskSpawned {
+            task_id: self.task_id,
+            tool_name: self.tool_name,
+            args: self.args,
+            cwd: self.cwd,
+            title: self.title,
+            execution_mode: self.execution_mode,
+            origin_session_id: self.origin_session_id,
+            artifacts: self.artifacts,
+        }
+    }
+}
+
+/// Fluent constructor for [`EventKind::ProviderEvent`]. Everything except the
+/// provider and status starts empty.
+#[derive(Debug, Clone)]
+pub struct ProviderEventBuilder {
+    provider: String,
+    status: ProviderEventStatus,
+    event_name: Option<String>,
+    data: Option<Value>,
+    raw: Option<String>,
+    errors: Vec<String>,
+    response_errors: Vec<String>,
+}
+
+impl ProviderEventBuilder {
+    pub fn new(provider: impl Into<String>, status: ProviderEventStatus) -> Self {
+        Self {
+            provider: provider.into(),
+            status,
+            event_name: None,
+            data: None,
+            raw: None,
+            errors: Vec::new(),
+            response_errors: Vec::new(),
+        }
+    }
+
+    /// Sets the fields of a decoded stream frame the way adapters report
+    /// them: `Event` frames keep `data`, `Done` / `InvalidJson` keep `raw`.
+    pub fn frame(
+        mut self,
+        event_name: Option<String>,
+        data: Option<Value>,
+        raw: impl Into<String>,
+    ) -> Self {
+        self.event_name = event_name;
+        match self.status {
+            ProviderEventStatus::Event => self.data = data,
+            ProviderEventStatus::Done | ProviderEventStatus::InvalidJson => {
+                self.raw = Some(raw.into())
+            }
+        }
+        self
+    }
+
+    pub fn event_name(mut self, event_name: impl Into<String>) -> Self {
+        self.event_name = Some(event_name.into());
+        self
+    }
+
+    pub fn data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    pub fn raw(mut self, raw: impl Into<String>) -> Self {
+        self.raw = Some(raw.into());
+        self
+    }
+
+    pub fn errors(mut self, errors: Vec<String>) -> Self {
+        self.errors = errors;
+        self
+    }
+
+    pub fn response_errors(mut self, response_errors: Vec<String>) -> Self {
+        self.response_errors = response_errors;
+        self
+    }
+
+    pub fn build(self) -> EventKind {
+        EventKind::ProviderEvent {
+            provider: self.provider,
+            status: self.status,
+            event_name: self.event_name,
+            data: self.data,
+            raw: self.raw,
+            errors: self.errors,
+            response_errors: self.response_errors,
+        }
+    }
+}
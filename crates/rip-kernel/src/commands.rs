@@ -69,4 +69,57 @@ impl CommandRegistry {
             None => Err(format!("command not found: {name}")),
         }
     }
+
+    /// Parses `raw` with [`parse_command_line`] and dispatches it without a
+    /// session.
+    pub fn execute_line(&self, raw: &str) -> CommandResult {
+        let (name, args) = parse_command_line(raw);
+        if name.is_empty() {
+            return Err("empty command line".to_string());
+        }
+        self.execute(
+            &name,
+            CommandContext {
+                session_id: None,
+                args,
+                raw: raw.to_string(),
+            },
+        )
+    }
+}
+
+/// Splits a command line into its name and arguments. Whitespace separates
+/// tokens except inside single quotes (fully literal) or double quotes; a
+/// backslash outside single quotes takes the next character literally. An
+/// unterminated quote runs to the end of the line.
+pub fn parse_command_line(raw: &str) -> (String, Vec<String>) {
+    let mut tokens = Vec::new();
+    let mut current: Option<String> = None;
+    let mut quote: Option<char> = None;
+    let mut chars = raw.chars();
+
+    while let Some(ch) = chars.next() {
+        match (quote, ch) {
+            (Some('\''), '\'') | (Some('"'), '"') => quote = None,
+            (Some('\''), _) => current.get_or_insert_with(String::new).push(ch),
+            (_, '\\') => {
+                let token = current.get_or_insert_with(String::new);
+                if let Some(escaped) = chars.next() {
+                    token.push(escaped);
+                }
+            }
+            (Some(_), _) => current.get_or_insert_with(String::new).push(ch),
+            (None, '\'' | '"') => {
+                current.get_or_insert_with(String::new);
+                quote = Some(ch);
+            }
+            (None, _) if ch.is_whitespace() => tokens.extend(current.take()),
+            (None, _) => current.get_or_insert_with(String::new).push(ch),
+        }
+    }
+    tokens.extend(current);
+
+    let mut tokens = tokens.into_iter();
+    let name = tokens.next().unwrap_or_default();
+    (name, tokens.collect())
 }
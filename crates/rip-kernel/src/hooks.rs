@@ -4,6 +4,8 @@ use std::sync::{Arc, Mutex};
 pub enum HookEventKind {
     SessionStarted,
     Output,
+    /// Fired by the tool runner before a tool executes; `Abort` vetoes the call.
+    ToolStarting,
     SessionEnded,
 }
 
@@ -14,21 +16,38 @@ pub struct HookContext {
     pub timestamp_ms: u64,
     pub event: HookEventKind,
     pub output: Option<String>,
+    /// Set for `ToolStarting`.
+    pub tool_name: Option<String>,
+    /// Workspace-relative paths the tool declares it will write (`write`,
+    /// `apply_patch`); `None` when the tool declares none.
+    pub affected_paths: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HookOutcome {
     Continue,
-    Abort { reason: String },
+    Abort {
+        reason: String,
+    },
+    /// Substitutes the output text of an `Output` event; later hooks see the
+    /// replacement. Treated as `Continue` for other events.
+    Replace {
+        output: String,
+    },
 }
 
 pub type HookHandler = Arc<dyn Fn(&HookContext) -> HookOutcome + Send + Sync>;
+pub type HookSessionFilter = Arc<dyn Fn(&str) -> bool + Send + Sync>;
 
 #[derive(Clone)]
 pub struct Hook {
     pub name: String,
     pub event: HookEventKind,
     pub handler: HookHandler,
+    /// When set, the hook only fires for session ids the filter accepts.
+    pub session_filter: Option<HookSessionFilter>,
+    /// Lower runs first; hooks with equal priority run in registration order.
+    pub priority: i32,
 }
 
 impl Hook {
@@ -37,8 +56,28 @@ impl Hook {
             name: name.into(),
             event,
             handler,
+            session_filter: None,
+            priority: 0,
         }
     }
+
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_session_filter(mut self, filter: HookSessionFilter) -> Self {
+        self.session_filter = Some(filter);
+        self
+    }
+
+    fn applies_to(&self, ctx: &HookContext) -> bool {
+        self.event == ctx.event
+            && self
+                .session_filter
+                .as_ref()
+                .is_none_or(|filter| filter(&ctx.session_id))
+    }
 }
 
 #[derive(Default)]
@@ -53,21 +92,87 @@ impl HookEngine {
         }
     }
 
+    /// Keeps `hooks` sorted by ascending priority so `run` can walk it in order;
+    /// a new hook goes after every registered hook of the same priority.
     pub fn register(&self, hook: Hook) {
         let mut hooks = self.hooks.lock().expect("hook engine mutex");
-        hooks.push(hook);
+        let idx = hooks.partition_point(|existing| existing.priority <= hook.priority);
+        hooks.insert(idx, hook);
     }
 
     pub fn run(&self, ctx: &HookContext) -> HookOutcome {
         let hooks = self.hooks.lock().expect("hook engine mutex");
-        for hook in hooks.iter().filter(|hook| hook.event == ctx.event) {
-            match (hook.handler)(ctx) {
+        let mut replaced: Option<HookContext> = None;
+        for hook in hooks.iter().filter(|hook| hook.applies_to(ctx)) {
+            match (hook.handler)(replaced.as_ref().unwrap_or(ctx)) {
                 HookOutcome::Continue => {}
                 HookOutcome::Abort { reason } => {
                     return HookOutcome::Abort { reason };
                 }
+                HookOutcome::Replace { output } => {
+                    replaced.get_or_insert_with(|| ctx.clone()).output = Some(output);
+                }
             }
         }
-        HookOutcome::Continue
+        match replaced.and_then(|ctx| ctx.output) {
+            Some(output) => HookOutcome::Replace { output },
+            None => HookOutcome::Continue,
+        }
+    }
+}
+
+/// A `ToolStarting` hook that aborts any tool whose declared affected paths
+/// fall outside `roots` (workspace-relative; `.` allows the whole workspace).
+/// Only `write` and `apply_patch` declare paths; tools that declare none, such
+/// as `bash`, are let through, so this is not a sandbox for them.
+pub fn path_allowlist_hook<I, S>(roots: I) -> Hook
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let roots: Vec<String> = roots
+        .into_iter()
+        .map(|root| normalize_hook_path(&root.into()))
+        .collect();
+    let handler: HookHandler = Arc::new(move |ctx: &HookContext| {
+        let Some(paths) = &ctx.affected_paths else {
+            return HookOutcome::Continue;
+        };
+        match paths.iter().find(|path| !path_within_roots(&roots, path)) {
+            Some(path) => HookOutcome::Abort {
+                reason: format!(
+                    "{} targets {path} outside allowed roots",
+                    ctx.tool_name.as_deref().unwrap_or("tool")
+                ),
+            },
+            None => HookOutcome::Continue,
+        }
+    });
+    Hook::new("path_allowlist", HookEventKind::ToolStarting, handler)
+}
+
+fn path_within_roots(roots: &[String], path: &str) -> bool {
+    let path = normalize_hook_path(path);
+    if path.starts_with('/') || path.split('/').any(|part| part == "..") {
+        return false;
+    }
+    roots.iter().any(|root| {
+        root.is_empty()
+            || path == *root
+            || path
+                .strip_prefix(root.as_str())
+                .is_some_and(|rest| rest.starts_with('/'))
+    })
+}
+
+fn normalize_hook_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let mut path = path.as_str();
+    while let Some(rest) = path.strip_prefix("./") {
+        path = rest;
+    }
+    if path == "." {
+        path = "";
     }
+    path.trim_end_matches('/').to_string()
 }
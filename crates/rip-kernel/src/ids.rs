@@ -0,0 +1,49 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+/// Source of event ids. The default is [`UuidV4Generator`]; swap in
+/// [`SequentialIdGenerator`] where ids must be reproducible (snapshot tests).
+pub trait IdGenerator: fmt::Debug + Send + Sync {
+    fn next_id(&self) -> String;
+}
+
+pub type SharedIdGenerator = Arc<dyn IdGenerator>;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UuidV4Generator;
+
+impl IdGenerator for UuidV4Generator {
+    fn next_id(&self) -> String {
+        Uuid::new_v4().to_string()
+    }
+}
+
+/// Yields `{prefix}0`, `{prefix}1`, ... in call order.
+#[derive(Debug, Default)]
+pub struct SequentialIdGenerator {
+    prefix: String,
+    next: AtomicU64,
+}
+
+impl SequentialIdGenerator {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            next: AtomicU64::new(0),
+        }
+    }
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&self) -> String {
+        let n = self.next.fetch_add(1, Ordering::Relaxed);
+        format!("{}{n}", self.prefix)
+    }
+}
+
+pub(crate) fn default_id_generator() -> SharedIdGenerator {
+    Arc::new(UuidV4Generator)
+}
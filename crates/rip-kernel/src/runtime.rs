@@ -0,0 +1,374 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use uuid::Uuid;
+
+use crate::commands::{Command, CommandContext, CommandRegistry, CommandResult};
+use crate::hooks::{Hook, HookContext, HookEngine, HookEventKind, HookOutcome};
+use crate::ids::{self, SharedIdGenerator};
+use crate::{Event, EventKind};
+
+pub type EventObserver = Arc<dyn Fn(&Event) + Send + Sync>;
+type EventObservers = Arc<Mutex<Vec<EventObserver>>>;
+
+#[derive(Clone)]
+pub struct Runtime {
+    hooks: Arc<HookEngine>,
+    commands: Arc<CommandRegistry>,
+    observers: EventObservers,
+    id_generator: SharedIdGenerator,
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Runtime {
+    pub fn new() -> Self {
+        Self {
+            hooks: Arc::new(HookEngine::new()),
+            commands: Arc::new(CommandRegistry::new()),
+            observers: Arc::new(Mutex::new(Vec::new())),
+            id_generator: ids::default_id_generator(),
+        }
+    }
+
+    /// Uses `generator` for the event ids of sessions started afterwards
+    /// (session ids from `start_session` stay UUIDs).
+    pub fn with_id_generator(mut self, generator: SharedIdGenerator) -> Self {
+        self.id_generator = generator;
+        self
+    }
+
+    pub fn start_session(&self, input: String) -> Session {
+        Session::new(input, self.hooks.clone())
+            .with_observers(self.observers.clone())
+            .with_id_generator(self.id_generator.clone())
+    }
+
+    pub fn start_session_with_id(&self, session_id: impl Into<String>, input: String) -> Session {
+        Session::with_id(session_id.into(), input, self.hooks.clone())
+            .with_observers(self.observers.clone())
+            .with_id_generator(self.id_generator.clone())
+    }
+
+    /// Registers a callback invoked with every event returned by
+    /// `Session::next_event` for sessions started from this runtime
+    /// (including ones started before registration).
+    pub fn register_observer<F>(&self, observer: F)
+    where
+        F: Fn(&Event) + Send + Sync + 'static,
+    {
+        let mut observers = self.observers.lock().expect("observers mutex");
+        observers.push(Arc::new(observer));
+    }
+
+    pub fn register_hook<F>(&self, name: impl Into<String>, event: HookEventKind, handler: F)
+    where
+        F: Fn(&HookContext) -> HookOutcome + Send + Sync + 'static,
+    {
+        self.register_hook_with_priority(name, event, 0, handler);
+    }
+
+    /// Like `register_hook`, but runs before hooks with a higher `priority`
+    /// regardless of registration order.
+    pub fn register_hook_with_priority<F>(
+        &self,
+        name: impl Into<String>,
+        event: HookEventKind,
+        priority: i32,
+        handler: F,
+    ) where
+        F: Fn(&HookContext) -> HookOutcome + Send + Sync + 'static,
+    {
+        let hook = Hook::new(name, event, Arc::new(handler)).with_priority(priority);
+        self.hooks.register(hook);
+    }
+
+    pub fn register_command<F>(
+        &self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        handler: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(CommandContext) -> CommandResult + Send + Sync + 'static,
+    {
+        let command = Command::new(name, description, Arc::new(handler));
+        self.commands.register(command)
+    }
+
+    pub fn hooks(&self) -> Arc<HookEngine> {
+        self.hooks.clone()
+    }
+
+    pub fn commands(&self) -> Arc<CommandRegistry> {
+        self.commands.clone()
+    }
+}
+
+/// Cloneable flag for stopping a `Session` from another thread (e.g. when the
+/// client driving it disconnects); see `Session::stop_handle`.
+#[derive(Debug, Clone, Default)]
+pub struct SessionStopHandle {
+    requested: Arc<AtomicBool>,
+}
+
+impl SessionStopHandle {
+    pub fn request_stop(&self) {
+        self.requested.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_stop_requested(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+}
+
+pub struct Session {
+    id: String,
+    input: String,
+    seq: u64,
+    stage: Stage,
+    hooks: Arc<HookEngine>,
+    observers: EventObservers,
+    id_generator: SharedIdGenerator,
+    stop: SessionStopHandle,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Stage {
+    Start,
+    Output,
+    End,
+    Cancelled,
+    Done,
+}
+
+impl Session {
+    pub fn new(input: String, hooks: Arc<HookEngine>) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            input,
+            seq: 0,
+            stage: Stage::Start,
+            hooks,
+            observers: EventObservers::default(),
+            id_generator: ids::default_id_generator(),
+            stop: SessionStopHandle::default(),
+        }
+    }
+
+    pub fn with_id(id: String, input: String, hooks: Arc<HookEngine>) -> Self {
+        Self {
+            id,
+            input,
+            seq: 0,
+            stage: Stage::Start,
+            hooks,
+            observers: EventObservers::default(),
+            id_generator: ids::default_id_generator(),
+            stop: SessionStopHandle::default(),
+        }
+    }
+
+    fn with_observers(mut self, observers: EventObservers) -> Self {
+        self.observers = observers;
+        self
+    }
+
+    fn with_id_generator(mut self, id_generator: SharedIdGenerator) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    pub fn set_seq(&mut self, seq: u64) {
+        self.seq = seq;
+    }
+
+    /// Continues numbering after a logged run: sets seq to one past the highest
+    /// seq recorded for this session id in `events`. Leaves seq untouched when
+    /// the log has no frames for this session.
+    pub fn resume_from_events(&mut self, events: &[Event]) {
+        if let Some(max_seq) = events
+            .iter()
+            .filter(|event| event.session_id == self.id)
+            .map(|event| event.seq)
+            .max()
+        {
+            self.seq = max_seq + 1;
+        }
+    }
+
+    /// Asks the session to stop: the next `next_event` call yields a final
+    /// `SessionEnded { reason: "cancelled" }`, and every call after that `None`.
+    pub fn request_stop(&self) {
+        self.stop.request_stop();
+    }
+
+    pub fn stop_handle(&self) -> SessionStopHandle {
+        self.stop.clone()
+    }
+
+    pub fn next_event(&mut self) -> Option<Event> {
+        let event = self.advance()?;
+        // Snapshot so observers may register further observers without deadlocking.
+        let observers = self.observers.lock().expect("observers mutex").clone();
+        for observer in &observers {
+            observer(&event);
+        }
+        Some(event)
+    }
+
+    fn advance(&mut self) -> Option<Event> {
+        if self.stage != Stage::Done && self.stop.is_stop_requested() {
+            self.stage = Stage::Cancelled;
+        }
+        let (next_stage, kind) = match self.stage {
+            Stage::Start => (
+                Stage::Output,
+                EventKind::SessionStarted {
+                    input: self.input.clone(),
+                },
+            ),
+            Stage::Output => (
+                Stage::End,
+                EventKind::OutputTextDelta {
+                    delta: format!("ack: {}", self.input),
+                    actor_id: None,
+                    origin: None,
+                },
+            ),
+            Stage::End => (
+                Stage::Done,
+                EventKind::SessionEnded {
+                    reason: "completed".to_string(),
+                },
+            ),
+            Stage::Cancelled => (
+                Stage::Done,
+                EventKind::SessionEnded {
+                    reason: "cancelled".to_string(),
+                },
+            ),
+            Stage::Done => return None,
+        };
+
+        self.stage = next_stage;
+
+        let timestamp_ms = now_ms();
+        let event = Event {
+            id: self.id_generator.next_id(),
+            session_id: self.id.clone(),
+            timestamp_ms,
+            seq: self.seq,
+            kind,
+        };
+
+        let (hook_event, output) = match &event.kind {
+            EventKind::SessionStarted { .. } => (Some(HookEventKind::SessionStarted), None),
+            EventKind::OutputTextDelta { delta, .. } => {
+                (Some(HookEventKind::Output), Some(delta.clone()))
+            }
+            EventKind::SessionEnded { .. } => (Some(HookEventKind::SessionEnded), None),
+            EventKind::SessionQueued { .. }
+            | EventKind::ContinuityCreated { .. }
+            | EventKind::ContinuityMessageAppended { .. }
+            | EventKind::ContinuityRunSpawned { .. }
+            | EventKind::ContinuityContextSelectionDecided { .. }
+            | EventKind::ContinuityContextCompiled { .. }
+            | EventKind::ContinuityProviderCursorUpdated { .. }
+            | EventKind::ContinuityCompactionCheckpointCreated { .. }
+            | EventKind::ContinuityCompactionAutoScheduleDecided { .. }
+            | EventKind::ContinuityJobSpawned { .. }
+            | EventKind::ContinuityJobEnded { .. }
+            | EventKind::ContinuityRunEnded { .. }
+            | EventKind::ContinuityToolSideEffects { .. }
+            | EventKind::ContinuityBranched { .. }
+            | EventKind::ContinuityHandoffCreated { .. } => (None, None),
+            EventKind::ReasoningTextDelta { .. }
+            | EventKind::ProviderEvent { .. }
+            | EventKind::UsageReported { .. }
+            | EventKind::OpenResponsesRequest { .. }
+            | EventKind::OpenResponsesRequestStarted { .. }
+            | EventKind::OpenResponsesResponseHeaders { .. }
+            | EventKind::OpenResponsesResponseFirstByte { .. }
+            | EventKind::ToolStarted { .. }
+            | EventKind::ToolStdout { .. }
+            | EventKind::ToolStderr { .. }
+            | EventKind::ToolEnded { .. }
+            | EventKind::ToolFailed { .. }
+            | EventKind::CheckpointCreated { .. }
+            | EventKind::CheckpointRewound { .. }
+            | EventKind::CheckpointFailed { .. }
+            | EventKind::ToolTaskSpawned { .. }
+            | EventKind::ToolTaskStatus { .. }
+            | EventKind::ToolTaskCancelRequested { .. }
+            | EventKind::ToolTaskCancelled { .. }
+            | EventKind::ToolTaskOutputDelta { .. }
+            | EventKind::ToolTaskStdinWritten { .. }
+            | EventKind::ToolTaskStdinClosed { .. }
+            | EventKind::ToolTaskResized { .. }
+            | EventKind::ToolTaskSignalled { .. } => (None, None),
+        };
+
+        if let Some(hook_event) = hook_event {
+            let ctx = HookContext {
+                session_id: self.id.clone(),
+                seq: self.seq,
+                timestamp_ms,
+                event: hook_event,
+                output,
+                tool_name: None,
+                affected_paths: None,
+            };
+
+            match self.hooks.run(&ctx) {
+                HookOutcome::Continue => {
+                    self.seq += 1;
+                    Some(event)
+                }
+                HookOutcome::Replace { output } => {
+                    let mut event = event;
+                    if let EventKind::OutputTextDelta { delta, .. } = &mut event.kind {
+                        *delta = output;
+                    }
+                    self.seq += 1;
+                    Some(event)
+                }
+                HookOutcome::Abort { reason } => {
+                    self.stage = Stage::Done;
+                    let abort_event = Event {
+                        id: self.id_generator.next_id(),
+                        session_id: self.id.clone(),
+                        timestamp_ms: now_ms(),
+                        seq: self.seq,
+                        kind: EventKind::SessionEnded { reason },
+                    };
+                    self.seq += 1;
+                    Some(abort_event)
+                }
+            }
+        } else {
+            self.seq += 1;
+            Some(event)
+        }
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use crate::{Event, EventKind, ProviderEventStatus, StreamKind};
+
+/// Compact digest of one session's frames, suitable for a single log line.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SessionSummary {
+    pub session_id: String,
+    pub output_text: String,
+    /// Tool names in the order they started.
+    pub tool_calls: Vec<String>,
+    pub errors: Vec<String>,
+    pub end_reason: Option<String>,
+    pub duration_ms: u64,
+}
+
+impl SessionSummary {
+    /// Summarizes the session-stream frames in `events`; task, continuity and
+    /// artifact frames are ignored.
+    pub fn from_events(events: &[Event]) -> SessionSummary {
+        let mut summary = SessionSummary::default();
+        let mut first_ms = None;
+        let mut last_ms = None;
+
+        for event in events
+            .iter()
+            .filter(|event| event.stream_kind() == StreamKind::Session)
+        {
+            if summary.session_id.is_empty() {
+                summary.session_id = event.session_id.clone();
+            }
+            first_ms.get_or_insert(event.timestamp_ms);
+            last_ms = Some(event.timestamp_ms);
+
+            match &event.kind {
+                EventKind::OutputTextDelta { delta, .. } => summary.output_text.push_str(delta),
+                EventKind::ToolStarted { name, .. } => summary.tool_calls.push(name.clone()),
+                EventKind::ToolFailed { tool_id, error } => {
+                    summary.errors.push(format!("tool {tool_id}: {error}"));
+                }
+                EventKind::CheckpointFailed { error, .. } => {
+                    summary.errors.push(format!("checkpoint: {error}"));
+                }
+                EventKind::ProviderEvent {
+                    status,
+                    errors,
+                    response_errors,
+                    ..
+                } => match status {
+                    ProviderEventStatus::InvalidJson => {
+                        summary.errors.push("provider: invalid json".to_string());
+                    }
+                    ProviderEventStatus::Event => summary.errors.extend(
+                        errors
+                            .iter()
+                            .chain(response_errors)
+                            .map(|error| format!("provider: {error}")),
+                    ),
+                    ProviderEventStatus::Done => {}
+                },
+                EventKind::SessionEnded { reason } => summary.end_reason = Some(reason.clone()),
+                _ => {}
+            }
+        }
+
+        summary.duration_ms = match (first_ms, last_ms) {
+            (Some(first), Some(last)) => last.saturating_sub(first),
+            _ => 0,
+        };
+        summary
+    }
+}
+
+/// Every `tool_id` referenced by a `tool_*` frame in `events`.
+pub fn tool_ids(events: &[Event]) -> BTreeSet<String> {
+    events
+        .iter()
+        .filter_map(|event| match &event.kind {
+            EventKind::ToolStarted { tool_id, .. }
+            | EventKind::ToolStdout { tool_id, .. }
+            | EventKind::ToolStderr { tool_id, .. }
+            | EventKind::ToolEnded { tool_id, .. }
+            | EventKind::ToolFailed { tool_id, .. } => Some(tool_id.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Tools that have a `tool_started` frame but no `tool_ended`/`tool_failed`.
+pub fn unfinished_tool_ids(events: &[Event]) -> BTreeSet<String> {
+    let mut started = BTreeSet::new();
+    let mut finished = BTreeSet::new();
+    for event in events {
+        match &event.kind {
+            EventKind::ToolStarted { tool_id, .. } => {
+                started.insert(tool_id.as_str());
+            }
+            EventKind::ToolEnded { tool_id, .. } | EventKind::ToolFailed { tool_id, .. } => {
+                finished.insert(tool_id.as_str());
+            }
+            _ => {}
+        }
+    }
+    started
+        .difference(&finished)
+        .map(|tool_id| tool_id.to_string())
+        .collect()
+}
+
+/// Merges runs of consecutive `tool_stdout` (or `tool_stderr`) frames for the
+/// same session and `tool_id` into one frame carrying the concatenated chunk.
+/// The merged frame keeps the first frame's `id`, `seq` and `timestamp_ms`;
+/// a stdout/stderr switch or any other frame ends the run.
+pub fn coalesce_tool_output(events: Vec<Event>) -> Vec<Event> {
+    let mut coalesced: Vec<Event> = Vec::with_capacity(events.len());
+    for event in events {
+        if let Some(last) = coalesced.last_mut() {
+            if last.session_id == event.session_id {
+                match (&mut last.kind, &event.kind) {
+                    (
+                        EventKind::ToolStdout { tool_id, chunk },
+                        EventKind::ToolStdout {
+                            tool_id: next_id,
+                            chunk: next,
+                        },
+                    )
+                    | (
+                        EventKind::ToolStderr { tool_id, chunk },
+                        EventKind::ToolStderr {
+                            tool_id: next_id,
+                            chunk: next,
+                        },
+                    ) if tool_id == next_id => {
+                        chunk.push_str(next);
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        coalesced.push(event);
+    }
+    coalesced
+}
+
+/// `(expected, actual)` seq pairs wherever a session's frames, ordered by
+/// `seq`, skip values. Each session is checked from its lowest seq; duplicate
+/// seqs are not reported.
+pub fn detect_seq_gaps(events: &[Event]) -> Vec<(u64, u64)> {
+    let mut seqs_by_session: BTreeMap<&str, Vec<u64>> = BTreeMap::new();
+    for event in events {
+        seqs_by_session
+            .entry(event.session_id.as_str())
+            .or_default()
+            .push(event.seq);
+    }
+    let mut gaps = Vec::new();
+    for seqs in seqs_by_session.values_mut() {
+        seqs.sort_unstable();
+        seqs.dedup();
+        gaps.extend(
+            seqs.windows(2)
+                .filter(|pair| pair[1] != pair[0] + 1)
+                .map(|pair| (pair[0] + 1, pair[1])),
+        );
+    }
+    gaps
+}
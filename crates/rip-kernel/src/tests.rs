@@ -0,0 +1,991 @@
+use std::sync::{Arc, Mutex};
+
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+fn session_emits_three_events_in_order() {
+    let runtime = Runtime::new();
+    let mut session = runtime.start_session("hello".to_string());
+
+    let mut events = Vec::new();
+    while let Some(event) = session.next_event() {
+        events.push(event);
+    }
+
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0].seq, 0);
+    assert_eq!(events[1].seq, 1);
+    assert_eq!(events[2].seq, 2);
+
+    matches!(events[0].kind, EventKind::SessionStarted { .. });
+    matches!(events[1].kind, EventKind::OutputTextDelta { .. });
+    matches!(events[2].kind, EventKind::SessionEnded { .. });
+}
+
+#[test]
+fn session_summary_collects_output_tools_errors_and_end() {
+    let frame = |seq: u64, timestamp_ms: u64, kind: EventKind| Event {
+        id: format!("e{seq}"),
+        session_id: "s1".to_string(),
+        timestamp_ms,
+        seq,
+        kind,
+    };
+    let events = vec![
+        frame(
+            0,
+            100,
+            EventKind::SessionStarted {
+                input: "hi".to_string(),
+            },
+        ),
+        frame(
+            1,
+            120,
+            EventKind::ToolStarted {
+                tool_id: "t1".to_string(),
+                name: "bash".to_string(),
+                args: serde_json::json!({}),
+                timeout_ms: None,
+            },
+        ),
+        frame(
+            2,
+            130,
+            EventKind::ToolFailed {
+                tool_id: "t1".to_string(),
+                error: "boom".to_string(),
+            },
+        ),
+        frame(
+            3,
+            140,
+            EventKind::ToolTaskOutputDelta {
+                task_id: "task-1".to_string(),
+                stream: ToolTaskStream::Stdout,
+                chunk: "ignored".to_string(),
+                artifacts: None,
+            },
+        ),
+        frame(
+            4,
+            150,
+            EventKind::OutputTextDelta {
+                delta: "hello ".to_string(),
+                actor_id: None,
+                origin: None,
+            },
+        ),
+        frame(
+            5,
+            160,
+            EventKind::OutputTextDelta {
+                delta: "world".to_string(),
+                actor_id: None,
+                origin: None,
+            },
+        ),
+        frame(
+            6,
+            175,
+            EventKind::SessionEnded {
+                reason: "completed".to_string(),
+            },
+        ),
+    ];
+
+    let summary = SessionSummary::from_events(&events);
+    assert_eq!(summary.session_id, "s1");
+    assert_eq!(summary.output_text, "hello world");
+    assert_eq!(summary.tool_calls, vec!["bash".to_string()]);
+    assert_eq!(summary.errors, vec!["tool t1: boom".to_string()]);
+    assert_eq!(summary.end_reason.as_deref(), Some("completed"));
+    assert_eq!(summary.duration_ms, 75);
+}
+
+#[test]
+fn tool_task_spawned_builder_defaults_to_pipes_without_cwd() {
+    let kind =
+        ToolTaskSpawnedBuilder::new("t1", "bash", serde_json::json!({"command": "ls"})).build();
+    match kind {
+        EventKind::ToolTaskSpawned {
+            task_id,
+            tool_name,
+            cwd,
+            title,
+            execution_mode,
+            origin_session_id,
+            artifacts,
+            ..
+        } => {
+            assert_eq!(task_id, "t1");
+            assert_eq!(tool_name, "bash");
+            assert_eq!(execution_mode, ToolTaskExecutionMode::Pipes);
+            assert!(cwd.is_none());
+            assert!(title.is_none());
+            assert!(origin_session_id.is_none());
+            assert!(artifacts.is_none());
+        }
+        other => panic!("expected tool_task_spawned, got {other:?}"),
+    }
+
+    let kind = ToolTaskSpawnedBuilder::new("t2", "bash", Value::Null)
+        .cwd("sub")
+        .title("build")
+        .execution_mode(ToolTaskExecutionMode::Pty)
+        .origin_session_id("s1")
+        .build();
+    assert!(matches!(
+        kind,
+        EventKind::ToolTaskSpawned {
+            cwd: Some(ref cwd),
+            title: Some(ref title),
+            execution_mode: ToolTaskExecutionMode::Pty,
+            origin_session_id: Some(ref origin),
+            ..
+        } if cwd == "sub" && title == "build" && origin == "s1"
+    ));
+}
+
+#[test]
+fn provider_event_builder_keeps_raw_for_invalid_json_frames() {
+    let event = Event {
+        id: "e1".to_string(),
+        session_id: "s1".to_string(),
+        timestamp_ms: 0,
+        seq: 1,
+        kind: ProviderEventBuilder::new("openresponses", ProviderEventStatus::InvalidJson)
+            .frame(
+                None,
+                Some(serde_json::json!({"ignored": true})),
+                "{not json",
+            )
+            .errors(vec!["invalid json".to_string()])
+            .build(),
+    };
+    let value = serde_json::to_value(&event).expect("serialize");
+    let text = value.to_string();
+    assert!(text.contains("\"status\":\"invalid_json\""), "{text}");
+    assert_eq!(value["provider"], "openresponses");
+    assert_eq!(value["raw"], "{not json");
+    assert!(value["data"].is_null());
+    assert_eq!(value["errors"], serde_json::json!(["invalid json"]));
+
+    let kind = ProviderEventBuilder::new("openresponses", ProviderEventStatus::Event)
+        .frame(
+            Some("response.created".to_string()),
+            Some(serde_json::json!({"type": "response.created"})),
+            "ignored",
+        )
+        .build();
+    assert!(matches!(
+        kind,
+        EventKind::ProviderEvent {
+            event_name: Some(ref name),
+            data: Some(_),
+            raw: None,
+            ..
+        } if name == "response.created"
+    ));
+}
+
+#[test]
+fn unfinished_tool_ids_reports_tools_without_end() {
+    let frame = |seq: u64, kind: EventKind| Event {
+        id: format!("e{seq}"),
+        session_id: "s1".to_string(),
+        timestamp_ms: seq,
+        seq,
+        kind,
+    };
+    let started = |tool_id: &str| EventKind::ToolStarted {
+        tool_id: tool_id.to_string(),
+        name: "bash".to_string(),
+        args: serde_json::json!({}),
+        timeout_ms: None,
+    };
+    let events = vec![
+        frame(0, started("t1")),
+        frame(1, started("t2")),
+        frame(
+            2,
+            EventKind::ToolStdout {
+                tool_id: "t2".to_string(),
+                chunk: "partial".to_string(),
+            },
+        ),
+        frame(
+            3,
+            EventKind::ToolEnded {
+                tool_id: "t1".to_string(),
+                exit_code: 0,
+                duration_ms: 5,
+                artifacts: None,
+            },
+        ),
+    ];
+
+    let all: Vec<String> = tool_ids(&events).into_iter().collect();
+    assert_eq!(all, vec!["t1".to_string(), "t2".to_string()]);
+    let unfinished: Vec<String> = unfinished_tool_ids(&events).into_iter().collect();
+    assert_eq!(unfinished, vec!["t2".to_string()]);
+}
+
+#[test]
+fn detect_seq_gaps_reports_missing_frames_per_session() {
+    let frame = |session_id: &str, seq: u64| Event {
+        id: format!("{session_id}-{seq}"),
+        session_id: session_id.to_string(),
+        timestamp_ms: seq,
+        seq,
+        kind: EventKind::SessionEnded {
+            reason: "completed".to_string(),
+        },
+    };
+
+    let clean = vec![
+        frame("s1", 0),
+        frame("s2", 0),
+        frame("s1", 1),
+        frame("s2", 1),
+    ];
+    assert!(detect_seq_gaps(&clean).is_empty());
+
+    let missing = vec![
+        frame("s1", 0),
+        frame("s1", 2),
+        frame("s2", 5),
+        frame("s2", 6),
+    ];
+    assert_eq!(detect_seq_gaps(&missing), vec![(1, 2)]);
+}
+
+#[test]
+fn coalesce_tool_output_merges_consecutive_chunks() {
+    let frame = |seq: u64, kind: EventKind| Event {
+        id: format!("e{seq}"),
+        session_id: "s1".to_string(),
+        timestamp_ms: seq,
+        seq,
+        kind,
+    };
+    let stdout = |chunk: &str| EventKind::ToolStdout {
+        tool_id: "t1".to_string(),
+        chunk: chunk.to_string(),
+    };
+    let events = vec![
+        frame(0, stdout("a")),
+        frame(1, stdout("b")),
+        frame(2, stdout("c")),
+        frame(
+            3,
+            EventKind::ToolStderr {
+                tool_id: "t1".to_string(),
+                chunk: "err".to_string(),
+            },
+        ),
+        frame(4, stdout("d")),
+    ];
+
+    let coalesced = coalesce_tool_output(events);
+    assert_eq!(coalesced.len(), 3);
+    assert_eq!(coalesced[0].seq, 0);
+    match &coalesced[0].kind {
+        EventKind::ToolStdout { tool_id, chunk } => {
+            assert_eq!(tool_id, "t1");
+            assert_eq!(chunk, "abc");
+        }
+        other => panic!("expected tool_stdout, got {other:?}"),
+    }
+    assert!(matches!(coalesced[1].kind, EventKind::ToolStderr { .. }));
+    assert_eq!(coalesced[2].seq, 4);
+}
+
+#[test]
+fn event_serializes_to_json() {
+    let runtime = Runtime::new();
+    let mut session = runtime.start_session("test".to_string());
+    let event = session.next_event().expect("event");
+    let json = serde_json::to_string(&event).expect("json");
+    assert!(json.contains("session_started"));
+    assert!(json.contains("input"));
+}
+
+#[test]
+fn event_json_line_round_trips_and_ignores_derived_stream_fields() {
+    let event = Event {
+        id: "e1".to_string(),
+        session_id: "s1".to_string(),
+        timestamp_ms: 42,
+        seq: 7,
+        kind: EventKind::ToolTaskStatus {
+            task_id: "task-9".to_string(),
+            status: ToolTaskStatus::Running,
+            exit_code: None,
+            started_at_ms: Some(40),
+            ended_at_ms: None,
+            artifacts: None,
+            error: None,
+        },
+    };
+    let line = event.to_json_line().expect("line");
+    assert!(!line.contains('\n'));
+    assert!(line.contains("\"stream_kind\":\"task\""), "{line}");
+    assert!(line.contains("\"stream_id\":\"s1\""), "{line}");
+
+    let decoded = Event::from_json_line(&line).expect("decode");
+    assert_eq!(decoded.id, event.id);
+    assert_eq!(decoded.session_id, event.session_id);
+    assert_eq!(decoded.timestamp_ms, event.timestamp_ms);
+    assert_eq!(decoded.seq, event.seq);
+    assert_eq!(
+        serde_json::to_value(&decoded.kind).expect("kind"),
+        serde_json::to_value(&event.kind).expect("kind")
+    );
+    assert_eq!(decoded.to_json_line().expect("line"), line);
+
+    assert!(Event::from_json_line("{\"id\":\"e1\"}").is_err());
+}
+
+/// Exhaustive on purpose: a new `EventKind` variant fails to compile here
+/// until it is added, which in turn requires a schema entry for the tag.
+fn event_kind_tag(kind: &EventKind) -> &'static str {
+    match kind {
+        EventKind::SessionQueued { .. } => "session_queued",
+        EventKind::SessionStarted { .. } => "session_started",
+        EventKind::OutputTextDelta { .. } => "output_text_delta",
+        EventKind::ReasoningTextDelta { .. } => "reasoning_text_delta",
+        EventKind::SessionEnded { .. } => "session_ended",
+        EventKind::ContinuityCreated { .. } => "continuity_created",
+        EventKind::ContinuityMessageAppended { .. } => "continuity_message_appended",
+        EventKind::ContinuityRunSpawned { .. } => "continuity_run_spawned",
+        EventKind::ContinuityContextSelectionDecided { .. } => {
+            "continuity_context_selection_decided"
+        }
+        EventKind::ContinuityContextCompiled { .. } => "continuity_context_compiled",
+        EventKind::ContinuityProviderCursorUpdated { .. } => "continuity_provider_cursor_updated",
+        EventKind::ContinuityCompactionCheckpointCreated { .. } => {
+            "continuity_compaction_checkpoint_created"
+        }
+        EventKind::ContinuityCompactionAutoScheduleDecided { .. } => {
+            "continuity_compaction_auto_schedule_decided"
+        }
+        EventKind::ContinuityJobSpawned { .. } => "continuity_job_spawned",
+        EventKind::ContinuityJobEnded { .. } => "continuity_job_ended",
+        EventKind::ContinuityRunEnded { .. } => "continuity_run_ended",
+        EventKind::ContinuityToolSideEffects { .. } => "continuity_tool_side_effects",
+        EventKind::ContinuityBranched { .. } => "continuity_branched",
+        EventKind::ContinuityHandoffCreated { .. } => "continuity_handoff_created",
+        EventKind::ToolStarted { .. } => "tool_started",
+        EventKind::ToolStdout { .. } => "tool_stdout",
+        EventKind::ToolStderr { .. } => "tool_stderr",
+        EventKind::ToolEnded { .. } => "tool_ended",
+        EventKind::ToolFailed { .. } => "tool_failed",
+        EventKind::OpenResponsesRequest { .. } => "openresponses_request",
+        EventKind::OpenResponsesRequestStarted { .. } => "openresponses_request_started",
+        EventKind::OpenResponsesResponseHeaders { .. } => "openresponses_response_headers",
+        EventKind::OpenResponsesResponseFirstByte { .. } => "openresponses_response_first_byte",
+        EventKind::ProviderEvent { .. } => "provider_event",
+        EventKind::UsageReported { .. } => "usage_reported",
+        EventKind::CheckpointCreated { .. } => "checkpoint_created",
+        EventKind::CheckpointRewound { .. } => "checkpoint_rewound",
+        EventKind::CheckpointFailed { .. } => "checkpoint_failed",
+        EventKind::ToolTaskSpawned { .. } => "tool_task_spawned",
+        EventKind::ToolTaskStatus { .. } => "tool_task_status",
+        EventKind::ToolTaskCancelRequested { .. } => "tool_task_cancel_requested",
+        EventKind::ToolTaskCancelled { .. } => "tool_task_cancelled",
+        EventKind::ToolTaskOutputDelta { .. } => "tool_task_output_delta",
+        EventKind::ToolTaskStdinWritten { .. } => "tool_task_stdin_written",
+        EventKind::ToolTaskStdinClosed { .. } => "tool_task_stdin_closed",
+        EventKind::ToolTaskResized { .. } => "tool_task_resized",
+        EventKind::ToolTaskSignalled { .. } => "tool_task_signalled",
+    }
+}
+
+#[test]
+fn event_wire_json_schema_covers_every_event_kind() {
+    let schema = event_wire_json_schema();
+    let tags: Vec<&str> = schema["properties"]["type"]["enum"]
+        .as_array()
+        .expect("type enum")
+        .iter()
+        .map(|tag| tag.as_str().expect("tag"))
+        .collect();
+    assert_eq!(tags.len(), 42);
+    assert_eq!(schema["oneOf"].as_array().map(Vec::len), Some(tags.len()));
+
+    for (tag, variant) in tags.iter().zip(schema["oneOf"].as_array().unwrap()) {
+        let sample = wire_schema::sample_event_json(tag).expect("sample");
+        let event: Event = serde_json::from_value(sample).expect(tag);
+        assert_eq!(event_kind_tag(&event.kind), *tag);
+
+        let wire = serde_json::to_value(&event).expect("json");
+        for key in wire.as_object().expect("object").keys() {
+            assert!(
+                schema["properties"].get(key).is_some() || variant["properties"].get(key).is_some(),
+                "{tag}: field {key} missing from schema"
+            );
+        }
+        for key in variant["required"].as_array().expect("required") {
+            let key = key.as_str().expect("key");
+            assert!(wire.get(key).is_some(), "{tag}: {key} not serialized");
+        }
+    }
+}
+
+#[test]
+fn event_with_timestamp_adds_rfc3339_field() {
+    let event = Event {
+        id: "e1".to_string(),
+        session_id: "s1".to_string(),
+        timestamp_ms: 1_700_000_000_123,
+        seq: 0,
+        kind: EventKind::SessionEnded {
+            reason: "completed".to_string(),
+        },
+    };
+
+    let plain = serde_json::to_value(&event).expect("json");
+    assert!(plain.get("timestamp").is_none());
+
+    let value = serde_json::to_value(EventWithTimestamp(&event)).expect("json");
+    assert_eq!(value["timestamp_ms"], 1_700_000_000_123u64);
+    assert_eq!(value["timestamp"], "2023-11-14T22:13:20.123Z");
+    assert_eq!(value["type"], "session_ended");
+
+    let roundtrip: Event = serde_json::from_value(value).expect("deserialize");
+    assert_eq!(roundtrip.timestamp_ms, event.timestamp_ms);
+}
+
+#[test]
+fn reasoning_text_delta_round_trips() {
+    let event = Event {
+        id: "e1".to_string(),
+        session_id: "s1".to_string(),
+        timestamp_ms: 0,
+        seq: 3,
+        kind: EventKind::ReasoningTextDelta {
+            delta: "thinking".to_string(),
+        },
+    };
+    assert_eq!(event.stream_kind(), StreamKind::Session);
+
+    let value = serde_json::to_value(&event).expect("json");
+    assert_eq!(value["type"], "reasoning_text_delta");
+    assert_eq!(value["delta"], "thinking");
+
+    let roundtrip: Event = serde_json::from_value(value).expect("deserialize");
+    match roundtrip.kind {
+        EventKind::ReasoningTextDelta { delta } => assert_eq!(delta, "thinking"),
+        other => panic!("expected reasoning_text_delta, got {other:?}"),
+    }
+}
+
+#[test]
+fn rfc3339_from_ms_handles_epoch_and_leap_days() {
+    assert_eq!(rfc3339_from_ms(0), "1970-01-01T00:00:00.000Z");
+    assert_eq!(rfc3339_from_ms(951_782_400_000), "2000-02-29T00:00:00.000Z");
+    assert_eq!(
+        rfc3339_from_ms(4_102_444_799_999),
+        "2099-12-31T23:59:59.999Z"
+    );
+}
+
+#[test]
+fn legacy_event_formats_deserialize() {
+    let legacy_session_started =
+        r#"{"id":"e1","session_id":"s1","timestamp_ms":0,"seq":0,"type":"session_started"}"#;
+    let event: Event = serde_json::from_str(legacy_session_started).expect("deserialize");
+    assert!(matches!(
+        event.kind,
+        EventKind::SessionStarted { input } if input.is_empty()
+    ));
+
+    let legacy_session_started_with_prompt = r#"{"id":"e2","session_id":"s1","timestamp_ms":0,"seq":0,"type":"session_started","prompt":"hello"}"#;
+    let event: Event =
+        serde_json::from_str(legacy_session_started_with_prompt).expect("deserialize");
+    assert!(matches!(
+        event.kind,
+        EventKind::SessionStarted { input } if input == "hello"
+    ));
+
+    let legacy_output = r#"{"id":"e3","session_id":"s1","timestamp_ms":0,"seq":1,"type":"output","content":"ack: hello"}"#;
+    let event: Event = serde_json::from_str(legacy_output).expect("deserialize");
+    assert!(matches!(
+        event.kind,
+        EventKind::OutputTextDelta {
+            delta,
+            actor_id: None,
+            origin: None,
+        } if delta == "ack: hello"
+    ));
+
+    let legacy_context_compiled = r#"{"id":"e4","session_id":"c1","timestamp_ms":0,"seq":2,"type":"continuity_context_compiled","run_session_id":"r1","bundle_artifact_id":"a1","compiler_id":"rip.context_compiler.v1","compiler_strategy":"recent_messages_v1","from_seq":1,"actor_id":"user","origin":"cli"}"#;
+    let event: Event = serde_json::from_str(legacy_context_compiled).expect("deserialize");
+    assert!(matches!(
+        event.kind,
+        EventKind::ContinuityContextCompiled {
+            included_messages: None,
+            included_tokens: None,
+            dropped_messages: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn session_started_includes_input() {
+    let runtime = Runtime::new();
+    let mut session = runtime.start_session("hello".to_string());
+    let event = session.next_event().expect("event");
+    match event.kind {
+        EventKind::SessionStarted { input } => assert_eq!(input, "hello"),
+        _ => panic!("expected session_started"),
+    }
+}
+
+#[test]
+fn observers_see_every_session_event_in_order() {
+    let runtime = Runtime::new();
+    let first = Arc::new(Mutex::new(Vec::new()));
+    let second = Arc::new(Mutex::new(Vec::new()));
+    for seen in [&first, &second] {
+        let seen = Arc::clone(seen);
+        runtime.register_observer(move |event: &Event| {
+            seen.lock()
+                .unwrap()
+                .push((event.seq, event_kind_tag(&event.kind)));
+        });
+    }
+
+    let mut session = runtime.start_session("hello".to_string());
+    while session.next_event().is_some() {}
+
+    let expected = vec![
+        (0, "session_started"),
+        (1, "output_text_delta"),
+        (2, "session_ended"),
+    ];
+    assert_eq!(*first.lock().unwrap(), expected);
+    assert_eq!(*second.lock().unwrap(), expected);
+}
+
+#[test]
+fn session_seq_can_be_overridden() {
+    let runtime = Runtime::new();
+    let mut session = runtime.start_session("hello".to_string());
+    assert_eq!(session.seq(), 0);
+    session.set_seq(42);
+    assert_eq!(session.seq(), 42);
+    let event = session.next_event().expect("event");
+    assert_eq!(event.seq, 42);
+}
+
+#[test]
+fn session_stop_handle_ends_session_with_cancelled_reason() {
+    let runtime = Runtime::new();
+    let mut session = runtime.start_session("hello".to_string());
+    let stop = session.stop_handle();
+
+    let first = session.next_event().expect("first");
+    assert!(matches!(first.kind, EventKind::SessionStarted { .. }));
+
+    stop.request_stop();
+    let last = session.next_event().expect("cancelled");
+    assert_eq!(last.seq, 1);
+    match last.kind {
+        EventKind::SessionEnded { reason } => assert_eq!(reason, "cancelled"),
+        other => panic!("expected session_ended, got {other:?}"),
+    }
+    assert!(session.next_event().is_none());
+    assert!(session.next_event().is_none());
+}
+
+#[test]
+fn session_resume_from_events_continues_after_logged_seq() {
+    let runtime = Runtime::new();
+    let mut session = runtime.start_session_with_id("s1", "hello".to_string());
+    let frame = |session_id: &str, seq: u64| Event {
+        id: format!("{session_id}-{seq}"),
+        session_id: session_id.to_string(),
+        timestamp_ms: seq,
+        seq,
+        kind: EventKind::OutputTextDelta {
+            delta: "x".to_string(),
+            actor_id: None,
+            origin: None,
+        },
+    };
+    let mut log: Vec<Event> = (0..=5).map(|seq| frame("s1", seq)).collect();
+    log.push(frame("s2", 9));
+
+    session.resume_from_events(&[frame("s2", 3)]);
+    assert_eq!(session.seq(), 0);
+
+    session.resume_from_events(&log);
+    assert_eq!(session.seq(), 6);
+    let event = session.next_event().expect("event");
+    assert_eq!(event.seq, 6);
+}
+
+#[test]
+fn runtime_id_generator_makes_event_ids_deterministic() {
+    let runtime = Runtime::new().with_id_generator(Arc::new(SequentialIdGenerator::new("evt-")));
+    let ids: Vec<String> = [
+        runtime.start_session_with_id("s1", "a".to_string()),
+        runtime.start_session_with_id("s2", "b".to_string()),
+    ]
+    .into_iter()
+    .flat_map(|mut session| std::iter::from_fn(move || session.next_event()))
+    .map(|event| event.id)
+    .collect();
+    assert_eq!(ids, ["evt-0", "evt-1", "evt-2", "evt-3", "evt-4", "evt-5"]);
+
+    let default_id = Runtime::new()
+        .start_session("a".to_string())
+        .next_event()
+        .expect("event")
+        .id;
+    assert!(Uuid::parse_str(&default_id).is_ok(), "{default_id}");
+}
+
+#[test]
+fn start_session_with_id_sets_id() {
+    let runtime = Runtime::new();
+    let session = runtime.start_session_with_id("custom", "hello".to_string());
+    assert_eq!(session.id(), "custom");
+}
+
+#[test]
+fn hook_abort_ends_session_early() {
+    let runtime = Runtime::new();
+    runtime.register_hook("abort-on-output", HookEventKind::Output, |_| {
+        HookOutcome::Abort {
+            reason: "stop".to_string(),
+        }
+    });
+
+    let mut session = runtime.start_session("hello".to_string());
+    let mut events = Vec::new();
+    while let Some(event) = session.next_event() {
+        events.push(event);
+    }
+
+    assert_eq!(events.len(), 2);
+    matches!(events[0].kind, EventKind::SessionStarted { .. });
+    matches!(events[1].kind, EventKind::SessionEnded { .. });
+}
+
+#[test]
+fn command_registry_executes() {
+    let runtime = Runtime::new();
+    runtime
+        .register_command("ping", "test command", |_ctx| Ok("pong".to_string()))
+        .expect("register");
+
+    let registry = runtime.commands();
+    let result = registry.execute(
+        "ping",
+        CommandContext {
+            session_id: None,
+            args: Vec::new(),
+            raw: "ping".to_string(),
+        },
+    );
+
+    assert_eq!(result.expect("command"), "pong");
+}
+
+#[test]
+fn parse_command_line_honors_quotes_and_escapes() {
+    let (name, args) = parse_command_line(r#"deploy "my app" --flag\ value"#);
+    assert_eq!(name, "deploy");
+    assert_eq!(args, vec!["my app".to_string(), "--flag value".to_string()]);
+
+    let (name, args) = parse_command_line(r#"  say 'it\s' "a \"b\"" '' "#);
+    assert_eq!(name, "say");
+    assert_eq!(args, vec![r"it\s", r#"a "b""#, ""]);
+
+    assert_eq!(parse_command_line("   "), (String::new(), Vec::new()));
+}
+
+#[test]
+fn command_registry_execute_line_parses_and_dispatches() {
+    let runtime = Runtime::new();
+    runtime
+        .register_command("deploy", "echo args", |ctx| Ok(ctx.args.join("|")))
+        .expect("register");
+
+    let registry = runtime.commands();
+    let result = registry.execute_line(r#"deploy "my app" --flag\ value"#);
+    assert_eq!(result.expect("command"), "my app|--flag value");
+    assert!(registry.execute_line("").is_err());
+    assert!(registry
+        .execute_line("missing arg")
+        .expect_err("not found")
+        .contains("command not found"));
+}
+
+fn ok_command(_ctx: CommandContext) -> CommandResult {
+    Ok("ok".to_string())
+}
+
+#[test]
+fn command_registry_rejects_duplicates() {
+    let runtime = Runtime::new();
+    runtime
+        .register_command("dup", "first", ok_command)
+        .expect("register");
+    let result = runtime.commands().execute(
+        "dup",
+        CommandContext {
+            session_id: None,
+            args: Vec::new(),
+            raw: "dup".to_string(),
+        },
+    );
+    assert_eq!(result.expect("execute"), "ok");
+    let err = runtime
+        .register_command("dup", "second", ok_command)
+        .expect_err("error");
+    assert!(err.contains("already registered"));
+}
+
+#[test]
+fn command_registry_lists_commands() {
+    let runtime = Runtime::new();
+    runtime
+        .register_command("a", "first", |_ctx| Ok("a".to_string()))
+        .expect("register");
+    runtime
+        .register_command("b", "second", |_ctx| Ok("b".to_string()))
+        .expect("register");
+
+    let mut names: Vec<String> = runtime
+        .commands()
+        .list()
+        .into_iter()
+        .map(|cmd| cmd.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+    let result = runtime.commands().execute(
+        "a",
+        CommandContext {
+            session_id: None,
+            args: Vec::new(),
+            raw: "a".to_string(),
+        },
+    );
+    assert_eq!(result.expect("execute"), "a");
+    let result = runtime.commands().execute(
+        "b",
+        CommandContext {
+            session_id: None,
+            args: Vec::new(),
+            raw: "b".to_string(),
+        },
+    );
+    assert_eq!(result.expect("execute"), "b");
+}
+
+#[test]
+fn command_registry_unknown_command_errors() {
+    let runtime = Runtime::new();
+    let result = runtime.commands().execute(
+        "missing",
+        CommandContext {
+            session_id: None,
+            args: Vec::new(),
+            raw: "missing".to_string(),
+        },
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn hooks_run_in_order() {
+    let runtime = Runtime::new();
+    let order: Arc<std::sync::Mutex<Vec<&'static str>>> =
+        Arc::new(std::sync::Mutex::new(Vec::new()));
+    let first = order.clone();
+    let second = order.clone();
+
+    runtime.register_hook("first", HookEventKind::SessionStarted, move |_| {
+        first.lock().expect("lock").push("first");
+        HookOutcome::Continue
+    });
+    runtime.register_hook("second", HookEventKind::SessionStarted, move |_| {
+        second.lock().expect("lock").push("second");
+        HookOutcome::Continue
+    });
+
+    let mut session = runtime.start_session("hello".to_string());
+    session.next_event();
+
+    let recorded = order.lock().expect("lock").clone();
+    assert_eq!(recorded, vec!["first", "second"]);
+}
+
+#[test]
+fn replace_hook_rewrites_output_delta() {
+    let runtime = Runtime::new();
+    runtime.register_hook("uppercase", HookEventKind::Output, |ctx| {
+        HookOutcome::Replace {
+            output: ctx.output.as_deref().unwrap_or_default().to_uppercase(),
+        }
+    });
+    runtime.register_hook("redact", HookEventKind::Output, |ctx| {
+        HookOutcome::Replace {
+            output: ctx
+                .output
+                .as_deref()
+                .unwrap_or_default()
+                .replace("HELLO", "*****"),
+        }
+    });
+
+    let mut session = runtime.start_session("hello there".to_string());
+    let events: Vec<Event> = std::iter::from_fn(|| session.next_event()).collect();
+
+    assert_eq!(events.len(), 3);
+    match &events[1].kind {
+        EventKind::OutputTextDelta { delta, .. } => assert_eq!(delta, "ACK: ***** THERE"),
+        other => panic!("expected output_text_delta, got {other:?}"),
+    }
+    assert!(matches!(events[2].kind, EventKind::SessionEnded { .. }));
+}
+
+#[test]
+fn hooks_run_by_priority_before_registration_order() {
+    let runtime = Runtime::new();
+    let order: Arc<std::sync::Mutex<Vec<&'static str>>> =
+        Arc::new(std::sync::Mutex::new(Vec::new()));
+    let default = order.clone();
+    let later_default = order.clone();
+    let security = order.clone();
+
+    runtime.register_hook("default", HookEventKind::SessionStarted, move |_| {
+        default.lock().expect("lock").push("default");
+        HookOutcome::Continue
+    });
+    runtime.register_hook_with_priority(
+        "security",
+        HookEventKind::SessionStarted,
+        -10,
+        move |_| {
+            security.lock().expect("lock").push("security");
+            HookOutcome::Continue
+        },
+    );
+    runtime.register_hook("later_default", HookEventKind::SessionStarted, move |_| {
+        later_default.lock().expect("lock").push("later_default");
+        HookOutcome::Continue
+    });
+
+    let mut session = runtime.start_session("hello".to_string());
+    session.next_event();
+
+    let recorded = order.lock().expect("lock").clone();
+    assert_eq!(recorded, vec!["security", "default", "later_default"]);
+}
+
+#[test]
+fn session_filtered_hook_skips_other_sessions() {
+    let runtime = Runtime::new();
+    let fired: Arc<std::sync::Mutex<Vec<String>>> = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorder = fired.clone();
+    let hook = Hook::new(
+        "scoped",
+        HookEventKind::SessionStarted,
+        Arc::new(move |ctx: &HookContext| {
+            recorder.lock().expect("lock").push(ctx.session_id.clone());
+            HookOutcome::Continue
+        }),
+    )
+    .with_session_filter(Arc::new(|session_id| session_id == "s1"));
+    runtime.hooks().register(hook);
+
+    runtime
+        .start_session_with_id("s2", "hello".to_string())
+        .next_event();
+    runtime
+        .start_session_with_id("s1", "hello".to_string())
+        .next_event();
+
+    assert_eq!(*fired.lock().expect("lock"), vec!["s1".to_string()]);
+}
+
+#[test]
+fn runtime_default_exposes_ids_and_hooks() {
+    let runtime = Runtime::default();
+    let session = runtime.start_session("hello".to_string());
+    assert!(!session.id().is_empty());
+
+    let hooks = runtime.hooks();
+    let ctx = HookContext {
+        session_id: session.id().to_string(),
+        seq: 0,
+        timestamp_ms: 0,
+        event: HookEventKind::SessionStarted,
+        output: None,
+        tool_name: None,
+        affected_paths: None,
+    };
+    assert_eq!(hooks.run(&ctx), HookOutcome::Continue);
+}
+
+#[test]
+fn runtime_exposes_commands_registry() {
+    let runtime = Runtime::new();
+    let registry = runtime.commands();
+    registry
+        .register(Command::new(
+            "noop",
+            "no-op",
+            std::sync::Arc::new(|_ctx| Ok("ok".to_string())),
+        ))
+        .expect("register");
+    let result = registry.execute(
+        "noop",
+        CommandContext {
+            session_id: None,
+            args: Vec::new(),
+            raw: String::new(),
+        },
+    );
+    assert_eq!(result.expect("execute"), "ok");
+}
+
+#[test]
+fn runtime_registers_command_and_hook() {
+    let runtime = Runtime::new();
+    runtime
+        .register_command("echo", "echo", |ctx| Ok(ctx.raw))
+        .expect("register command");
+    let result = runtime.commands().execute(
+        "echo",
+        CommandContext {
+            session_id: None,
+            args: vec!["hi".to_string()],
+            raw: "hi".to_string(),
+        },
+    );
+    assert_eq!(result.expect("execute"), "hi");
+
+    runtime.register_hook("noop", HookEventKind::SessionStarted, |_ctx| {
+        HookOutcome::Continue
+    });
+    let ctx = HookContext {
+        session_id: "s1".to_string(),
+        seq: 0,
+        timestamp_ms: 0,
+        event: HookEventKind::SessionStarted,
+        output: None,
+        tool_name: None,
+        affected_paths: None,
+    };
+    assert_eq!(runtime.hooks().run(&ctx), HookOutcome::Continue);
+}
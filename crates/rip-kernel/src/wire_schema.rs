@@ -0,0 +1,485 @@
+use serde_json::{json, Map, Value};
+
+/// Field specs per `EventKind` tag, in enum order. Spec syntax: `string`,
+/// `u64`, `i32`, `bool`, `any`, `#Def` ($ref), `[T]` (array of T); a `|null`
+/// suffix marks a nullable field and a leading `?` one omitted when unset.
+const EVENT_KINDS: &[(&str, &[(&str, &str)])] = &[
+    ("session_queued", &[("max_concurrent_sessions", "u64")]),
+    ("session_started", &[("input", "string")]),
+    (
+        "output_text_delta",
+        &[
+            ("delta", "string"),
+            ("actor_id", "?string"),
+            ("origin", "?string"),
+        ],
+    ),
+    ("reasoning_text_delta", &[("delta", "string")]),
+    ("session_ended", &[("reason", "string")]),
+    (
+        "continuity_created",
+        &[("workspace", "string"), ("title", "string|null")],
+    ),
+    (
+        "continuity_message_appended",
+        &[
+            ("actor_id", "string"),
+            ("origin", "string"),
+            ("content", "string"),
+        ],
+    ),
+    (
+        "continuity_run_spawned",
+        &[
+            ("run_session_id", "string"),
+            ("message_id", "string"),
+            ("actor_id", "?string"),
+            ("origin", "?string"),
+        ],
+    ),
+    (
+        "continuity_context_selection_decided",
+        &[
+            ("run_session_id", "string"),
+            ("message_id", "string"),
+            ("compiler_id", "string"),
+            ("compiler_strategy", "string"),
+            ("limits", "any"),
+            (
+                "compaction_checkpoint",
+                "?#ContextSelectionCompactionCheckpointV1",
+            ),
+            (
+                "compaction_checkpoints",
+                "?[#ContextSelectionCompactionCheckpointV1]",
+            ),
+            ("resets", "?[#ContextSelectionResetV1]"),
+            ("reason", "?any"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "continuity_context_compiled",
+        &[
+            ("run_session_id", "string"),
+            ("bundle_artifact_id", "string"),
+            ("compiler_id", "string"),
+            ("compiler_strategy", "string"),
+            ("from_seq", "u64"),
+            ("from_message_id", "?string"),
+            ("included_messages", "?u64"),
+            ("included_tokens", "?u64"),
+            ("dropped_messages", "?u64"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "continuity_provider_cursor_updated",
+        &[
+            ("provider", "string"),
+            ("endpoint", "?string"),
+            ("model", "?string"),
+            ("cursor", "any"),
+            ("action", "string"),
+            ("reason", "?string"),
+            ("run_session_id", "?string"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "continuity_compaction_checkpoint_created",
+        &[
+            ("checkpoint_id", "string"),
+            ("cut_rule_id", "string"),
+            ("summary_kind", "string"),
+            ("summary_artifact_id", "string"),
+            ("from_seq", "u64"),
+            ("from_message_id", "?string"),
+            ("to_seq", "u64"),
+            ("to_message_id", "?string"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "continuity_compaction_auto_schedule_decided",
+        &[
+            ("decision_id", "string"),
+            ("policy_id", "string"),
+            ("decision", "string"),
+            ("execute", "bool"),
+            ("stride_messages", "u64"),
+            ("max_new_checkpoints", "u64"),
+            ("block_on_inflight", "bool"),
+            ("message_count", "u64"),
+            ("cut_rule_id", "string"),
+            ("planned", "[#CompactionPlannedCutPoint]"),
+            ("job_id", "?string"),
+            ("job_kind", "?string"),
+            ("reason", "?any"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "continuity_job_spawned",
+        &[
+            ("job_id", "string"),
+            ("job_kind", "string"),
+            ("details", "?any"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "continuity_job_ended",
+        &[
+            ("job_id", "string"),
+            ("job_kind", "string"),
+            ("status", "string"),
+            ("result", "?any"),
+            ("error", "?string"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "continuity_run_ended",
+        &[
+            ("run_session_id", "string"),
+            ("message_id", "string"),
+            ("reason", "string"),
+            ("actor_id", "?string"),
+            ("origin", "?string"),
+        ],
+    ),
+    (
+        "continuity_tool_side_effects",
+        &[
+            ("run_session_id", "string"),
+            ("tool_id", "string"),
+            ("tool_name", "string"),
+            ("affected_paths", "[string]|null"),
+            ("checkpoint_id", "string|null"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "continuity_branched",
+        &[
+            ("parent_thread_id", "string"),
+            ("parent_seq", "u64"),
+            ("parent_message_id", "?string"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "continuity_handoff_created",
+        &[
+            ("from_thread_id", "string"),
+            ("from_seq", "u64"),
+            ("from_message_id", "?string"),
+            ("summary_artifact_id", "?string"),
+            ("summary_markdown", "?string"),
+            ("actor_id", "string"),
+            ("origin", "string"),
+        ],
+    ),
+    (
+        "tool_started",
+        &[
+            ("tool_id", "string"),
+            ("name", "string"),
+            ("args", "any"),
+            ("timeout_ms", "u64|null"),
+        ],
+    ),
+    ("tool_stdout", &[("tool_id", "string"), ("chunk", "string")]),
+    ("tool_stderr", &[("tool_id", "string"), ("chunk", "string")]),
+    (
+        "tool_ended",
+        &[
+            ("tool_id", "string"),
+            ("exit_code", "i32"),
+            ("duration_ms", "u64"),
+            ("artifacts", "any"),
+        ],
+    ),
+    ("tool_failed", &[("tool_id", "string"), ("error", "string")]),
+    (
+        "openresponses_request",
+        &[
+            ("endpoint", "string"),
+            ("model", "string|null"),
+            ("request_index", "u64"),
+            ("kind", "string"),
+            ("body_artifact_id", "string"),
+            ("body_bytes", "u64"),
+            ("total_bytes", "u64"),
+            ("truncated", "bool"),
+        ],
+    ),
+    (
+        "openresponses_request_started",
+        &[
+            ("endpoint", "string"),
+            ("model", "string|null"),
+            ("request_index", "u64"),
+            ("kind", "string"),
+        ],
+    ),
+    (
+        "openresponses_response_headers",
+        &[
+            ("request_index", "u64"),
+            ("status", "u64"),
+            ("request_id", "string|null"),
+            ("content_type", "string|null"),
+        ],
+    ),
+    (
+        "openresponses_response_first_byte",
+        &[("request_index", "u64")],
+    ),
+    (
+        "provider_event",
+        &[
+            ("provider", "string"),
+            ("status", "#ProviderEventStatus"),
+            ("event_name", "string|null"),
+            ("data", "any"),
+            ("raw", "string|null"),
+            ("errors", "[string]"),
+            ("response_errors", "[string]"),
+        ],
+    ),
+    ("usage_reported", &[("usage", "any"), ("partial", "bool")]),
+    (
+        "checkpoint_created",
+        &[
+            ("checkpoint_id", "string"),
+            ("label", "string"),
+            ("created_at_ms", "u64"),
+            ("files", "[string]"),
+            ("auto", "bool"),
+            ("tool_name", "string|null"),
+        ],
+    ),
+    (
+        "checkpoint_rewound",
+        &[
+            ("checkpoint_id", "string"),
+            ("label", "string"),
+            ("files", "[string]"),
+        ],
+    ),
+    (
+        "checkpoint_failed",
+        &[("action", "#CheckpointAction"), ("error", "string")],
+    ),
+    (
+        "tool_task_spawned",
+        &[
+            ("task_id", "string"),
+            ("tool_name", "string"),
+            ("args", "any"),
+            ("cwd", "string|null"),
+            ("title", "string|null"),
+            ("execution_mode", "#ToolTaskExecutionMode"),
+            ("origin_session_id", "string|null"),
+            ("artifacts", "any"),
+        ],
+    ),
+    (
+        "tool_task_status",
+        &[
+            ("task_id", "string"),
+            ("status", "#ToolTaskStatus"),
+            ("exit_code", "i32|null"),
+            ("started_at_ms", "u64|null"),
+            ("ended_at_ms", "u64|null"),
+            ("artifacts", "any"),
+            ("error", "string|null"),
+        ],
+    ),
+    (
+        "tool_task_cancel_requested",
+        &[("task_id", "string"), ("reason", "string")],
+    ),
+    (
+        "tool_task_cancelled",
+        &[
+            ("task_id", "string"),
+            ("reason", "string"),
+            ("wall_time_ms", "u64|null"),
+        ],
+    ),
+    (
+        "tool_task_output_delta",
+        &[
+            ("task_id", "string"),
+            ("stream", "#ToolTaskStream"),
+            ("chunk", "string"),
+            ("artifacts", "any"),
+        ],
+    ),
+    (
+        "tool_task_stdin_written",
+        &[("task_id", "string"), ("chunk_b64", "string")],
+    ),
+    ("tool_task_stdin_closed", &[("task_id", "string")]),
+    (
+        "tool_task_resized",
+        &[("task_id", "string"), ("rows", "u64"), ("cols", "u64")],
+    ),
+    (
+        "tool_task_signalled",
+        &[("task_id", "string"), ("signal", "string")],
+    ),
+];
+
+/// JSON Schema (draft 2020-12) for the serialized `Event` envelope: the common
+/// header fields plus one `oneOf` branch per `EventKind`, discriminated by
+/// `type`.
+pub fn event_wire_json_schema() -> Value {
+    let tags: Vec<&str> = EVENT_KINDS.iter().map(|(tag, _)| *tag).collect();
+    let variants: Vec<Value> = EVENT_KINDS
+        .iter()
+        .map(|(tag, fields)| {
+            let mut properties = Map::new();
+            let mut required = vec![Value::from("type")];
+            properties.insert("type".to_string(), json!({ "const": tag }));
+            for (name, spec) in fields.iter() {
+                let (optional, spec) = match spec.strip_prefix('?') {
+                    Some(rest) => (true, rest),
+                    None => (false, *spec),
+                };
+                if !optional {
+                    required.push(Value::from(*name));
+                }
+                properties.insert((*name).to_string(), field_schema(spec));
+            }
+            json!({
+                "title": tag,
+                "properties": properties,
+                "required": required,
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "EventWire",
+        "type": "object",
+        "required": ["id", "session_id", "stream_kind", "stream_id", "timestamp_ms", "seq", "type"],
+        "properties": {
+            "id": { "type": "string" },
+            "session_id": { "type": "string" },
+            "stream_kind": { "enum": ["session", "task", "continuity", "artifact"] },
+            "stream_id": { "type": "string" },
+            "timestamp_ms": { "type": "integer", "minimum": 0 },
+            "timestamp": { "type": "string", "format": "date-time" },
+            "seq": { "type": "integer", "minimum": 0 },
+            "type": { "enum": tags },
+        },
+        "oneOf": variants,
+        "$defs": {
+            "ProviderEventStatus": { "enum": ["event", "done", "invalid_json"] },
+            "ToolTaskExecutionMode": { "enum": ["pipes", "pty"] },
+            "ToolTaskStatus": { "enum": ["queued", "running", "exited", "cancelled", "failed"] },
+            "ToolTaskStream": { "enum": ["stdout", "stderr", "pty"] },
+            "CheckpointAction": { "enum": ["create", "rewind"] },
+            "CompactionPlannedCutPoint": {
+                "type": "object",
+                "required": ["target_message_ordinal", "to_seq", "to_message_id"],
+                "properties": {
+                    "target_message_ordinal": { "type": "integer", "minimum": 0 },
+                    "to_seq": { "type": "integer", "minimum": 0 },
+                    "to_message_id": { "type": "string" },
+                },
+            },
+            "ContextSelectionCompactionCheckpointV1": {
+                "type": "object",
+                "required": ["checkpoint_id", "summary_kind", "summary_artifact_id", "to_seq"],
+                "properties": {
+                    "checkpoint_id": { "type": "string" },
+                    "summary_kind": { "type": "string" },
+                    "summary_artifact_id": { "type": "string" },
+                    "to_seq": { "type": "integer", "minimum": 0 },
+                },
+            },
+            "ContextSelectionResetV1": {
+                "type": "object",
+                "required": ["input", "action", "reason"],
+                "properties": {
+                    "input": { "type": "string" },
+                    "action": { "type": "string" },
+                    "reason": { "type": "string" },
+                    "ref": {},
+                },
+            },
+        },
+    })
+}
+
+fn field_schema(spec: &str) -> Value {
+    if let Some(inner) = spec.strip_suffix("|null") {
+        return json!({ "anyOf": [field_schema(inner), { "type": "null" }] });
+    }
+    if let Some(inner) = spec.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        return json!({ "type": "array", "items": field_schema(inner) });
+    }
+    if let Some(def) = spec.strip_prefix('#') {
+        return json!({ "$ref": format!("#/$defs/{def}") });
+    }
+    match spec {
+        "string" => json!({ "type": "string" }),
+        "u64" => json!({ "type": "integer", "minimum": 0 }),
+        "i32" => json!({ "type": "integer" }),
+        "bool" => json!({ "type": "boolean" }),
+        _ => json!({}),
+    }
+}
+
+/// Minimal instance of `tag`'s payload built from the schema table, so tests
+/// can check every documented variant actually deserializes.
+#[cfg(test)]
+pub(crate) fn sample_event_json(tag: &str) -> Option<Value> {
+    fn sample(spec: &str) -> Value {
+        if spec.ends_with("|null") {
+            return Value::Null;
+        }
+        if spec.starts_with('[') {
+            return json!([]);
+        }
+        match spec {
+            "string" => json!(""),
+            "u64" | "i32" => json!(0),
+            "bool" => json!(false),
+            "#ProviderEventStatus" => json!("event"),
+            "#ToolTaskExecutionMode" => json!("pipes"),
+            "#ToolTaskStatus" => json!("queued"),
+            "#ToolTaskStream" => json!("stdout"),
+            "#CheckpointAction" => json!("create"),
+            _ => json!({}),
+        }
+    }
+
+    let (_, fields) = EVENT_KINDS.iter().find(|(name, _)| *name == tag)?;
+    let mut value = json!({
+        "id": "e1",
+        "session_id": "s1",
+        "timestamp_ms": 0,
+        "seq": 0,
+        "type": tag,
+    });
+    for (name, spec) in fields.iter().filter(|(_, spec)| !spec.starts_with('?')) {
+        value[*name] = sample(spec);
+    }
+    Some(value)
+}
@@ -13,6 +13,8 @@ fn runtime_exposes_hooks_and_commands() {
         timestamp_ms: 0,
         event: HookEventKind::SessionStarted,
         output: None,
+        tool_name: None,
+        affected_paths: None,
     };
     assert_eq!(hooks.run(&ctx), HookOutcome::Continue);
 
@@ -0,0 +1,160 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+use rip_kernel::{Event, EventKind};
+
+use crate::EventSink;
+
+const MAGIC_V1: &[u8; 8] = b"RIPEVB01";
+/// Upper bound on a single frame body; a length prefix above this is treated
+/// as corruption rather than trusted as an allocation size.
+const MAX_FRAME_LEN: usize = 64 * 1024 * 1024;
+
+/// Appends events as length-prefixed binary frames instead of JSONL.
+///
+/// Layout: an 8-byte magic header, then per event a little-endian `u32` frame
+/// length followed by `seq: u64`, `timestamp_ms: u64`, `id` and `session_id`
+/// (each a `u32` length + UTF-8 bytes) and the JSON-encoded `EventKind` as the
+/// remainder of the frame. The envelope skips the repeated JSONL keys; the kind
+/// stays JSON because its payloads carry arbitrary `serde_json::Value`s.
+pub struct BinaryEventSink {
+    writer: Mutex<BufWriter<File>>,
+}
+
+impl BinaryEventSink {
+    pub fn new(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if file.metadata()?.len() == 0 {
+            file.write_all(MAGIC_V1)?;
+            file.flush()?;
+        }
+        Ok(Self {
+            writer: Mutex::new(BufWriter::new(file)),
+        })
+    }
+}
+
+impl EventSink for BinaryEventSink {
+    fn append(&self, event: &Event) -> io::Result<()> {
+        let frame = encode_frame(event)?;
+        let mut writer = self.writer.lock().expect("binary event sink mutex");
+        writer.write_all(&frame)?;
+        writer.flush()
+    }
+}
+
+/// Reads every event from a file written by [`BinaryEventSink`].
+pub fn read_binary_events(path: impl AsRef<Path>) -> io::Result<Vec<Event>> {
+    decode_binary_events(BufReader::new(File::open(path)?))
+}
+
+/// Decodes a binary event stream (magic header + frames) back into events.
+///
+/// A torn trailing frame (the writer died mid-append) ends the stream like EOF;
+/// frames claiming more than `MAX_FRAME_LEN` bytes are rejected as invalid.
+pub fn decode_binary_events(mut reader: impl Read) -> io::Result<Vec<Event>> {
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC_V1 {
+        return Err(invalid_data("binary event log magic mismatch"));
+    }
+
+    let mut events = Vec::new();
+    loop {
+        let mut len = [0u8; 4];
+        match reader.read_exact(&mut len) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(events),
+            Err(err) => return Err(err),
+        }
+        let len = u32::from_le_bytes(len) as usize;
+        if len > MAX_FRAME_LEN {
+            return Err(invalid_data(format!(
+                "binary event frame of {len} bytes exceeds {MAX_FRAME_LEN}"
+            )));
+        }
+        let mut frame = Vec::with_capacity(len);
+        (&mut reader).take(len as u64).read_to_end(&mut frame)?;
+        if frame.len() < len {
+            return Ok(events);
+        }
+        events.push(decode_frame(&frame)?);
+    }
+}
+
+fn encode_frame(event: &Event) -> io::Result<Vec<u8>> {
+    let kind = serde_json::to_vec(&event.kind).map_err(|err| invalid_data(err.to_string()))?;
+    let mut body = Vec::with_capacity(24 + event.id.len() + event.session_id.len() + kind.len());
+    body.extend_from_slice(&event.seq.to_le_bytes());
+    body.extend_from_slice(&event.timestamp_ms.to_le_bytes());
+    push_str(&mut body, &event.id)?;
+    push_str(&mut body, &event.session_id)?;
+    body.extend_from_slice(&kind);
+
+    let len = u32::try_from(body.len())
+        .ok()
+        .filter(|len| *len as usize <= MAX_FRAME_LEN)
+        .ok_or_else(|| invalid_data("binary event frame too large"))?;
+    let mut frame = Vec::with_capacity(4 + body.len());
+    frame.extend_from_slice(&len.to_le_bytes());
+    frame.extend_from_slice(&body);
+    Ok(frame)
+}
+
+fn decode_frame(frame: &[u8]) -> io::Result<Event> {
+    let mut cursor = frame;
+    let seq = take_u64(&mut cursor)?;
+    let timestamp_ms = take_u64(&mut cursor)?;
+    let id = take_str(&mut cursor)?;
+    let session_id = take_str(&mut cursor)?;
+    let kind: EventKind =
+        serde_json::from_slice(cursor).map_err(|err| invalid_data(err.to_string()))?;
+    Ok(Event {
+        id,
+        session_id,
+        timestamp_ms,
+        seq,
+        kind,
+    })
+}
+
+fn push_str(out: &mut Vec<u8>, value: &str) -> io::Result<()> {
+    let len =
+        u32::try_from(value.len()).map_err(|_| invalid_data("binary event field too large"))?;
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(value.as_bytes());
+    Ok(())
+}
+
+fn take<'a>(cursor: &mut &'a [u8], len: usize) -> io::Result<&'a [u8]> {
+    if cursor.len() < len {
+        return Err(invalid_data("binary event frame truncated"));
+    }
+    let (head, rest) = cursor.split_at(len);
+    *cursor = rest;
+    Ok(head)
+}
+
+fn take_u64(cursor: &mut &[u8]) -> io::Result<u64> {
+    let bytes = take(cursor, 8)?;
+    Ok(u64::from_le_bytes(bytes.try_into().expect("8 bytes")))
+}
+
+fn take_str(cursor: &mut &[u8]) -> io::Result<String> {
+    let len = take(cursor, 4)?;
+    let len = u32::from_le_bytes(len.try_into().expect("4 bytes")) as usize;
+    let bytes = take(cursor, len)?;
+    String::from_utf8(bytes.to_vec()).map_err(|err| invalid_data(err.to_string()))
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
@@ -8,6 +8,13 @@ use std::{
 
 use rip_kernel::{Event, StreamKind};
 
+mod binary;
+mod redact;
+mod sink;
+pub use binary::{decode_binary_events, read_binary_events, BinaryEventSink};
+pub use redact::Redactor;
+pub use sink::{EventSink, MemoryEventSink, NullEventSink, TeeEventSink};
+
 pub struct EventLog {
     path: PathBuf,
     writer: Mutex<BufWriter<File>>,
@@ -28,7 +35,8 @@ impl EventLog {
 
     pub fn append(&self, event: &Event) -> io::Result<()> {
         let mut writer = self.writer.lock().expect("event log mutex");
-        let line = serde_json::to_string(event)
+        let line = event
+            .to_json_line()
             .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
         writer.write_all(line.as_bytes())?;
         writer.write_all(b"\n")?;
@@ -42,7 +50,7 @@ impl EventLog {
         let mut events = Vec::new();
         for line in reader.lines() {
             let line = line?;
-            let event: Event = serde_json::from_str(&line)
+            let event = Event::from_json_line(&line)
                 .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
             events.push(event);
         }
@@ -194,6 +202,80 @@ mod tests {
         matches!(events[0].kind, EventKind::SessionStarted { .. });
     }
 
+    #[test]
+    fn tee_sink_fans_out_to_memory_and_null() {
+        let memory = std::sync::Arc::new(MemoryEventSink::new());
+        let tee = TeeEventSink::new(vec![Box::new(memory.clone()), Box::new(NullEventSink)]);
+
+        let runtime = Runtime::new();
+        let mut session = runtime.start_session("hello".to_string());
+        while let Some(event) = session.next_event() {
+            tee.append(&event).expect("append");
+        }
+
+        let recorded = memory.events();
+        assert_eq!(recorded.len(), 3);
+        assert!(matches!(recorded[0].kind, EventKind::SessionStarted { .. }));
+        assert!(matches!(recorded[2].kind, EventKind::SessionEnded { .. }));
+    }
+
+    #[test]
+    fn binary_sink_roundtrips_events_losslessly() {
+        let dir = tempdir().expect("tmp");
+        let path = dir.path().join("nested").join("events.bin");
+
+        let runtime = Runtime::new();
+        let mut session = runtime.start_session("hello".to_string());
+        let mut expected = Vec::new();
+        while let Some(event) = session.next_event() {
+            expected.push(event);
+        }
+        expected.insert(
+            1,
+            Event {
+                id: "tool-1".to_string(),
+                session_id: expected[0].session_id.clone(),
+                timestamp_ms: 42,
+                seq: 1,
+                kind: EventKind::ToolStarted {
+                    tool_id: "t1".to_string(),
+                    name: "bash".to_string(),
+                    args: serde_json::json!({"cmd": "echo \"hi\"\n", "nested": [1, null, 2.5]}),
+                    timeout_ms: None,
+                },
+            },
+        );
+
+        let (head, tail) = expected.split_at(2);
+        let sink = BinaryEventSink::new(&path).expect("sink");
+        for event in head {
+            sink.append(event).expect("append");
+        }
+        drop(sink);
+        let sink = BinaryEventSink::new(&path).expect("reopen sink");
+        for event in tail {
+            sink.append(event).expect("append");
+        }
+
+        let decoded = read_binary_events(&path).expect("decode");
+        let to_json = |events: &[Event]| serde_json::to_value(events).expect("json");
+        assert_eq!(to_json(&decoded), to_json(&expected));
+
+        let bytes = fs::read(&path).expect("read");
+        let torn = decode_binary_events(&bytes[..bytes.len() - 1]).expect("torn tail");
+        assert_eq!(to_json(&torn), to_json(&expected[..expected.len() - 1]));
+    }
+
+    #[test]
+    fn binary_decode_rejects_oversized_frame_length() {
+        let mut bytes = b"RIPEVB01".to_vec();
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 16]);
+        let err = decode_binary_events(bytes.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("exceeds"));
+    }
+
     #[test]
     fn event_log_creates_parent_dirs() {
         let dir = tempdir().expect("tmp");
@@ -0,0 +1,139 @@
+use rip_kernel::{Event, EventKind};
+use serde_json::Value;
+
+const MASK: &str = "***";
+
+/// Masks secrets in event payloads before they are logged.
+///
+/// The value under a configured field name (matched case-insensitively at any
+/// depth, e.g. `api_key` inside `tool_started.args`) is replaced whole by
+/// `***`, whatever its type, and every occurrence of a configured secret value
+/// inside any other string is replaced with `***`.
+#[derive(Debug, Clone, Default)]
+pub struct Redactor {
+    fields: Vec<String>,
+    values: Vec<String>,
+}
+
+impl Redactor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_field(mut self, name: impl Into<String>) -> Self {
+        self.fields.push(name.into());
+        self
+    }
+
+    /// Empty values are ignored.
+    pub fn with_value(mut self, secret: impl Into<String>) -> Self {
+        let secret = secret.into();
+        if !secret.is_empty() {
+            self.values.push(secret);
+        }
+        self
+    }
+
+    /// Returns a redacted clone of `event`. Fails only if a configured field
+    /// name collides with a typed `EventKind` field that cannot hold `***`
+    /// (e.g. `type` or `status`).
+    pub fn redact(&self, event: &Event) -> serde_json::Result<Event> {
+        let mut kind = serde_json::to_value(&event.kind)?;
+        self.redact_value(&mut kind);
+        Ok(Event {
+            id: event.id.clone(),
+            session_id: event.session_id.clone(),
+            timestamp_ms: event.timestamp_ms,
+            seq: event.seq,
+            kind: serde_json::from_value::<EventKind>(kind)?,
+        })
+    }
+
+    fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::String(text) => {
+                for secret in &self.values {
+                    if text.contains(secret.as_str()) {
+                        *text = text.replace(secret.as_str(), MASK);
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.redact_value(item);
+                }
+            }
+            Value::Object(obj) => {
+                for (key, item) in obj.iter_mut() {
+                    if self.is_secret_field(key) {
+                        *item = Value::String(MASK.to_string());
+                    } else {
+                        self.redact_value(item);
+                    }
+                }
+            }
+            Value::Null | Value::Bool(_) | Value::Number(_) => {}
+        }
+    }
+
+    fn is_secret_field(&self, key: &str) -> bool {
+        self.fields
+            .iter()
+            .any(|field| field.eq_ignore_ascii_case(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redactor_masks_secret_fields_and_values() {
+        let event = Event {
+            id: "e1".to_string(),
+            session_id: "s1".to_string(),
+            timestamp_ms: 7,
+            seq: 3,
+            kind: EventKind::ToolStarted {
+                tool_id: "t1".to_string(),
+                name: "http".to_string(),
+                args: serde_json::json!({
+                    "url": "https://example.test",
+                    "API_KEY": "sk-live-123",
+                    "headers": {"api_key": ["a", {"n": 1}], "retries": 2},
+                    "command": "curl -H 'token: tok-456' https://example.test",
+                }),
+                timeout_ms: Some(1_000),
+            },
+        };
+
+        let redactor = Redactor::new().with_field("api_key").with_value("tok-456");
+        let redacted = redactor.redact(&event).expect("redact");
+        assert_eq!(redacted.id, "e1");
+        assert_eq!(redacted.seq, 3);
+        match redacted.kind {
+            EventKind::ToolStarted {
+                tool_id,
+                args,
+                timeout_ms,
+                ..
+            } => {
+                assert_eq!(tool_id, "t1");
+                assert_eq!(timeout_ms, Some(1_000));
+                assert_eq!(
+                    args,
+                    serde_json::json!({
+                        "url": "https://example.test",
+                        "API_KEY": "***",
+                        "headers": {"api_key": "***", "retries": 2},
+                        "command": "curl -H 'token: ***' https://example.test",
+                    })
+                );
+            }
+            other => panic!("expected tool_started, got {other:?}"),
+        }
+
+        let err = Redactor::new().with_field("type").redact(&event);
+        assert!(err.is_err());
+    }
+}
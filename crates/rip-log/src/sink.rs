@@ -0,0 +1,88 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use rip_kernel::Event;
+
+use crate::EventLog;
+
+/// Destination for persisted event frames.
+pub trait EventSink: Send + Sync {
+    fn append(&self, event: &Event) -> io::Result<()>;
+}
+
+impl<T: EventSink + ?Sized> EventSink for Arc<T> {
+    fn append(&self, event: &Event) -> io::Result<()> {
+        (**self).append(event)
+    }
+}
+
+impl EventSink for EventLog {
+    fn append(&self, event: &Event) -> io::Result<()> {
+        EventLog::append(self, event)
+    }
+}
+
+/// Keeps appended events in memory; mainly for tests.
+#[derive(Debug, Default)]
+pub struct MemoryEventSink {
+    events: Mutex<Vec<Event>>,
+}
+
+impl MemoryEventSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> Vec<Event> {
+        self.events.lock().expect("memory sink mutex").clone()
+    }
+}
+
+impl EventSink for MemoryEventSink {
+    fn append(&self, event: &Event) -> io::Result<()> {
+        self.events
+            .lock()
+            .expect("memory sink mutex")
+            .push(event.clone());
+        Ok(())
+    }
+}
+
+/// Discards every event.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NullEventSink;
+
+impl EventSink for NullEventSink {
+    fn append(&self, _event: &Event) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Fans each event out to every wrapped sink, in order. All sinks see the
+/// event even if an earlier one fails; the first error is returned.
+pub struct TeeEventSink {
+    sinks: Vec<Box<dyn EventSink>>,
+}
+
+impl TeeEventSink {
+    pub fn new(sinks: Vec<Box<dyn EventSink>>) -> Self {
+        Self { sinks }
+    }
+}
+
+impl EventSink for TeeEventSink {
+    fn append(&self, event: &Event) -> io::Result<()> {
+        let mut first_err = None;
+        for sink in &self.sinks {
+            if let Err(err) = sink.append(event) {
+                first_err.get_or_insert(err);
+            }
+        }
+        match first_err {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
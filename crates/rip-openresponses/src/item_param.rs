@@ -0,0 +1,376 @@
+use serde_json::Value;
+
+use crate::extension::{is_provider_extension_item, normalize_extension_item_for_schema};
+use crate::schema::{
+    INPUT_AUDIO_CONTENT_VALIDATOR, ITEM_PARAM_TYPES, ITEM_PARAM_VARIANT_VALIDATORS, MESSAGE_ROLES,
+};
+use crate::validation_error::{into_result, schema_errors};
+use crate::ValidationError;
+
+/// Every `type` discriminator `validate_item_param` checks (provider extension
+/// items such as `openrouter:web_search` are accepted on top of these).
+pub fn supported_item_param_types() -> &'static [&'static str] {
+    ITEM_PARAM_TYPES.as_slice()
+}
+
+/// Schema-accurate alternative to `validate_item_param`: selects the
+/// `ItemParam` branch(es) by `type` (a missing or null `type` means
+/// `item_reference`, as in the schema) and reports that branch's `jsonschema`
+/// errors. Provider extension items are checked as `web_search_call`.
+pub fn validate_item_param_schema(value: &Value) -> Result<(), Vec<ValidationError>> {
+    if !value.is_object() {
+        return Err(vec![ValidationError::new(
+            "invalid_type",
+            "",
+            "ItemParam must be an object",
+        )]);
+    }
+    let mut normalized = value.clone();
+    normalize_extension_item_for_schema(&mut normalized);
+    let item_type = match normalized.get("type") {
+        None | Some(Value::Null) => "item_reference",
+        Some(Value::String(item_type)) => item_type.as_str(),
+        Some(_) => {
+            return Err(vec![ValidationError::new(
+                "invalid_type",
+                "/type",
+                "ItemParam.type must be a string",
+            )])
+        }
+    };
+    match ITEM_PARAM_VARIANT_VALIDATORS.get(item_type) {
+        Some(validator) => into_result(schema_errors(validator, &normalized)),
+        None => Err(vec![ValidationError::new(
+            "invalid_value",
+            "/type",
+            format!("ItemParam.type has no schema variant: {item_type}"),
+        )]),
+    }
+}
+
+pub fn validate_item_param(value: &Value) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    let map = match value.as_object() {
+        Some(map) => map,
+        None => {
+            return Err(vec![ValidationError::new(
+                "invalid_type",
+                "",
+                "ItemParam must be an object",
+            )])
+        }
+    };
+
+    let type_value = map.get("type");
+    let item_type = type_value.and_then(|value| value.as_str());
+
+    if item_type.is_none() || item_type == Some("item_reference") {
+        return validate_item_reference(map, type_value);
+    }
+
+    match item_type.unwrap() {
+        "message" => {
+            let context = "ItemParam(message)";
+            match require_field(map, "role", context, &mut errors) {
+                Some(Value::String(role)) if !MESSAGE_ROLES.contains(&role.as_str()) => {
+                    errors.push(ValidationError::new(
+                        "invalid_value",
+                        "/role",
+                        format!("{context}.role must be one of {}", MESSAGE_ROLES.join(", ")),
+                    ));
+                }
+                Some(Value::String(_)) => {}
+                Some(_) => errors.push(invalid_type(context, "role", "a string")),
+                None => {}
+            }
+            match require_field(map, "content", context, &mut errors) {
+                Some(Value::Array(blocks)) => {
+                    for (idx, block) in blocks.iter().enumerate() {
+                        if block.get("type").and_then(Value::as_str) != Some("input_audio") {
+                            continue;
+                        }
+                        errors.extend(
+                            schema_errors(&INPUT_AUDIO_CONTENT_VALIDATOR, block)
+                                .into_iter()
+                                .map(|err| {
+                                    err.nested(
+                                        &format!("/content/{idx}"),
+                                        &format!("{context}.content[{idx}]: "),
+                                    )
+                                }),
+                        );
+                    }
+                }
+                Some(Value::String(_)) => {}
+                Some(_) => errors.push(invalid_type(context, "content", "a string or array")),
+                None => {}
+            }
+        }
+        "function_call" => {
+            let context = "ItemParam(function_call)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_string_field(map, "name", context, &mut errors);
+            require_string_field(map, "arguments", context, &mut errors);
+        }
+        "function_call_output" => {
+            let context = "ItemParam(function_call_output)";
+            require_string_field(map, "call_id", context, &mut errors);
+            match require_field(map, "output", context, &mut errors) {
+                Some(Value::String(_)) | Some(Value::Array(_)) => {}
+                Some(_) => errors.push(invalid_type(context, "output", "a string or array")),
+                None => {}
+            }
+        }
+        "reasoning" => {
+            let context = "ItemParam(reasoning)";
+            require_array_field(map, "summary", context, &mut errors);
+        }
+        "compaction" => {
+            let context = "ItemParam(compaction)";
+            require_string_field(map, "encrypted_content", context, &mut errors);
+        }
+        "code_interpreter_call" => {
+            let context = "ItemParam(code_interpreter_call)";
+            require_string_field(map, "id", context, &mut errors);
+            require_string_field(map, "container_id", context, &mut errors);
+            require_string_field(map, "code", context, &mut errors);
+        }
+        "computer_call" => {
+            let context = "ItemParam(computer_call)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_object_field(map, "action", context, &mut errors);
+        }
+        "computer_call_output" => {
+            let context = "ItemParam(computer_call_output)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_object_field(map, "output", context, &mut errors);
+        }
+        "custom_tool_call" => {
+            let context = "ItemParam(custom_tool_call)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_string_field(map, "name", context, &mut errors);
+            require_string_field(map, "input", context, &mut errors);
+        }
+        "custom_tool_call_output" => {
+            let context = "ItemParam(custom_tool_call_output)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_string_field(map, "output", context, &mut errors);
+        }
+        "file_search_call" => {
+            let context = "ItemParam(file_search_call)";
+            require_string_field(map, "id", context, &mut errors);
+            match require_field(map, "queries", context, &mut errors) {
+                Some(Value::Array(items)) => {
+                    if items.is_empty() {
+                        errors.push(ValidationError::new(
+                            "invalid_value",
+                            "/queries",
+                            format!("{context}.queries must not be empty"),
+                        ));
+                    }
+                    for (idx, item) in items.iter().enumerate() {
+                        if !item.is_string() {
+                            errors.push(ValidationError::new(
+                                "invalid_type",
+                                format!("/queries/{idx}"),
+                                format!("{context}.queries[{idx}] must be a string"),
+                            ));
+                        }
+                    }
+                }
+                Some(_) => errors.push(invalid_type(context, "queries", "an array")),
+                None => {}
+            }
+        }
+        "web_search_call" => {}
+        "image_generation_call" => {
+            let context = "ItemParam(image_generation_call)";
+            require_string_field(map, "id", context, &mut errors);
+        }
+        "local_shell_call" => {
+            let context = "ItemParam(local_shell_call)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_object_field(map, "action", context, &mut errors);
+        }
+        "local_shell_call_output" => {
+            let context = "ItemParam(local_shell_call_output)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_string_field(map, "output", context, &mut errors);
+        }
+        "shell_call" => {
+            let context = "ItemParam(shell_call)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_object_field(map, "action", context, &mut errors);
+        }
+        "shell_call_output" => {
+            let context = "ItemParam(shell_call_output)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_array_field(map, "output", context, &mut errors);
+        }
+        "apply_patch_call" => {
+            let context = "ItemParam(apply_patch_call)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_string_field(map, "status", context, &mut errors);
+            require_object_field(map, "operation", context, &mut errors);
+        }
+        "apply_patch_call_output" => {
+            let context = "ItemParam(apply_patch_call_output)";
+            require_string_field(map, "call_id", context, &mut errors);
+            require_string_field(map, "status", context, &mut errors);
+        }
+        "mcp_approval_request" => {
+            let context = "ItemParam(mcp_approval_request)";
+            require_string_field(map, "server_label", context, &mut errors);
+            require_string_field(map, "name", context, &mut errors);
+            require_string_field(map, "arguments", context, &mut errors);
+        }
+        "mcp_approval_response" => {
+            let context = "ItemParam(mcp_approval_response)";
+            require_string_field(map, "approval_request_id", context, &mut errors);
+            require_bool_field(map, "approve", context, &mut errors);
+        }
+        _ if is_provider_extension_item(value) => {}
+        other => errors.push(ValidationError::new(
+            "invalid_value",
+            "/type",
+            format!("ItemParam.type has unsupported value \"{other}\""),
+        )),
+    }
+
+    into_result(errors)
+}
+
+/// Inserts `"detail": "auto"` on every `input_image` / `computer_screenshot`
+/// block whose `detail` is missing or null. Walks `content`, `input` and
+/// `output` arrays, so it accepts a create-response body, an item, or a bare
+/// content array. The request-side param schemas treat `detail` as optional,
+/// but `InputImageContent` (the shape echoed back in response resources)
+/// requires it; run this before `validate_item_param` or a resource round-trip
+/// to match the providers, which default omitted detail to `auto`.
+pub fn normalize_input_image_detail(value: &mut Value) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(normalize_input_image_detail),
+        Value::Object(map) => {
+            let is_image = matches!(
+                map.get("type").and_then(Value::as_str),
+                Some("input_image" | "computer_screenshot")
+            );
+            if is_image && map.get("detail").is_none_or(Value::is_null) {
+                map.insert("detail".to_string(), Value::String("auto".to_string()));
+            }
+            for key in ["content", "input", "output"] {
+                if let Some(child) = map.get_mut(key) {
+                    normalize_input_image_detail(child);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn require_field<'a>(
+    map: &'a serde_json::Map<String, Value>,
+    field: &str,
+    context: &str,
+    errors: &mut Vec<ValidationError>,
+) -> Option<&'a Value> {
+    match map.get(field) {
+        Some(value) => Some(value),
+        None => {
+            errors.push(ValidationError::new(
+                "missing_field",
+                format!("/{field}"),
+                format!("{context} missing required field `{field}`"),
+            ));
+            None
+        }
+    }
+}
+
+fn require_string_field(
+    map: &serde_json::Map<String, Value>,
+    field: &str,
+    context: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    match require_field(map, field, context, errors) {
+        Some(Value::String(_)) => {}
+        Some(_) => errors.push(invalid_type(context, field, "a string")),
+        None => {}
+    }
+}
+
+fn require_array_field(
+    map: &serde_json::Map<String, Value>,
+    field: &str,
+    context: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    match require_field(map, field, context, errors) {
+        Some(Value::Array(_)) => {}
+        Some(_) => errors.push(invalid_type(context, field, "an array")),
+        None => {}
+    }
+}
+
+fn require_object_field(
+    map: &serde_json::Map<String, Value>,
+    field: &str,
+    context: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    match require_field(map, field, context, errors) {
+        Some(Value::Object(_)) => {}
+        Some(_) => errors.push(invalid_type(context, field, "an object")),
+        None => {}
+    }
+}
+
+fn require_bool_field(
+    map: &serde_json::Map<String, Value>,
+    field: &str,
+    context: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    match require_field(map, field, context, errors) {
+        Some(Value::Bool(_)) => {}
+        Some(_) => errors.push(invalid_type(context, field, "a boolean")),
+        None => {}
+    }
+}
+
+fn invalid_type(context: &str, field: &str, expected: &str) -> ValidationError {
+    ValidationError::new(
+        "invalid_type",
+        format!("/{field}"),
+        format!("{context}.{field} must be {expected}"),
+    )
+}
+
+pub(crate) fn validate_item_reference(
+    map: &serde_json::Map<String, Value>,
+    type_value: Option<&Value>,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    if let Some(type_value) = type_value {
+        match type_value {
+            Value::Null => {}
+            Value::String(value) => {
+                if value != "item_reference" {
+                    errors.push(ValidationError::new(
+                        "invalid_value",
+                        "/type",
+                        "ItemReferenceParam.type must be \"item_reference\" when provided",
+                    ));
+                }
+            }
+            _ => errors.push(ValidationError::new(
+                "invalid_type",
+                "/type",
+                "ItemReferenceParam.type must be a string or null",
+            )),
+        }
+    }
+    require_string_field(map, "id", "ItemReferenceParam", &mut errors);
+    into_result(errors)
+}
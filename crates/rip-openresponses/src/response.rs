@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The `error` object of a failed response resource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseError {
+    pub code: Option<String>,
+    pub message: String,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+}
+
+/// Extracts the `error` of a response resource. Also accepts a streaming event
+/// that wraps one (e.g. `response.failed`). Returns `None` when there is no
+/// error object or it lacks a string `message`.
+pub fn extract_response_error(response: &Value) -> Option<ResponseError> {
+    let error = match response.get("error") {
+        Some(error) if error.is_object() => error,
+        _ => response.get("response")?.get("error")?,
+    };
+    let field = |name: &str| {
+        error
+            .get(name)
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_string())
+    };
+    Some(ResponseError {
+        code: field("code"),
+        message: field("message")?,
+        type_: field("type"),
+    })
+}
+
+/// Token counts from the `usage` object of a response resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Usage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub total_tokens: u64,
+    /// `output_tokens_details.reasoning_tokens`.
+    pub reasoning_tokens: Option<u64>,
+    /// `input_tokens_details.cached_tokens`.
+    pub cached_tokens: Option<u64>,
+}
+
+impl Usage {
+    /// Reads `usage` from a response resource or a streaming event that wraps
+    /// one (e.g. `response.completed`). Returns `None` when there is no usage
+    /// object or one of the required token counts is missing.
+    pub fn from_response(value: &Value) -> Option<Usage> {
+        let usage = match value.get("usage") {
+            Some(usage) if usage.is_object() => usage,
+            _ => value.get("response")?.get("usage")?,
+        };
+        let count = |name: &str| usage.get(name).and_then(|value| value.as_u64());
+        let detail = |details: &str, name: &str| {
+            usage
+                .get(details)
+                .and_then(|details| details.get(name))
+                .and_then(|value| value.as_u64())
+        };
+        Some(Usage {
+            input_tokens: count("input_tokens")?,
+            output_tokens: count("output_tokens")?,
+            total_tokens: count("total_tokens")?,
+            reasoning_tokens: detail("output_tokens_details", "reasoning_tokens"),
+            cached_tokens: detail("input_tokens_details", "cached_tokens"),
+        })
+    }
+}
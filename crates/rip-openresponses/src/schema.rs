@@ -0,0 +1,363 @@
+use jsonschema::JSONSchema;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+const SPLIT_COMPONENTS_URI_PREFIX: &str = "https://openresponses.local/components/schemas/";
+const SPLIT_PATHS_URI: &str = "https://openresponses.local/paths/responses.json";
+
+pub(crate) static OPENAPI: Lazy<Value> = Lazy::new(|| {
+    let raw = include_str!("../../../schemas/openresponses/openapi.json");
+    serde_json::from_str(raw).expect("openapi.json valid")
+});
+
+static SPLIT_COMPONENTS: Lazy<BTreeMap<String, Value>> = Lazy::new(|| {
+    let raw = include_str!("../../../schemas/openresponses/split_components.json");
+    let mut components: BTreeMap<String, Value> =
+        serde_json::from_str(raw).expect("split_components.json valid");
+    // Components the upstream split lacks (e.g. `input_audio` content); upstream wins.
+    let raw = include_str!("../../../schemas/openresponses/local_components.json");
+    let local: BTreeMap<String, Value> =
+        serde_json::from_str(raw).expect("local_components.json valid");
+    for (name, schema) in local {
+        components.entry(name).or_insert(schema);
+    }
+    // ...and wire `input_audio` into the user message content parts it belongs to.
+    if let Some(Value::Array(parts)) = components
+        .get_mut("UserMessageItemParam.json")
+        .and_then(|schema| schema.pointer_mut("/properties/content/oneOf/0/items/oneOf"))
+    {
+        let audio = serde_json::json!({ "$ref": "./InputAudioContentParam.json" });
+        if !parts.contains(&audio) {
+            parts.push(audio);
+        }
+    }
+    components
+});
+
+static SPLIT_PATHS_RESPONSES: Lazy<Value> = Lazy::new(|| {
+    let raw = include_str!("../../../schemas/openresponses/paths_responses.json");
+    serde_json::from_str(raw).expect("paths_responses.json valid")
+});
+
+static STREAM_EVENT_TYPES: Lazy<Vec<String>> = Lazy::new(|| {
+    let raw = include_str!("../../../schemas/openresponses/streaming_event_types.json");
+    serde_json::from_str(raw).expect("streaming_event_types.json valid")
+});
+
+static STREAM_SCHEMA: Lazy<Value> =
+    Lazy::new(|| extract_split_streaming_schema().expect("split streaming event schema not found"));
+
+static RESPONSE_SCHEMA: Lazy<Value> = Lazy::new(|| {
+    split_component_schema("ResponseResource.json")
+        .cloned()
+        .expect("ResponseResource schema not found")
+});
+
+static CREATE_RESPONSE_SCHEMA: Lazy<Value> = Lazy::new(|| {
+    split_component_schema("CreateResponseBody.json")
+        .cloned()
+        .expect("CreateResponseBody schema not found")
+});
+
+static TOOL_PARAM_SCHEMA: Lazy<Value> = Lazy::new(|| {
+    split_component_schema("ResponsesToolParam.json")
+        .cloned()
+        .expect("ResponsesToolParam schema not found")
+});
+
+static TOOL_CHOICE_SCHEMA: Lazy<Value> = Lazy::new(|| {
+    split_component_schema("ToolChoiceParam.json")
+        .cloned()
+        .expect("ToolChoiceParam schema not found")
+});
+
+static ITEM_PARAM_SCHEMA: Lazy<Value> = Lazy::new(|| {
+    let mut schema = split_component_schema("ItemParam.json")
+        .cloned()
+        .expect("ItemParam schema not found");
+    if let Some(obj) = schema.as_object_mut() {
+        obj.remove("discriminator");
+    }
+    schema
+});
+
+pub(crate) static STREAM_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_split_stream_schema(&SPLIT_COMPONENTS));
+
+/// `STREAM_VALIDATOR` with every event's `response` relaxed to a bare object,
+/// so the envelope can be checked without re-validating the snapshot.
+pub(crate) static STREAM_ENVELOPE_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_split_stream_schema(&stream_envelope_components()));
+
+/// `ItemParam` with its `type` discriminator applied: one validator per type
+/// value, covering only the `oneOf` branches that value selects.
+pub(crate) static ITEM_PARAM_VARIANT_VALIDATORS: Lazy<BTreeMap<String, JSONSchema>> =
+    Lazy::new(compile_item_param_variant_validators);
+
+pub(crate) static RESPONSE_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_split_schema("ResponseResource.json"));
+
+pub(crate) static CREATE_RESPONSE_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_split_schema("CreateResponseBody.json"));
+
+pub(crate) static TOOL_PARAM_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_split_schema("ResponsesToolParam.json"));
+
+pub(crate) static TOOL_CHOICE_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_split_schema("ToolChoiceParam.json"));
+
+pub(crate) static SPECIFIC_TOOL_CHOICE_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_split_schema("SpecificToolChoiceParam.json"));
+
+pub(crate) static INPUT_AUDIO_CONTENT_VALIDATOR: Lazy<JSONSchema> =
+    Lazy::new(|| compile_split_schema("InputAudioContentParam.json"));
+
+pub(crate) const MESSAGE_ROLES: [&str; 4] = ["assistant", "developer", "system", "user"];
+/// `ResponsesToolParam` `type` values, read from the schema's `oneOf` branches.
+pub(crate) static TOOL_PARAM_TYPES: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    discriminated_variants("ResponsesToolParam.json")
+        .into_keys()
+        .collect()
+});
+/// `ItemParam` `type` values, read from the schema's `oneOf` branches.
+pub(crate) static ITEM_PARAM_TYPES: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    discriminated_variants("ItemParam.json")
+        .into_keys()
+        .collect()
+});
+
+pub fn openapi() -> &'static Value {
+    &OPENAPI
+}
+
+/// The `info.version` of the embedded OpenAPI document.
+pub fn openapi_version() -> &'static str {
+    openapi_info("version")
+}
+
+/// The `info.title` of the embedded OpenAPI document.
+pub fn openapi_title() -> &'static str {
+    openapi_info("title")
+}
+
+fn openapi_info(field: &str) -> &'static str {
+    OPENAPI
+        .get("info")
+        .and_then(|info| info.get(field))
+        .and_then(|value| value.as_str())
+        .unwrap_or_else(|| panic!("openapi.json info.{field}"))
+}
+
+/// Names of the split component schemas, including the local additions.
+pub fn schema_component_names() -> Vec<&'static str> {
+    SPLIT_COMPONENTS.keys().map(String::as_str).collect()
+}
+
+/// Whether [`openapi_version`] shares a semver major version with `expected`.
+pub fn is_compatible_with(expected: &str) -> bool {
+    fn major(version: &str) -> Option<u64> {
+        let version = version.trim().trim_start_matches('v');
+        version.split('.').next()?.parse().ok()
+    }
+    match (major(openapi_version()), major(expected)) {
+        (Some(actual), Some(expected)) => actual == expected,
+        _ => false,
+    }
+}
+
+pub fn allowed_stream_event_types() -> &'static [String] {
+    &STREAM_EVENT_TYPES
+}
+
+/// Required field names of the streaming event variant whose `type` is
+/// `event_type`, in schema order; `None` for unknown event types.
+pub fn stream_event_required_fields(event_type: &str) -> Option<Vec<String>> {
+    STREAM_SCHEMA
+        .get("oneOf")?
+        .as_array()?
+        .iter()
+        .filter_map(|branch| branch.get("$ref")?.as_str()?.rsplit('/').next())
+        .filter_map(split_component_schema)
+        .find(|schema| {
+            schema
+                .pointer("/properties/type/enum")
+                .and_then(Value::as_array)
+                .is_some_and(|types| types.iter().any(|value| value == event_type))
+        })
+        .map(|schema| {
+            schema
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|required| {
+                    required
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+}
+
+pub fn streaming_event_schema() -> &'static Value {
+    &STREAM_SCHEMA
+}
+
+pub fn response_resource_schema() -> &'static Value {
+    &RESPONSE_SCHEMA
+}
+
+pub fn create_response_body_schema() -> &'static Value {
+    &CREATE_RESPONSE_SCHEMA
+}
+
+pub fn tool_param_schema() -> &'static Value {
+    &TOOL_PARAM_SCHEMA
+}
+
+pub fn tool_choice_param_schema() -> &'static Value {
+    &TOOL_CHOICE_SCHEMA
+}
+
+pub fn item_param_schema() -> &'static Value {
+    &ITEM_PARAM_SCHEMA
+}
+
+/// Forces compilation of every schema validator so the first validation on a
+/// request path does not pay the compile cost. Idempotent and safe to call
+/// from multiple threads: concurrent callers block until the one compile
+/// finishes.
+pub fn warm_validators() {
+    Lazy::force(&STREAM_VALIDATOR);
+    Lazy::force(&STREAM_ENVELOPE_VALIDATOR);
+    Lazy::force(&RESPONSE_VALIDATOR);
+    Lazy::force(&CREATE_RESPONSE_VALIDATOR);
+    Lazy::force(&TOOL_PARAM_VALIDATOR);
+    Lazy::force(&TOOL_CHOICE_VALIDATOR);
+    Lazy::force(&SPECIFIC_TOOL_CHOICE_VALIDATOR);
+    Lazy::force(&ITEM_PARAM_VARIANT_VALIDATORS);
+}
+
+fn split_component_schema(name: &str) -> Option<&'static Value> {
+    SPLIT_COMPONENTS.get(name)
+}
+
+pub(crate) fn compile_split_schema(name: &str) -> JSONSchema {
+    let root_ref = serde_json::json!({
+        "$ref": format!("{SPLIT_COMPONENTS_URI_PREFIX}{name}")
+    });
+    compile_split_root(&root_ref, name)
+}
+
+fn compile_split_root(root: &Value, label: &str) -> JSONSchema {
+    let mut options = JSONSchema::options();
+    for (schema_name, schema) in SPLIT_COMPONENTS.iter() {
+        let uri = format!("{SPLIT_COMPONENTS_URI_PREFIX}{schema_name}");
+        options.with_document(uri, schema.clone());
+    }
+    options
+        .compile(root)
+        .unwrap_or_else(|_| panic!("compile split schema {label}"))
+}
+
+/// Groups a component's `oneOf` branches by the `type` values they accept
+/// (`properties.type.enum`, or the `enum`s under `properties.type.anyOf`):
+/// `type` value -> names of the branch components that accept it.
+fn discriminated_variants(component: &str) -> BTreeMap<&'static str, Vec<&'static str>> {
+    let branches = split_component_schema(component)
+        .and_then(|schema| schema.get("oneOf"))
+        .and_then(Value::as_array)
+        .unwrap_or_else(|| panic!("{component} oneOf"));
+    let mut variants: BTreeMap<&'static str, Vec<&'static str>> = BTreeMap::new();
+    for branch in branches {
+        let Some(name) = branch
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.rsplit('/').next())
+        else {
+            continue;
+        };
+        let Some((name, type_schema)) = SPLIT_COMPONENTS
+            .get_key_value(name)
+            .and_then(|(name, schema)| Some((name.as_str(), schema.pointer("/properties/type")?)))
+        else {
+            continue;
+        };
+        let enums = std::iter::once(type_schema)
+            .chain(
+                type_schema
+                    .get("anyOf")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten(),
+            )
+            .filter_map(|schema| schema.get("enum").and_then(Value::as_array))
+            .flatten()
+            .filter_map(Value::as_str);
+        for variant_type in enums {
+            variants.entry(variant_type).or_default().push(name);
+        }
+    }
+    variants
+}
+
+/// Applies the `ItemParam` `type` discriminator: compiles a validator per
+/// value covering the branches `discriminated_variants` maps it to.
+fn compile_item_param_variant_validators() -> BTreeMap<String, JSONSchema> {
+    discriminated_variants("ItemParam.json")
+        .into_iter()
+        .map(|(item_type, names)| {
+            let mut refs: Vec<Value> = names
+                .into_iter()
+                .map(|name| serde_json::json!({ "$ref": format!("{SPLIT_COMPONENTS_URI_PREFIX}{name}") }))
+                .collect();
+            let root = if refs.len() == 1 {
+                refs.remove(0)
+            } else {
+                serde_json::json!({ "oneOf": refs })
+            };
+            let validator = compile_split_root(&root, &format!("ItemParam({item_type})"));
+            (item_type.to_string(), validator)
+        })
+        .collect()
+}
+
+fn compile_split_stream_schema(components: &BTreeMap<String, Value>) -> JSONSchema {
+    let mut options = JSONSchema::options();
+    for (schema_name, schema) in components {
+        let uri = format!("{SPLIT_COMPONENTS_URI_PREFIX}{schema_name}");
+        options.with_document(uri, schema.clone());
+    }
+    options.with_document(SPLIT_PATHS_URI.to_string(), SPLIT_PATHS_RESPONSES.clone());
+    let root_ref = serde_json::json!({
+        "$ref": format!("{SPLIT_PATHS_URI}#/post/responses/200/content/text~1event-stream/schema")
+    });
+    options
+        .compile(&root_ref)
+        .expect("compile split streaming schema")
+}
+
+fn stream_envelope_components() -> BTreeMap<String, Value> {
+    let mut components = SPLIT_COMPONENTS.clone();
+    for (name, schema) in components.iter_mut() {
+        if !name.ends_with("StreamingEvent.json") {
+            continue;
+        }
+        if let Some(response) = schema.pointer_mut("/properties/response") {
+            *response = serde_json::json!({ "type": "object" });
+        }
+    }
+    components
+}
+
+fn extract_split_streaming_schema() -> Option<Value> {
+    let pointer = "/post/responses/200/content/text~1event-stream/schema";
+    SPLIT_PATHS_RESPONSES.pointer(pointer).cloned()
+}
+
+#[cfg(test)]
+pub(crate) fn extract_component_schema(name: &str) -> Option<Value> {
+    let pointer = format!("/components/schemas/{name}");
+    OPENAPI.pointer(&pointer).cloned()
+}
@@ -12,7 +12,7 @@ fn validate_helper_error_paths() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("ItemReferenceParam.type must be")),
+            .any(|err| err.message.contains("ItemReferenceParam.type must be")),
         "errors: {errors:?}"
     );
 
@@ -61,19 +61,19 @@ fn validate_item_param_reports_missing_required_fields() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `call_id`")),
+            .any(|err| err.message.contains("missing required field `call_id`")),
         "errors: {errors:?}"
     );
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `name`")),
+            .any(|err| err.message.contains("missing required field `name`")),
         "errors: {errors:?}"
     );
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `arguments`")),
+            .any(|err| err.message.contains("missing required field `arguments`")),
         "errors: {errors:?}"
     );
 }
@@ -88,7 +88,7 @@ fn validate_item_param_reports_missing_message_fields() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `role`")),
+            .any(|err| err.message.contains("missing required field `role`")),
         "errors: {errors:?}"
     );
 
@@ -102,7 +102,7 @@ fn validate_item_param_reports_missing_message_fields() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `content`")),
+            .any(|err| err.message.contains("missing required field `content`")),
         "errors: {errors:?}"
     );
 }
@@ -119,7 +119,7 @@ fn validate_item_param_reports_missing_output_fields() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `output`")),
+            .any(|err| err.message.contains("missing required field `output`")),
         "errors: {errors:?}"
     );
 }
@@ -136,7 +136,7 @@ fn validate_item_param_reports_missing_query_fields() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `queries`")),
+            .any(|err| err.message.contains("missing required field `queries`")),
         "errors: {errors:?}"
     );
 }
@@ -152,7 +152,7 @@ fn validate_item_param_reports_missing_structured_fields() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `summary`")),
+            .any(|err| err.message.contains("missing required field `summary`")),
         "errors: {errors:?}"
     );
 
@@ -166,7 +166,7 @@ fn validate_item_param_reports_missing_structured_fields() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `action`")),
+            .any(|err| err.message.contains("missing required field `action`")),
         "errors: {errors:?}"
     );
 
@@ -180,7 +180,7 @@ fn validate_item_param_reports_missing_structured_fields() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `approve`")),
+            .any(|err| err.message.contains("missing required field `approve`")),
         "errors: {errors:?}"
     );
 }
@@ -196,13 +196,13 @@ fn validate_item_param_reports_invalid_field_types() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("role must be a string")),
+            .any(|err| err.message.contains("role must be a string")),
         "errors: {errors:?}"
     );
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("content must be a string or array")),
+            .any(|err| err.message.contains("content must be a string or array")),
         "errors: {errors:?}"
     );
 
@@ -213,7 +213,9 @@ fn validate_item_param_reports_invalid_field_types() {
     });
     let errors = validate_item_param(&invalid_role).err().unwrap_or_default();
     assert!(
-        errors.iter().any(|err| err.contains("role must be one of")),
+        errors
+            .iter()
+            .any(|err| err.message.contains("role must be one of")),
         "errors: {errors:?}"
     );
 
@@ -225,7 +227,7 @@ fn validate_item_param_reports_invalid_field_types() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("summary must be an array")),
+            .any(|err| err.message.contains("summary must be an array")),
         "errors: {errors:?}"
     );
 
@@ -240,7 +242,7 @@ fn validate_item_param_reports_invalid_field_types() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("action must be an object")),
+            .any(|err| err.message.contains("action must be an object")),
         "errors: {errors:?}"
     );
 
@@ -253,7 +255,7 @@ fn validate_item_param_reports_invalid_field_types() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("approve must be a boolean")),
+            .any(|err| err.message.contains("approve must be a boolean")),
         "errors: {errors:?}"
     );
 }
@@ -271,7 +273,7 @@ fn validate_item_param_reports_file_search_query_errors() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("queries must not be empty")),
+            .any(|err| err.message.contains("queries must not be empty")),
         "errors: {errors:?}"
     );
 
@@ -284,7 +286,7 @@ fn validate_item_param_reports_file_search_query_errors() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("queries[1] must be a string")),
+            .any(|err| err.message.contains("queries[1] must be a string")),
         "errors: {errors:?}"
     );
 }
@@ -297,9 +299,9 @@ fn validate_item_param_reports_item_reference_errors() {
     });
     let errors = validate_item_param(&value).err().unwrap_or_default();
     assert!(
-        errors
-            .iter()
-            .any(|err| err.contains("ItemReferenceParam.type must be a string or null")),
+        errors.iter().any(|err| err
+            .message
+            .contains("ItemReferenceParam.type must be a string or null")),
         "errors: {errors:?}"
     );
 
@@ -310,7 +312,7 @@ fn validate_item_param_reports_item_reference_errors() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("missing required field `id`")),
+            .any(|err| err.message.contains("missing required field `id`")),
         "errors: {errors:?}"
     );
 }
@@ -322,7 +324,9 @@ fn validate_item_param_reports_unknown_type() {
     });
     let errors = validate_item_param(&value).err().unwrap_or_default();
     assert!(
-        errors.iter().any(|err| err.contains("unsupported value")),
+        errors
+            .iter()
+            .any(|err| err.message.contains("unsupported value")),
         "errors: {errors:?}"
     );
 }
@@ -347,7 +351,9 @@ fn validate_item_param_rejects_prefixed_extension_items_missing_required_fields(
 
     let errors = validate_item_param(&value).err().unwrap_or_default();
     assert!(
-        errors.iter().any(|err| err.contains("unsupported value")),
+        errors
+            .iter()
+            .any(|err| err.message.contains("unsupported value")),
         "errors: {errors:?}"
     );
 }
@@ -366,19 +372,19 @@ fn validate_item_param_reports_additional_type_errors() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("call_id must be a string")),
+            .any(|err| err.message.contains("call_id must be a string")),
         "errors: {errors:?}"
     );
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("name must be a string")),
+            .any(|err| err.message.contains("name must be a string")),
         "errors: {errors:?}"
     );
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("arguments must be a string")),
+            .any(|err| err.message.contains("arguments must be a string")),
         "errors: {errors:?}"
     );
 
@@ -393,7 +399,7 @@ fn validate_item_param_reports_additional_type_errors() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("output must be a string or array")),
+            .any(|err| err.message.contains("output must be a string or array")),
         "errors: {errors:?}"
     );
 
@@ -408,7 +414,7 @@ fn validate_item_param_reports_additional_type_errors() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("queries must be an array")),
+            .any(|err| err.message.contains("queries must be an array")),
         "errors: {errors:?}"
     );
 
@@ -423,7 +429,169 @@ fn validate_item_param_reports_additional_type_errors() {
     assert!(
         errors
             .iter()
-            .any(|err| err.contains("output must be a string")),
+            .any(|err| err.message.contains("output must be a string")),
         "errors: {errors:?}"
     );
 }
+
+#[test]
+fn validate_item_param_checks_input_audio_content() {
+    let message = |audio: Value| {
+        serde_json::json!({
+            "type": "message",
+            "role": "user",
+            "content": [audio]
+        })
+    };
+    assert!(validate_item_param(&message(serde_json::json!({
+        "type": "input_audio",
+        "input_audio": { "data": "SUQz", "format": "mp3" }
+    })))
+    .is_ok());
+
+    let errors = validate_item_param(&message(serde_json::json!({
+        "type": "input_audio",
+        "input_audio": { "format": "mp3" }
+    })))
+    .expect_err("missing data");
+    assert_eq!(
+        errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        vec!["ItemParam(message).content[0]: \"data\" is a required property"]
+    );
+}
+
+#[test]
+fn supported_item_param_types_are_all_accepted() {
+    let fixtures: Vec<(&str, Value)> = vec![
+        (
+            "message",
+            serde_json::json!({ "role": "user", "content": "hi" }),
+        ),
+        (
+            "function_call",
+            serde_json::json!({ "call_id": "c1", "name": "echo", "arguments": "{}" }),
+        ),
+        (
+            "function_call_output",
+            serde_json::json!({ "call_id": "c1", "output": "ok" }),
+        ),
+        ("reasoning", serde_json::json!({ "summary": [] })),
+        (
+            "compaction",
+            serde_json::json!({ "encrypted_content": "enc" }),
+        ),
+        (
+            "code_interpreter_call",
+            serde_json::json!({ "id": "ci1", "container_id": "cntr_1", "code": "print(1)" }),
+        ),
+        (
+            "computer_call",
+            serde_json::json!({ "call_id": "cc1", "action": {} }),
+        ),
+        (
+            "computer_call_output",
+            serde_json::json!({ "call_id": "cc1", "output": {} }),
+        ),
+        (
+            "custom_tool_call",
+            serde_json::json!({ "call_id": "ct1", "name": "tool", "input": "in" }),
+        ),
+        (
+            "custom_tool_call_output",
+            serde_json::json!({ "call_id": "ct1", "output": "out" }),
+        ),
+        (
+            "file_search_call",
+            serde_json::json!({ "id": "fs1", "queries": ["q1"] }),
+        ),
+        ("web_search_call", serde_json::json!({})),
+        ("image_generation_call", serde_json::json!({ "id": "ig1" })),
+        (
+            "local_shell_call",
+            serde_json::json!({ "call_id": "ls1", "action": {} }),
+        ),
+        (
+            "local_shell_call_output",
+            serde_json::json!({ "call_id": "ls1", "output": "ok" }),
+        ),
+        (
+            "shell_call",
+            serde_json::json!({ "call_id": "sh1", "action": {} }),
+        ),
+        (
+            "shell_call_output",
+            serde_json::json!({ "call_id": "sh1", "output": [] }),
+        ),
+        (
+            "apply_patch_call",
+            serde_json::json!({ "call_id": "ap1", "status": "in_progress", "operation": {} }),
+        ),
+        (
+            "apply_patch_call_output",
+            serde_json::json!({ "call_id": "ap1", "status": "completed" }),
+        ),
+        (
+            "mcp_approval_request",
+            serde_json::json!({ "server_label": "srv", "name": "tool", "arguments": "{}" }),
+        ),
+        (
+            "mcp_approval_response",
+            serde_json::json!({ "approval_request_id": "ar1", "approve": true }),
+        ),
+        ("item_reference", serde_json::json!({ "id": "item_1" })),
+    ];
+
+    let mut fixture_types: Vec<&str> = fixtures.iter().map(|(item_type, _)| *item_type).collect();
+    let mut supported = supported_item_param_types().to_vec();
+    fixture_types.sort_unstable();
+    supported.sort_unstable();
+    assert_eq!(supported, fixture_types);
+
+    for (item_type, mut value) in fixtures {
+        value["type"] = Value::String(item_type.to_string());
+        let errors = validate_item_param(&value).err().unwrap_or_default();
+        assert!(errors.is_empty(), "errors: {errors:?} for {value}");
+    }
+
+    let unknown = serde_json::json!({ "type": "not_an_item" });
+    assert!(validate_item_param(&unknown).is_err());
+}
+
+#[test]
+fn validate_item_param_schema_rejects_function_call_missing_arguments() {
+    let value = serde_json::json!({
+        "type": "function_call",
+        "call_id": "c1",
+        "name": "echo"
+    });
+    let errors = validate_item_param_schema(&value).expect_err("missing arguments");
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].code, "required");
+    assert_eq!(errors[0].instance_path, "");
+    assert!(
+        errors[0].message.contains("\"arguments\""),
+        "message: {}",
+        errors[0].message
+    );
+
+    let mut complete = value;
+    complete["arguments"] = serde_json::json!("{}");
+    assert!(validate_item_param_schema(&complete).is_ok());
+}
+
+#[test]
+fn validate_item_param_schema_selects_branch_by_type() {
+    for role in ["assistant", "developer", "system", "user"] {
+        let value = serde_json::json!({ "type": "message", "role": role, "content": "hi" });
+        let errors = validate_item_param_schema(&value).err().unwrap_or_default();
+        assert!(errors.is_empty(), "errors: {errors:?} for {value}");
+    }
+    assert!(validate_item_param_schema(&serde_json::json!({ "id": "item_1" })).is_ok());
+
+    let errors = validate_item_param_schema(&serde_json::json!({ "type": "bogus" }))
+        .expect_err("unknown type");
+    assert_eq!(errors[0].code, "invalid_value");
+    assert_eq!(errors[0].instance_path, "/type");
+
+    assert!(validate_item_param_schema(&serde_json::json!("nope")).is_err());
+}
@@ -1,4 +1,7 @@
 use super::*;
+use crate::item_param::validate_item_reference;
+use crate::schema::{compile_split_schema, extract_component_schema, OPENAPI};
+use jsonschema::JSONSchema;
 
 fn fixture_response_resource() -> Value {
     let raw =
@@ -43,11 +43,13 @@ fn validate_create_response_body_reports_invalid_tool_fields() {
         .err()
         .unwrap_or_default();
     assert!(
-        errors.iter().any(|err| err.contains("tools must be")),
+        errors
+            .iter()
+            .any(|err| err.message.contains("tools must be")),
         "errors: {errors:?}"
     );
     assert!(
-        errors.iter().any(|err| err.contains("tool_choice")),
+        errors.iter().any(|err| err.message.contains("tool_choice")),
         "errors: {errors:?}"
     );
 }
@@ -103,6 +105,37 @@ fn validate_tool_param_accepts_all_variants() {
     }
 }
 
+#[test]
+fn classify_tool_param_reports_matched_variant() {
+    let cases = [
+        (
+            serde_json::json!({ "type": "function", "name": "echo" }),
+            Some("function"),
+        ),
+        (
+            serde_json::json!({ "type": "mcp", "server_label": "srv" }),
+            Some("mcp"),
+        ),
+        (
+            serde_json::json!({ "type": "file_search", "vector_store_ids": ["vs_1"] }),
+            Some("file_search"),
+        ),
+        (
+            serde_json::json!({ "type": "file_search", "max_num_results": "nope" }),
+            None,
+        ),
+        (
+            serde_json::json!({ "type": "apply_patch" }),
+            Some("apply_patch"),
+        ),
+        (serde_json::json!({ "type": "openrouter:web_search" }), None),
+        (serde_json::json!(42), None),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(classify_tool_param(&value), expected, "for {value}");
+    }
+}
+
 #[test]
 fn validate_tool_param_accepts_provider_extension_tools() {
     let value = serde_json::json!({
@@ -232,6 +265,29 @@ fn validate_tool_choice_param_rejects_allowed_tools_invalid_tool() {
     assert!(validate_tool_choice_param(&value).is_err());
 }
 
+#[test]
+fn validate_tool_choice_param_reports_unknown_allowed_tool_type() {
+    let value = serde_json::json!({
+        "type": "allowed_tools",
+        "tools": [
+            { "type": "function", "name": "echo" },
+            { "type": "bogus" }
+        ]
+    });
+    let errors = validate_tool_choice_param(&value).err().unwrap_or_default();
+    assert!(
+        errors.iter().any(|err| err.instance_path == "/tools/1"
+            && err.message.starts_with("allowed_tools.tools[1]: ")),
+        "errors: {errors:?}"
+    );
+    assert!(
+        errors
+            .iter()
+            .all(|err| !err.message.starts_with("allowed_tools.tools[0]: ")),
+        "errors: {errors:?}"
+    );
+}
+
 #[test]
 fn validate_tool_choice_param_rejects_allowed_tools_invalid_mode() {
     let value = serde_json::json!({
@@ -249,3 +305,151 @@ fn validate_tool_choice_param_rejects_invalid() {
     let value = serde_json::json!(false);
     assert!(validate_tool_choice_param(&value).is_err());
 }
+
+#[test]
+fn validate_create_response_body_checks_input_audio_blocks() {
+    let body = |audio: Value| {
+        serde_json::json!({
+            "model": "gpt-4.1",
+            "input": [{
+                "type": "message",
+                "role": "user",
+                "content": [
+                    { "type": "input_text", "text": "transcribe this" },
+                    audio
+                ]
+            }]
+        })
+    };
+
+    let well_formed = serde_json::json!({
+        "type": "input_audio",
+        "input_audio": { "data": "UklGRg==", "format": "wav" }
+    });
+    let errors = validate_create_response_body(&body(well_formed.clone()))
+        .err()
+        .unwrap_or_default();
+    assert!(errors.is_empty(), "errors: {errors:?}");
+
+    let missing_source = serde_json::json!({ "type": "input_audio" });
+    let errors = validate_create_response_body(&body(missing_source))
+        .err()
+        .unwrap_or_default();
+    assert!(
+        errors
+            .iter()
+            .any(|err| err.instance_path == "/input/0/content/1" && err.code == "required"),
+        "errors: {errors:?}"
+    );
+
+    let bad_format = serde_json::json!({
+        "type": "input_audio",
+        "input_audio": { "data": "UklGRg==", "format": "flac" }
+    });
+    let errors = validate_create_response_body(&body(bad_format))
+        .err()
+        .unwrap_or_default();
+    assert!(
+        errors
+            .iter()
+            .any(|err| err.instance_path == "/input/0/content/1/input_audio/format"),
+        "errors: {errors:?}"
+    );
+}
+
+#[test]
+fn validate_create_response_body_reports_structured_error_paths() {
+    let value = serde_json::json!({
+        "model": "gpt-4.1",
+        "input": [{
+            "type": "message",
+            "role": "user",
+            "content": [{ "type": "input_audio", "input_audio": { "data": "AAAA", "format": "ogg" } }]
+        }],
+        "tools": "nope",
+        "temperature": "hot"
+    });
+    let errors = validate_create_response_body(&value)
+        .err()
+        .unwrap_or_default();
+
+    let tools = errors
+        .iter()
+        .find(|err| err.instance_path == "/tools")
+        .expect("tools error");
+    assert_eq!(tools.code, "invalid_type");
+    assert_eq!(tools.to_string(), "tools must be an array or null");
+
+    let audio = errors
+        .iter()
+        .find(|err| err.instance_path == "/input/0/content/0/input_audio/format")
+        .expect("audio format error");
+    assert_eq!(audio.code, "enum");
+    assert_eq!(
+        audio.to_string(),
+        "input[0]: ItemParam(message).content[0]: \"ogg\" is not one of [\"mp3\",\"wav\"]"
+    );
+
+    let temperature = errors
+        .iter()
+        .find(|err| err.instance_path == "/temperature")
+        .expect("schema error");
+    assert!(!temperature.schema_path.is_empty(), "errors: {errors:?}");
+    assert!(!temperature.code.is_empty(), "errors: {errors:?}");
+}
+
+#[test]
+fn validate_create_response_body_validates_input_item_params() {
+    let value = serde_json::json!({
+        "model": "gpt-4.1",
+        "input": [
+            { "type": "message", "role": "user", "content": "hi" },
+            { "type": "function_call", "name": "echo", "arguments": "{}" }
+        ]
+    });
+    let errors = validate_create_response_body(&value)
+        .err()
+        .unwrap_or_default();
+    let call_id = errors
+        .iter()
+        .find(|err| {
+            err.message == "input[1]: ItemParam(function_call) missing required field `call_id`"
+        })
+        .unwrap_or_else(|| panic!("errors: {errors:?}"));
+    assert_eq!(call_id.instance_path, "/input/1/call_id");
+    assert!(
+        errors
+            .iter()
+            .all(|err| !err.message.starts_with("input[0]")),
+        "errors: {errors:?}"
+    );
+
+    let string_input = serde_json::json!({ "model": "gpt-4.1", "input": "hi" });
+    assert!(validate_create_response_body(&string_input).is_ok());
+}
+
+#[test]
+fn validate_create_response_body_orders_errors_by_path_then_message() {
+    let value = serde_json::json!({
+        "model": 7,
+        "input": [
+            { "type": "function_call" },
+            { "type": "message", "role": "robot", "content": 1 }
+        ],
+        "tools": [{ "type": "function" }, "nope"],
+        "tool_choice": { "type": "bogus" },
+        "temperature": "hot"
+    });
+    let first = validate_create_response_body(&value).expect_err("invalid");
+    let second = validate_create_response_body(&value).expect_err("invalid");
+    assert_eq!(first, second);
+    assert!(first.len() > 5, "{first:?}");
+
+    let keys: Vec<(&str, &str)> = first
+        .iter()
+        .map(|err| (err.instance_path.as_str(), err.message.as_str()))
+        .collect();
+    let mut sorted = keys.clone();
+    sorted.sort();
+    assert_eq!(keys, sorted);
+}
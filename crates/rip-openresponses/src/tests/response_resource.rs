@@ -376,3 +376,117 @@ fn validate_response_resource_accepts_code_interpreter_call() {
     let errors = validate_response_resource(&value).err().unwrap_or_default();
     assert!(errors.is_empty(), "errors: {errors:?} for {item}");
 }
+
+#[test]
+fn extract_response_error_reads_failed_response() {
+    let mut response = fixture_response_resource();
+    if let Value::Object(map) = &mut response {
+        map.insert("status".to_string(), Value::String("failed".to_string()));
+        map.insert(
+            "error".to_string(),
+            serde_json::json!({
+                "code": "server_error",
+                "message": "The model crashed.",
+                "type": "server_error"
+            }),
+        );
+    }
+
+    let error = extract_response_error(&response).expect("error");
+    assert_eq!(error.code.as_deref(), Some("server_error"));
+    assert_eq!(error.message, "The model crashed.");
+    assert_eq!(error.type_.as_deref(), Some("server_error"));
+
+    let event = serde_json::json!({
+        "type": "response.failed",
+        "sequence_number": 3,
+        "response": response
+    });
+    assert_eq!(extract_response_error(&event), Some(error));
+
+    let mut ok = fixture_response_resource();
+    if let Value::Object(map) = &mut ok {
+        map.insert("error".to_string(), Value::Null);
+    }
+    assert!(extract_response_error(&ok).is_none());
+    assert!(extract_response_error(&serde_json::json!({"error": {"code": "x"}})).is_none());
+}
+
+#[test]
+fn usage_from_response_reads_completed_response() {
+    let mut response = fixture_response_resource();
+    if let Value::Object(map) = &mut response {
+        map.insert("status".to_string(), Value::String("completed".to_string()));
+        map.insert(
+            "usage".to_string(),
+            serde_json::json!({
+                "input_tokens": 12,
+                "input_tokens_details": { "cached_tokens": 4 },
+                "output_tokens": 7,
+                "output_tokens_details": {},
+                "total_tokens": 19
+            }),
+        );
+    }
+
+    let usage = Usage::from_response(&response).expect("usage");
+    assert_eq!(
+        usage,
+        Usage {
+            input_tokens: 12,
+            output_tokens: 7,
+            total_tokens: 19,
+            reasoning_tokens: None,
+            cached_tokens: Some(4),
+        }
+    );
+
+    let event = serde_json::json!({
+        "type": "response.completed",
+        "sequence_number": 9,
+        "response": response
+    });
+    assert_eq!(Usage::from_response(&event), Some(usage));
+
+    let mut no_usage = fixture_response_resource();
+    if let Value::Object(map) = &mut no_usage {
+        map.insert("usage".to_string(), Value::Null);
+    }
+    assert!(Usage::from_response(&no_usage).is_none());
+    assert!(Usage::from_response(&serde_json::json!({"usage": {"input_tokens": 1}})).is_none());
+}
+
+#[test]
+fn validate_response_resource_deep_applies_item_checks_to_output() {
+    let value = fixture_response_resource();
+    let errors = validate_response_resource_deep(&value)
+        .err()
+        .unwrap_or_default();
+    assert!(errors.is_empty(), "errors: {errors:?}");
+
+    let value = response_with_output(vec![
+        serde_json::json!({
+            "type": "mcp_list_tools",
+            "id": "list_1",
+            "server_label": "srv",
+            "tools": []
+        }),
+        serde_json::json!({
+            "type": "file_search_call",
+            "id": "fs1",
+            "status": "completed",
+            "queries": [],
+            "results": null
+        }),
+    ]);
+    assert!(validate_response_resource(&value).is_ok());
+    let errors = validate_response_resource_deep(&value)
+        .err()
+        .unwrap_or_default();
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].instance_path, "/output/1/queries");
+    assert_eq!(
+        errors[0].message,
+        "output[1]: ItemParam(file_search_call).queries must not be empty"
+    );
+}
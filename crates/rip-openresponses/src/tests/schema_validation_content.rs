@@ -267,3 +267,80 @@ fn validate_response_format_schemas() {
     );
     assert!(errors.is_empty(), "errors: {errors:?}");
 }
+
+#[test]
+fn validate_input_audio_content_block_schemas() {
+    let valid = serde_json::json!({
+        "type": "input_audio",
+        "input_audio": { "data": "UklGRg==", "format": "wav" }
+    });
+    let ogg = serde_json::json!({
+        "type": "input_audio",
+        "input_audio": { "data": "T2dnUw==", "format": "ogg" }
+    });
+
+    for name in ["InputAudioContent.json", "InputAudioContentParam.json"] {
+        let errors = schema_errors(name, valid.clone());
+        assert!(errors.is_empty(), "{name} errors: {errors:?}");
+        let errors = schema_errors(name, ogg.clone());
+        assert!(
+            errors.iter().any(|err| err.contains("ogg")),
+            "{name} errors: {errors:?}"
+        );
+    }
+
+    let message = |block: Value| {
+        serde_json::json!({
+            "type": "message",
+            "role": "user",
+            "content": [{ "type": "input_text", "text": "listen" }, block]
+        })
+    };
+    assert!(validate_item_param(&message(valid)).is_ok());
+    let errors = validate_item_param(&message(ogg)).expect_err("ogg rejected");
+    assert_eq!(
+        errors[0].to_string(),
+        "ItemParam(message).content[1]: \"ogg\" is not one of [\"mp3\",\"wav\"]"
+    );
+}
+
+#[test]
+fn normalize_input_image_detail_defaults_missing_detail_to_auto() {
+    let block = serde_json::json!({
+        "type": "input_image",
+        "image_url": "https://example.com/image.png",
+        "file_id": null
+    });
+    let errors = schema_errors("InputImageContent.json", block.clone());
+    assert!(
+        !errors.is_empty(),
+        "expected strict schema to require detail"
+    );
+
+    let mut item = serde_json::json!({
+        "type": "message",
+        "role": "user",
+        "content": [{ "type": "input_text", "text": "look" }, block]
+    });
+    normalize_input_image_detail(&mut item);
+    assert_eq!(item["content"][1]["detail"], "auto");
+    assert!(item["content"][0].get("detail").is_none());
+    let errors = schema_errors("InputImageContent.json", item["content"][1].clone());
+    assert!(errors.is_empty(), "errors: {errors:?}");
+    assert!(validate_item_param(&item).is_ok());
+
+    let mut body = serde_json::json!({
+        "input": [{
+            "type": "computer_call_output",
+            "call_id": "cc1",
+            "output": { "type": "computer_screenshot", "image_url": "https://example.com/s.png" }
+        }, {
+            "type": "message",
+            "role": "user",
+            "content": [{ "type": "input_image", "image_url": "https://example.com/i.png", "detail": "low" }]
+        }]
+    });
+    normalize_input_image_detail(&mut body);
+    assert_eq!(body["input"][0]["output"]["detail"], "auto");
+    assert_eq!(body["input"][1]["content"][0]["detail"], "low");
+}
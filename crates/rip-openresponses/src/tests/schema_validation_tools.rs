@@ -520,3 +520,40 @@ fn validate_custom_tool_and_format_schemas() {
     );
     assert!(errors.is_empty(), "errors: {errors:?}");
 }
+
+#[test]
+fn validate_function_tool_parameters_rejects_invalid_schema() {
+    let valid = serde_json::json!({
+        "type": "function",
+        "name": "lookup",
+        "parameters": {
+            "type": "object",
+            "properties": { "query": { "type": "string" } },
+            "required": ["query"]
+        }
+    });
+    assert!(validate_function_tool_parameters(&valid).is_ok());
+    assert!(validate_function_tool_parameters(&serde_json::json!({
+        "type": "function",
+        "name": "noop"
+    }))
+    .is_ok());
+
+    let malformed = serde_json::json!({
+        "type": "function",
+        "name": "lookup",
+        "parameters": { "type": 123 }
+    });
+    let errors = validate_function_tool_parameters(&malformed).unwrap_err();
+    assert!(
+        errors[0].message.contains("not a valid JSON Schema"),
+        "errors: {errors:?}"
+    );
+
+    let not_object = serde_json::json!({
+        "type": "function",
+        "name": "lookup",
+        "parameters": "object"
+    });
+    assert!(validate_function_tool_parameters(&not_object).is_err());
+}
@@ -39,3 +39,15 @@ fn tool_choice_param_schema_is_present() {
 fn item_param_schema_is_present() {
     assert!(item_param_schema().get("oneOf").is_some());
 }
+
+#[test]
+fn warm_validators_is_idempotent_across_threads() {
+    let handles: Vec<_> = (0..4)
+        .map(|_| std::thread::spawn(warm_validators))
+        .collect();
+    for handle in handles {
+        handle.join().expect("warm thread");
+    }
+    warm_validators();
+    assert!(validate_tool_choice_param(&serde_json::json!("auto")).is_ok());
+}
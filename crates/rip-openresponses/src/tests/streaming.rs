@@ -51,3 +51,99 @@ fn validate_stream_event_rejects_prefixed_extension_item_without_required_fields
 
     assert!(validate_stream_event(&value).is_err());
 }
+
+#[test]
+fn validate_stream_events_reports_every_line() {
+    let raw =
+        include_str!("../../../rip-provider-openresponses/fixtures/openresponses/stream_all.jsonl");
+    let valid = raw.lines().next().expect("fixture line");
+    let input = format!("{valid}\nnot json\n{{\"type\":\"bogus\"}}\n{valid}");
+
+    let results = validate_stream_events(input.lines());
+    let indices: Vec<usize> = results.iter().map(|(idx, _)| *idx).collect();
+    assert_eq!(indices, vec![0, 1, 2, 3]);
+    assert!(results[0].1.is_ok());
+    let json_errors = results[1].1.as_ref().expect_err("invalid json");
+    assert!(
+        json_errors[0].message.starts_with("invalid json:"),
+        "errors: {json_errors:?}"
+    );
+    assert!(results[2].1.is_err());
+    assert!(results[3].1.is_ok());
+}
+
+#[test]
+fn validate_stream_events_accepts_fixture_stream() {
+    let raw =
+        include_str!("../../../rip-provider-openresponses/fixtures/openresponses/stream_all.jsonl");
+    let failures: Vec<_> = validate_stream_events(raw.lines())
+        .into_iter()
+        .filter(|(_, result)| result.is_err())
+        .collect();
+    assert!(failures.is_empty(), "failures: {failures:?}");
+}
+
+#[test]
+fn validate_stream_event_full_separates_event_and_response_errors() {
+    let response = fixture_response_resource();
+    let completed = serde_json::json!({
+        "type": "response.completed",
+        "sequence_number": 1,
+        "response": response
+    });
+    let report = validate_stream_event_full(&completed);
+    assert!(report.is_valid(), "report: {report:?}");
+
+    let mut broken = response.clone();
+    if let Value::Object(map) = &mut broken {
+        map.remove("id");
+    }
+    let report = validate_stream_event_full(&serde_json::json!({
+        "type": "response.completed",
+        "sequence_number": 2,
+        "response": broken
+    }));
+    assert!(!report.response_errors.is_empty(), "report: {report:?}");
+    assert!(report.event_errors.is_empty(), "report: {report:?}");
+    assert!(!report.is_valid());
+
+    let report = validate_stream_event_full(&serde_json::json!({
+        "type": "response.output_text.delta",
+        "sequence_number": 3
+    }));
+    assert!(!report.event_errors.is_empty(), "report: {report:?}");
+    assert!(report.response_errors.is_empty(), "report: {report:?}");
+}
+
+#[test]
+fn validate_stream_event_envelope_skips_nested_response() {
+    let event = serde_json::json!({
+        "type": "response.in_progress",
+        "sequence_number": 1,
+        "response": {}
+    });
+    assert!(validate_stream_event(&event).is_err());
+    assert!(validate_stream_event_envelope(&event).is_ok());
+
+    let not_object = serde_json::json!({
+        "type": "response.in_progress",
+        "sequence_number": 1,
+        "response": "resp_1"
+    });
+    assert!(validate_stream_event_envelope(&not_object).is_err());
+    assert!(validate_stream_event_envelope(&serde_json::json!({
+        "type": "response.in_progress",
+        "sequence_number": 1
+    }))
+    .is_err());
+}
+
+#[test]
+fn stream_event_required_fields_reads_matching_variant() {
+    let required = stream_event_required_fields("response.output_text.delta").expect("known type");
+    assert!(required.iter().any(|field| field == "delta"));
+    assert!(required.iter().any(|field| field == "sequence_number"));
+    assert!(required.iter().any(|field| field == "type"));
+
+    assert!(stream_event_required_fields("response.made_up").is_none());
+}
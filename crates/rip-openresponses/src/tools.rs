@@ -0,0 +1,92 @@
+use jsonschema::JSONSchema;
+use serde_json::Value;
+
+use crate::extension::is_extension_slug;
+use crate::schema::{
+    SPECIFIC_TOOL_CHOICE_VALIDATOR, TOOL_CHOICE_VALIDATOR, TOOL_PARAM_TYPES, TOOL_PARAM_VALIDATOR,
+};
+use crate::validation_error::{into_result, schema_errors};
+use crate::ValidationError;
+
+pub fn validate_responses_tool_param(value: &Value) -> Result<(), Vec<ValidationError>> {
+    if is_provider_extension_tool_param(value) {
+        return Ok(());
+    }
+
+    into_result(schema_errors(&TOOL_PARAM_VALIDATOR, value))
+}
+
+/// The `type` of the `ResponsesToolParam` variant `value` matches, or `None`
+/// when it fails schema validation. Provider extension tools
+/// (`slug:name`) have no canonical variant and also yield `None`.
+pub fn classify_tool_param(value: &Value) -> Option<&'static str> {
+    if !TOOL_PARAM_VALIDATOR.is_valid(value) {
+        return None;
+    }
+    let tool_type = value.get("type").and_then(Value::as_str)?;
+    TOOL_PARAM_TYPES
+        .iter()
+        .copied()
+        .find(|known| *known == tool_type)
+}
+
+/// Checks that a `function` tool's `parameters` is itself a compilable JSON
+/// Schema. A missing or `null` `parameters` is accepted (the field is optional).
+pub fn validate_function_tool_parameters(value: &Value) -> Result<(), Vec<ValidationError>> {
+    let parameters = match value.get("parameters") {
+        None | Some(Value::Null) => return Ok(()),
+        Some(parameters @ Value::Object(_)) => parameters,
+        Some(_) => {
+            return Err(vec![ValidationError::new(
+                "invalid_type",
+                "/parameters",
+                "parameters must be a JSON Schema object",
+            )])
+        }
+    };
+    match JSONSchema::compile(parameters) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(vec![ValidationError::new(
+            "invalid_schema",
+            "/parameters",
+            format!("parameters is not a valid JSON Schema: {err}"),
+        )]),
+    }
+}
+
+fn is_provider_extension_tool_param(value: &Value) -> bool {
+    let Some(map) = value.as_object() else {
+        return false;
+    };
+    let Some(tool_type) = map.get("type").and_then(Value::as_str) else {
+        return false;
+    };
+    let Some((slug, name)) = tool_type.split_once(':') else {
+        return false;
+    };
+    is_extension_slug(slug) && !name.trim().is_empty()
+}
+
+pub fn validate_tool_choice_param(value: &Value) -> Result<(), Vec<ValidationError>> {
+    let mut errors = schema_errors(&TOOL_CHOICE_VALIDATOR, value);
+    // The `oneOf` failure above is opaque; name the offending allowed tool.
+    if value.get("type").and_then(Value::as_str) == Some("allowed_tools") {
+        if let Some(tools) = value.get("tools").and_then(Value::as_array) {
+            for (idx, tool) in tools.iter().enumerate() {
+                if let Err(errs) = validate_specific_tool_choice_param(tool) {
+                    errors.extend(errs.into_iter().map(|err| {
+                        err.nested(
+                            &format!("/tools/{idx}"),
+                            &format!("allowed_tools.tools[{idx}]: "),
+                        )
+                    }));
+                }
+            }
+        }
+    }
+    into_result(errors)
+}
+
+pub fn validate_specific_tool_choice_param(value: &Value) -> Result<(), Vec<ValidationError>> {
+    into_result(schema_errors(&SPECIFIC_TOOL_CHOICE_VALIDATOR, value))
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A single validation failure. `Display` renders `message`, which is the
+/// same text the validators produced before errors were structured.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationError {
+    /// JSON Pointer to the offending value (`""` is the validated value itself).
+    pub instance_path: String,
+    /// JSON Pointer into the schema that rejected the value; empty for
+    /// hand-written checks that do not go through `jsonschema`.
+    pub schema_path: String,
+    /// Machine-readable reason: the `jsonschema` error kind in snake_case
+    /// (`type`, `required`, `enum`, ...) or one of the hand-written codes
+    /// (`missing_field`, `invalid_type`, `invalid_value`, `invalid_schema`,
+    /// `invalid_json`).
+    pub code: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    pub(crate) fn new(
+        code: &str,
+        instance_path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            instance_path: instance_path.into(),
+            schema_path: String::new(),
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn from_schema(err: jsonschema::ValidationError<'_>) -> Self {
+        Self {
+            instance_path: err.instance_path.to_string(),
+            schema_path: err.schema_path.to_string(),
+            code: schema_error_code(&err.kind),
+            message: err.to_string(),
+        }
+    }
+
+    /// Re-roots an error found in a nested value: `pointer` is prepended to
+    /// `instance_path` and `label` (e.g. `tools[0]: `) to the message.
+    pub(crate) fn nested(mut self, pointer: &str, label: &str) -> Self {
+        self.instance_path = format!("{pointer}{}", self.instance_path);
+        self.message = format!("{label}{}", self.message);
+        self
+    }
+}
+
+/// Every public validator returns through here: errors are sorted by
+/// `instance_path`, then `message`, so the order is stable across runs
+/// regardless of `jsonschema`'s iteration order.
+pub(crate) fn into_result(mut errors: Vec<ValidationError>) -> Result<(), Vec<ValidationError>> {
+    if errors.is_empty() {
+        return Ok(());
+    }
+    errors.sort_by(|a, b| {
+        a.instance_path
+            .cmp(&b.instance_path)
+            .then_with(|| a.message.cmp(&b.message))
+    });
+    Err(errors)
+}
+
+/// Collects a compiled schema's failures for `value` (empty when valid).
+pub(crate) fn schema_errors(
+    schema: &jsonschema::JSONSchema,
+    value: &serde_json::Value,
+) -> Vec<ValidationError> {
+    match schema.validate(value) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors.map(ValidationError::from_schema).collect(),
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Stable snake_case code for each `jsonschema` error kind; spelled out so a
+/// dependency's `Debug` output can never change what clients see.
+fn schema_error_code(kind: &jsonschema::error::ValidationErrorKind) -> String {
+    use jsonschema::error::ValidationErrorKind as Kind;
+
+    let code = match kind {
+        Kind::AdditionalItems { .. } => "additional_items",
+        Kind::AdditionalProperties { .. } => "additional_properties",
+        Kind::AnyOf => "any_of",
+        Kind::BacktrackLimitExceeded { .. } => "backtrack_limit_exceeded",
+        Kind::Constant { .. } => "constant",
+        Kind::Contains => "contains",
+        Kind::ContentEncoding { .. } => "content_encoding",
+        Kind::ContentMediaType { .. } => "content_media_type",
+        Kind::Enum { .. } => "enum",
+        Kind::ExclusiveMaximum { .. } => "exclusive_maximum",
+        Kind::ExclusiveMinimum { .. } => "exclusive_minimum",
+        Kind::FalseSchema => "false_schema",
+        Kind::FileNotFound { .. } => "file_not_found",
+        Kind::Format { .. } => "format",
+        Kind::FromUtf8 { .. } | Kind::Utf8 { .. } => "utf8",
+        Kind::JSONParse { .. } => "json_parse",
+        Kind::InvalidReference { .. } => "invalid_reference",
+        Kind::InvalidURL { .. } => "invalid_url",
+        Kind::MaxItems { .. } => "max_items",
+        Kind::Maximum { .. } => "maximum",
+        Kind::MaxLength { .. } => "max_length",
+        Kind::MaxProperties { .. } => "max_properties",
+        Kind::MinItems { .. } => "min_items",
+        Kind::Minimum { .. } => "minimum",
+        Kind::MinLength { .. } => "min_length",
+        Kind::MinProperties { .. } => "min_properties",
+        Kind::MultipleOf { .. } => "multiple_of",
+        Kind::Not { .. } => "not",
+        Kind::OneOfMultipleValid => "one_of_multiple_valid",
+        Kind::OneOfNotValid => "one_of_not_valid",
+        Kind::Pattern { .. } => "pattern",
+        Kind::PropertyNames { .. } => "property_names",
+        Kind::Required { .. } => "required",
+        Kind::Schema => "schema",
+        Kind::Type { .. } => "type",
+        Kind::UnevaluatedProperties { .. } => "unevaluated_properties",
+        Kind::UniqueItems => "unique_items",
+        Kind::UnknownReferenceScheme { .. } => "unknown_reference_scheme",
+        Kind::Resolver { .. } => "resolver",
+    };
+    code.to_string()
+}
@@ -1,6 +1,7 @@
 use rip_openresponses::{
-    allowed_stream_event_types, create_response_body_schema, item_param_schema, openapi,
-    response_resource_schema, streaming_event_schema, tool_choice_param_schema, tool_param_schema,
+    allowed_stream_event_types, create_response_body_schema, is_compatible_with, item_param_schema,
+    openapi, openapi_title, openapi_version, response_resource_schema, schema_component_names,
+    streaming_event_schema, tool_choice_param_schema, tool_param_schema,
 };
 use std::collections::BTreeSet;
 
@@ -33,3 +34,29 @@ fn allowed_stream_event_types_match_generated_type_map() {
 
     assert_eq!(allowed, mapped);
 }
+
+#[test]
+fn openapi_version_reports_semver_major_compatibility() {
+    let version = openapi_version();
+    assert!(!version.is_empty());
+    assert!(is_compatible_with(version));
+
+    let major: u64 = version
+        .split('.')
+        .next()
+        .and_then(|major| major.parse().ok())
+        .expect("numeric major");
+    assert!(is_compatible_with(&format!("{major}.999.0")));
+    assert!(!is_compatible_with(&format!("{}.0.0", major + 1)));
+    assert!(!is_compatible_with("not-a-version"));
+}
+
+#[test]
+fn openapi_metadata_and_component_names_are_exposed() {
+    assert!(!openapi_title().is_empty());
+
+    let names = schema_component_names();
+    assert!(names.contains(&"ResponseResource.json"));
+    assert!(names.contains(&"InputAudioContent.json"));
+    assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+}
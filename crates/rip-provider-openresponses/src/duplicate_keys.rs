@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use serde::de::{DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+
+/// JSON pointer of the first object key that repeats within its object, or
+/// `None` when every object in `raw` has unique keys (or `raw` is not JSON).
+pub(crate) fn find_duplicate_key(raw: &str) -> Option<String> {
+    let mut deserializer = serde_json::Deserializer::from_str(raw);
+    DuplicateKeyScan {
+        path: String::new(),
+    }
+    .deserialize(&mut deserializer)
+    .ok()
+    .flatten()
+}
+
+struct DuplicateKeyScan {
+    path: String,
+}
+
+impl DuplicateKeyScan {
+    fn child(&self, segment: &str) -> Self {
+        let segment = segment.replace('~', "~0").replace('/', "~1");
+        Self {
+            path: format!("{}/{segment}", self.path),
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for DuplicateKeyScan {
+    type Value = Option<String>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for DuplicateKeyScan {
+    type Value = Option<String>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any JSON value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut found = None;
+        let mut idx = 0usize;
+        while let Some(nested) = seq.next_element_seed(self.child(&idx.to_string()))? {
+            found = found.or(nested);
+            idx += 1;
+        }
+        Ok(found)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut seen = HashSet::new();
+        let mut found = None;
+        while let Some(key) = map.next_key::<String>()? {
+            let child = self.child(&key);
+            let duplicate = (!seen.insert(key)).then(|| child.path.clone());
+            let nested = map.next_value_seed(child)?;
+            found = found.or(duplicate).or(nested);
+        }
+        Ok(found)
+    }
+}
@@ -0,0 +1,142 @@
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rip_kernel::{
+    Event, EventKind, ProviderEventBuilder, ProviderEventStatus, SharedIdGenerator, UuidV4Generator,
+};
+
+use crate::parsed_event::{ParsedEvent, ParsedEventKind, TERMINAL_EVENT_TYPES};
+
+#[derive(Debug)]
+pub struct EventFrameMapper {
+    session_id: String,
+    seq: u64,
+    id_generator: SharedIdGenerator,
+}
+
+impl EventFrameMapper {
+    pub fn new(session_id: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            seq: 0,
+            id_generator: Arc::new(UuidV4Generator),
+        }
+    }
+
+    /// Draws frame ids from `generator` instead of random UUIDs.
+    pub fn with_id_generator(mut self, generator: SharedIdGenerator) -> Self {
+        self.id_generator = generator;
+        self
+    }
+
+    /// Starts frame numbering at `seq` instead of 0 (e.g. when resuming a stream).
+    pub fn with_seq(mut self, seq: u64) -> Self {
+        self.seq = seq;
+        self
+    }
+
+    /// Maps one parsed SSE event to frames. Ordering contract: the
+    /// `ProviderEvent` frame always comes first, followed by any frames derived
+    /// from it (currently at most one `OutputTextDelta`, `ReasoningTextDelta`
+    /// or `UsageReported`); `seq` values are contiguous and continue across
+    /// calls.
+    pub fn map(&mut self, parsed: &ParsedEvent) -> Vec<Event> {
+        let provider_frame = self.emit_provider_event(parsed);
+        let mut frames = vec![provider_frame];
+
+        if let Some(delta) = text_delta(parsed, "response.output_text.delta") {
+            frames.push(self.emit(EventKind::OutputTextDelta {
+                delta,
+                actor_id: None,
+                origin: None,
+            }));
+        }
+        if let Some(delta) = text_delta(parsed, "response.reasoning.delta") {
+            frames.push(self.emit(EventKind::ReasoningTextDelta { delta }));
+        }
+        if let Some((usage, partial)) = reported_usage(parsed) {
+            frames.push(self.emit(EventKind::UsageReported { usage, partial }));
+        }
+
+        frames
+    }
+
+    /// Maps a batch in input order, as if calling `map` on each event, so the
+    /// output keeps the per-event provider-before-derived ordering and
+    /// contiguous `seq` values.
+    pub fn map_all(&mut self, parsed_events: &[ParsedEvent]) -> Vec<Event> {
+        parsed_events
+            .iter()
+            .flat_map(|parsed| self.map(parsed))
+            .collect()
+    }
+
+    fn emit_provider_event(&mut self, parsed: &ParsedEvent) -> Event {
+        let status = match parsed.kind {
+            ParsedEventKind::Done => ProviderEventStatus::Done,
+            ParsedEventKind::InvalidJson => ProviderEventStatus::InvalidJson,
+            ParsedEventKind::Event => ProviderEventStatus::Event,
+        };
+
+        self.emit(
+            ProviderEventBuilder::new("openresponses", status)
+                .frame(
+                    parsed.event.clone(),
+                    parsed.data.clone(),
+                    parsed.raw.clone(),
+                )
+                .errors(parsed.errors.clone())
+                .response_errors(parsed.response_errors.clone())
+                .build(),
+        )
+    }
+
+    fn emit(&mut self, kind: EventKind) -> Event {
+        let event = Event {
+            id: self.id_generator.next_id(),
+            session_id: self.session_id.clone(),
+            timestamp_ms: now_ms(),
+            seq: self.seq,
+            kind,
+        };
+        self.seq += 1;
+        event
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+pub(crate) fn text_delta(parsed: &ParsedEvent, expected_type: &str) -> Option<String> {
+    let data = parsed.data.as_ref()?;
+    let obj = data.as_object()?;
+    let event_type = obj.get("type").and_then(|value| value.as_str());
+    if event_type != Some(expected_type) {
+        return None;
+    }
+    obj.get("delta")
+        .and_then(|value| value.as_str())
+        .map(|value| value.to_string())
+}
+
+/// Usage carried by an event, with whether a later report supersedes it:
+/// mid-stream `response.usage.delta` events (`usage`, or `delta` for providers
+/// that nest it there) are partial, the `response.usage` of a terminal event
+/// is final.
+fn reported_usage(parsed: &ParsedEvent) -> Option<(Value, bool)> {
+    let obj = parsed.data.as_ref()?.as_object()?;
+    let event_type = obj.get("type").and_then(|value| value.as_str())?;
+    let (usage, partial) = if event_type == "response.usage.delta" {
+        (obj.get("usage").or_else(|| obj.get("delta"))?, true)
+    } else if TERMINAL_EVENT_TYPES.contains(&event_type) {
+        (obj.get("response")?.get("usage")?, false)
+    } else {
+        return None;
+    };
+    usage.is_object().then(|| (usage.clone(), partial))
+}
@@ -0,0 +1,178 @@
+use serde_json::Value;
+
+use rip_openresponses::{
+    allowed_stream_event_types, validate_stream_event_envelope, validate_stream_event_full,
+    ValidationReport,
+};
+
+use crate::duplicate_keys;
+use crate::validation::{normalize_event_for_validation, ValidationOptions};
+
+/// Event types that end a response stream.
+pub const TERMINAL_EVENT_TYPES: [&str; 3] = [
+    "response.completed",
+    "response.failed",
+    "response.incomplete",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsedEventKind {
+    Done,
+    InvalidJson,
+    Event,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedEvent {
+    pub kind: ParsedEventKind,
+    pub event: Option<String>,
+    /// Last `id:` value seen on the stream when this event dispatched (the
+    /// SSE last-event-id, which carries over to later events until replaced).
+    pub id: Option<String>,
+    pub raw: String,
+    pub data: Option<Value>,
+    pub errors: Vec<String>,
+    pub response_errors: Vec<String>,
+}
+
+impl ParsedEvent {
+    /// True once the stream is logically finished: the `[DONE]` sentinel or one
+    /// of [`TERMINAL_EVENT_TYPES`].
+    pub fn is_terminal(&self) -> bool {
+        match self.kind {
+            ParsedEventKind::Done => true,
+            ParsedEventKind::InvalidJson => false,
+            ParsedEventKind::Event => {
+                let name = self.event_type().or(self.event.as_deref());
+                name.is_some_and(|name| TERMINAL_EVENT_TYPES.contains(&name))
+            }
+        }
+    }
+
+    /// The payload's `type` (not the SSE `event:` name).
+    pub fn event_type(&self) -> Option<&str> {
+        self.data_field("type")?.as_str()
+    }
+
+    pub fn sequence_number(&self) -> Option<u64> {
+        self.data_field("sequence_number")?.as_u64()
+    }
+
+    pub fn item_id(&self) -> Option<&str> {
+        self.data_field("item_id")?.as_str()
+    }
+
+    /// The embedded response resource of `response.*` lifecycle events.
+    pub fn response(&self) -> Option<&Value> {
+        self.data_field("response")
+    }
+
+    fn data_field(&self, key: &str) -> Option<&Value> {
+        self.data.as_ref()?.get(key)
+    }
+
+    pub(crate) fn done(raw: String) -> Self {
+        Self {
+            kind: ParsedEventKind::Done,
+            event: None,
+            id: None,
+            raw,
+            data: None,
+            errors: Vec::new(),
+            response_errors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn invalid_json(raw: String, err: String, event: Option<String>) -> Self {
+        Self {
+            kind: ParsedEventKind::InvalidJson,
+            event,
+            id: None,
+            raw,
+            data: None,
+            errors: vec![err],
+            response_errors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn event(
+        raw: String,
+        event: Option<String>,
+        data: Value,
+        validation: ValidationOptions,
+    ) -> Self {
+        let mut errors = Vec::new();
+        let validation_data = if validation.normalize_missing_item_ids
+            || validation.normalize_missing_response_user
+            || validation.normalize_reasoning_text_events
+            || validation.normalize_missing_reasoning_summary
+            || validation.normalize_response_web_search_tools
+        {
+            normalize_event_for_validation(&data, validation)
+        } else {
+            data.clone()
+        };
+        let validate_response = !validation.validate_response_on_terminal_only
+            || data
+                .get("type")
+                .and_then(|v| v.as_str())
+                .is_some_and(|name| TERMINAL_EVENT_TYPES.contains(&name));
+        let report = if validate_response {
+            validate_stream_event_full(&validation_data)
+        } else {
+            ValidationReport {
+                event_errors: validate_stream_event_envelope(&validation_data)
+                    .err()
+                    .unwrap_or_default(),
+                response_errors: Vec::new(),
+            }
+        };
+        errors.extend(report.event_errors.iter().map(ToString::to_string));
+
+        if !validation.allow_unknown_event_types {
+            let name = event
+                .as_deref()
+                .or_else(|| data.get("type").and_then(|v| v.as_str()));
+            if let Some(name) = name {
+                if !allowed_stream_event_types()
+                    .iter()
+                    .any(|allowed| allowed == name)
+                {
+                    errors.push(format!("event type '{name}' is not in the allowlist"));
+                }
+            }
+        }
+
+        if validation.reject_duplicate_keys {
+            if let Some(path) = duplicate_keys::find_duplicate_key(&raw) {
+                errors.push(format!("duplicate object key at '{path}'"));
+            }
+        }
+
+        if let Some(event_name) = event.as_ref() {
+            if let Some(type_name) = data.get("type").and_then(|v| v.as_str()) {
+                if event_name != type_name {
+                    errors.push(format!(
+                        "event name '{event_name}' does not match type '{type_name}'"
+                    ));
+                }
+            }
+        }
+
+        let response_errors = report
+            .response_errors
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        Self {
+            kind: ParsedEventKind::Event,
+            event,
+            id: None,
+            raw,
+            data: Some(data),
+            errors,
+            response_errors,
+        }
+    }
+}
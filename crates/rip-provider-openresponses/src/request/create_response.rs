@@ -14,7 +14,7 @@ impl CreateResponsePayload {
     pub fn new(body: Value) -> Self {
         let errors = match validate_create_response_body(&body) {
             Ok(_) => Vec::new(),
-            Err(errs) => errs,
+            Err(errs) => errs.iter().map(ToString::to_string).collect(),
         };
         Self { body, errors }
     }
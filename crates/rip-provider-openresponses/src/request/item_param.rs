@@ -1,3 +1,4 @@
+use rip_kernel::{Event, EventKind};
 use serde_json::{Map, Value};
 
 use rip_openresponses::validate_item_param;
@@ -14,7 +15,7 @@ impl ItemParam {
     pub fn new(value: Value) -> Self {
         let errors = match validate_item_param(&value) {
             Ok(_) => Vec::new(),
-            Err(errs) => errs,
+            Err(errs) => errs.iter().map(ToString::to_string).collect(),
         };
         Self { value, errors }
     }
@@ -100,6 +101,17 @@ impl ItemParam {
         Self::new(Value::Object(obj))
     }
 
+    /// A `function_call_output` whose `output` is the JSON string produced by
+    /// `tool_events_to_function_call_output`.
+    pub fn function_call_output_from_tool(
+        call_id: impl Into<String>,
+        tool_name: &str,
+        events: &[Event],
+    ) -> Self {
+        let output = tool_events_to_function_call_output(tool_name, events);
+        Self::function_call_output(call_id, Value::String(output.to_string()))
+    }
+
     pub fn reasoning(summary: Vec<Value>) -> Self {
         let mut obj = Map::new();
         obj.insert("type".to_string(), Value::String("reasoning".to_string()));
@@ -326,3 +338,48 @@ impl ItemParam {
         &self.errors
     }
 }
+
+/// Folds a tool run's events into `{ tool, ok, exit_code, stdout, stderr,
+/// artifacts?, error? }`. A run without `ToolEnded` reports `exit_code: 1`.
+pub fn tool_events_to_function_call_output(tool_name: &str, events: &[Event]) -> Value {
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    let mut exit_code: i32 = 1;
+    let mut artifacts: Option<Value> = None;
+    let mut tool_error: Option<String> = None;
+
+    for event in events {
+        match &event.kind {
+            EventKind::ToolStdout { chunk, .. } => stdout.push_str(chunk),
+            EventKind::ToolStderr { chunk, .. } => stderr.push_str(chunk),
+            EventKind::ToolEnded {
+                exit_code: code,
+                artifacts: tool_artifacts,
+                ..
+            } => {
+                exit_code = *code;
+                artifacts = tool_artifacts.clone();
+            }
+            EventKind::ToolFailed { error, .. } => tool_error = Some(error.clone()),
+            _ => {}
+        }
+    }
+
+    let ok = exit_code == 0 && tool_error.is_none();
+    let mut obj = Map::new();
+    obj.insert("tool".to_string(), Value::String(tool_name.to_string()));
+    obj.insert("ok".to_string(), Value::Bool(ok));
+    obj.insert(
+        "exit_code".to_string(),
+        Value::Number(serde_json::Number::from(exit_code as i64)),
+    );
+    obj.insert("stdout".to_string(), Value::String(stdout));
+    obj.insert("stderr".to_string(), Value::String(stderr));
+    if let Some(artifacts) = artifacts {
+        obj.insert("artifacts".to_string(), artifacts);
+    }
+    if let Some(error) = tool_error {
+        obj.insert("error".to_string(), Value::String(error));
+    }
+    Value::Object(obj)
+}
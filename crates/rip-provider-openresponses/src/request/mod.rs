@@ -6,7 +6,7 @@ mod tool_choice;
 mod tool_param;
 
 pub use create_response::{CreateResponseBuilder, CreateResponsePayload};
-pub use item_param::ItemParam;
+pub use item_param::{tool_events_to_function_call_output, ItemParam};
 pub use tool_choice::{SpecificToolChoiceParam, ToolChoiceParam, ToolChoiceValue};
 pub use tool_param::ToolParam;
 
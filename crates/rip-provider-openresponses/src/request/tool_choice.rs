@@ -2,7 +2,7 @@ use serde_json::{Map, Value};
 
 use rip_openresponses::{validate_specific_tool_choice_param, validate_tool_choice_param};
 
-use super::tool_type_only;
+use super::{tool_type_only, ToolParam};
 
 #[derive(Debug, Clone)]
 pub struct ToolChoiceParam {
@@ -14,7 +14,7 @@ impl ToolChoiceParam {
     pub fn new(value: Value) -> Self {
         let errors = match validate_tool_choice_param(&value) {
             Ok(_) => Vec::new(),
-            Err(errs) => errs,
+            Err(errs) => errs.iter().map(ToString::to_string).collect(),
         };
         Self { value, errors }
     }
@@ -111,6 +111,15 @@ impl ToolChoiceParam {
         Self::new(Value::Object(obj))
     }
 
+    /// `allowed_tools` restricted to exactly the offered `tools`.
+    pub fn allowed_from_tools(tools: &[ToolParam], mode: Option<ToolChoiceValue>) -> Self {
+        let tools = tools
+            .iter()
+            .map(SpecificToolChoiceParam::from_tool)
+            .collect();
+        Self::allowed_tools_with_mode(tools, mode)
+    }
+
     pub fn value(&self) -> &Value {
         &self.value
     }
@@ -151,7 +160,7 @@ impl SpecificToolChoiceParam {
     pub fn new(value: Value) -> Self {
         let errors = match validate_specific_tool_choice_param(&value) {
             Ok(_) => Vec::new(),
-            Err(errs) => errs,
+            Err(errs) => errs.iter().map(ToString::to_string).collect(),
         };
         Self { value, errors }
     }
@@ -220,6 +229,19 @@ impl SpecificToolChoiceParam {
         Self::new(Value::Object(tool_type_only("apply_patch")))
     }
 
+    /// Selects an offered tool by its `type`, plus `name` (function/custom) or
+    /// `server_label` (mcp). Tool types without a specific choice variant
+    /// surface as validation errors.
+    pub fn from_tool(tool: &ToolParam) -> Self {
+        let mut obj = Map::new();
+        for key in ["type", "name", "server_label"] {
+            if let Some(value) = tool.value().get(key) {
+                obj.insert(key.to_string(), value.clone());
+            }
+        }
+        Self::new(Value::Object(obj))
+    }
+
     pub fn value(&self) -> &Value {
         &self.value
     }
@@ -14,7 +14,7 @@ impl ToolParam {
     pub fn new(value: Value) -> Self {
         let errors = match validate_responses_tool_param(&value) {
             Ok(_) => Vec::new(),
-            Err(errs) => errs,
+            Err(errs) => errs.iter().map(ToString::to_string).collect(),
         };
         Self { value, errors }
     }
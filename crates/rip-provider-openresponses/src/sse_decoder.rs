@@ -0,0 +1,221 @@
+use serde_json::Value;
+
+use crate::parsed_event::ParsedEvent;
+use crate::validation::ValidationOptions;
+
+type CommentCallback = Box<dyn FnMut(&str) + Send>;
+
+/// Default cap on undispatched SSE input held by an `SseDecoder`.
+pub const DEFAULT_SSE_MAX_BUFFER_BYTES: usize = 8 * 1024 * 1024;
+
+pub struct SseDecoder {
+    pub(crate) buffer: String,
+    current_event: Option<String>,
+    /// Data buffer per the SSE spec: each `data:` line appends its value plus
+    /// `\n`, and a single trailing `\n` is removed when the event dispatches.
+    current_data: Option<String>,
+    /// Last `id:` value; unlike `current_event` it survives dispatch.
+    current_id: Option<String>,
+    validation: ValidationOptions,
+    comment_callback: Option<CommentCallback>,
+    /// Drop input up to the next `\n`: the rest of a comment split across
+    /// chunks, or of a line cut off by the buffer cap.
+    skipping_line: bool,
+    retry_ms: Option<u64>,
+    max_buffer_bytes: usize,
+}
+
+impl Default for SseDecoder {
+    fn default() -> Self {
+        Self::new_with_limits(ValidationOptions::default(), DEFAULT_SSE_MAX_BUFFER_BYTES)
+    }
+}
+
+impl std::fmt::Debug for SseDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SseDecoder")
+            .field("buffer", &self.buffer)
+            .field("current_event", &self.current_event)
+            .field("current_data", &self.current_data)
+            .field("current_id", &self.current_id)
+            .field("validation", &self.validation)
+            .field("comment_callback", &self.comment_callback.is_some())
+            .field("skipping_line", &self.skipping_line)
+            .field("retry_ms", &self.retry_ms)
+            .field("max_buffer_bytes", &self.max_buffer_bytes)
+            .finish()
+    }
+}
+
+impl SseDecoder {
+    pub fn new() -> Self {
+        Self::new_with_validation(ValidationOptions::strict())
+    }
+
+    pub fn new_with_validation(validation: ValidationOptions) -> Self {
+        Self::new_with_limits(validation, DEFAULT_SSE_MAX_BUFFER_BYTES)
+    }
+
+    /// Like `new_with_validation`, but caps the undispatched input (partial
+    /// line plus `data:` lines of an unterminated event) at `max_buffer_bytes`.
+    /// Past the cap, `push` drops the pending input (and the rest of a line it
+    /// cut off, up to the next newline) and yields an `InvalidJson` event with
+    /// a `sse buffer exceeded {n} bytes` error.
+    pub fn new_with_limits(validation: ValidationOptions, max_buffer_bytes: usize) -> Self {
+        Self {
+            buffer: String::new(),
+            current_event: None,
+            current_data: None,
+            current_id: None,
+            validation,
+            comment_callback: None,
+            skipping_line: false,
+            retry_ms: None,
+            max_buffer_bytes,
+        }
+    }
+
+    /// Value of the most recent `id:` field, to send back as `Last-Event-ID`
+    /// when reconnecting. An empty `id:` clears it.
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.current_id.as_deref()
+    }
+
+    /// Reconnection delay from the most recent valid `retry:` field, if the
+    /// server sent one. Per the SSE spec it applies to every later reconnect.
+    pub fn suggested_retry_ms(&self) -> Option<u64> {
+        self.retry_ms
+    }
+
+    /// Observes `:` comment lines (keep-alives) without turning them into
+    /// events. The callback gets the text after the `:`; a comment split
+    /// across chunks fires once, with the part seen in its first chunk, and
+    /// the rest of the line is discarded rather than buffered.
+    pub fn with_comment_callback(mut self, callback: impl FnMut(&str) + Send + 'static) -> Self {
+        self.comment_callback = Some(Box::new(callback));
+        self
+    }
+
+    pub fn push(&mut self, chunk: &str) -> Vec<ParsedEvent> {
+        let chunk = if self.skipping_line {
+            match chunk.find('\n') {
+                Some(end) => {
+                    self.skipping_line = false;
+                    &chunk[end + 1..]
+                }
+                None => return Vec::new(),
+            }
+        } else {
+            chunk
+        };
+        self.buffer.push_str(chunk);
+        let buffer = std::mem::take(&mut self.buffer);
+        let mut events = Vec::new();
+        let mut lines = buffer.split('\n').peekable();
+        let mut pending_tail = None;
+
+        while let Some(line) = lines.next() {
+            let is_last = lines.peek().is_none();
+            if is_last && !buffer.ends_with('\n') {
+                if let Some(comment) = line.strip_prefix(':') {
+                    self.observe_comment(comment);
+                    self.skipping_line = true;
+                } else {
+                    pending_tail = Some(line.to_string());
+                }
+                break;
+            }
+
+            let line = line.trim_end_matches('\r');
+            if let Some(rest) = line.strip_prefix("event:") {
+                let value = rest.trim();
+                self.current_event = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            } else if let Some(rest) = line.strip_prefix("data:") {
+                let value = rest.trim_start();
+                let data = self.current_data.get_or_insert_with(String::new);
+                data.push_str(value);
+                data.push('\n');
+            } else if let Some(rest) = line.strip_prefix("id:") {
+                let value = rest.strip_prefix(' ').unwrap_or(rest);
+                // Per the SSE spec, ids containing NUL are ignored.
+                if !value.contains('\0') {
+                    self.current_id = (!value.is_empty()).then(|| value.to_string());
+                }
+            } else if let Some(rest) = line.strip_prefix("retry:") {
+                let value = rest.strip_prefix(' ').unwrap_or(rest);
+                if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+                    if let Ok(retry_ms) = value.parse() {
+                        self.retry_ms = Some(retry_ms);
+                    }
+                }
+            } else if line.is_empty() {
+                if is_last {
+                    pending_tail = Some(String::new());
+                    break;
+                }
+                if let Some(mut data) = self.current_data.take() {
+                    if data.ends_with('\n') {
+                        data.pop();
+                    }
+                    events.push(self.parse_event(data));
+                    self.current_event = None;
+                }
+            } else if let Some(comment) = line.strip_prefix(':') {
+                self.observe_comment(comment);
+            }
+        }
+
+        self.buffer = pending_tail.unwrap_or_default();
+        let buffered = self.buffer.len() + self.current_data.as_ref().map_or(0, String::len);
+        if buffered > self.max_buffer_bytes {
+            // A partial line is still arriving; its tail must not be parsed as
+            // a fresh line once the buffer is dropped.
+            self.skipping_line = !self.buffer.is_empty();
+            self.buffer.clear();
+            self.current_data = None;
+            events.push(ParsedEvent::invalid_json(
+                String::new(),
+                format!("sse buffer exceeded {} bytes", self.max_buffer_bytes),
+                self.current_event.take(),
+            ));
+        }
+        events
+    }
+
+    pub fn finish(&mut self) -> Vec<ParsedEvent> {
+        self.skipping_line = false;
+        if self.buffer.is_empty() {
+            return Vec::new();
+        }
+        let chunk = format!("{}\n", self.buffer);
+        self.buffer.clear();
+        self.push(&chunk)
+    }
+
+    fn observe_comment(&mut self, comment: &str) {
+        if let Some(callback) = self.comment_callback.as_mut() {
+            callback(comment);
+        }
+    }
+
+    fn parse_event(&self, raw: String) -> ParsedEvent {
+        let mut parsed = if raw == "[DONE]" {
+            ParsedEvent::done(raw)
+        } else {
+            match serde_json::from_str::<Value>(&raw) {
+                Ok(value) => {
+                    ParsedEvent::event(raw, self.current_event.clone(), value, self.validation)
+                }
+                Err(err) => {
+                    ParsedEvent::invalid_json(raw, err.to_string(), self.current_event.clone())
+                }
+            }
+        };
+        parsed.id = self.current_id.clone();
+        parsed
+    }
+}
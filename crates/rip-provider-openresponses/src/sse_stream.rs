@@ -0,0 +1,100 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+
+use futures_util::stream::{self, Stream, StreamExt};
+
+use crate::{ParsedEvent, SseDecoder};
+
+/// Adapts an async byte stream into parsed SSE events using a strict decoder.
+pub fn sse_event_stream<S, B>(byte_stream: S) -> impl Stream<Item = ParsedEvent>
+where
+    S: Stream<Item = B>,
+    B: AsRef<[u8]>,
+{
+    sse_event_stream_with_decoder(byte_stream, SseDecoder::new())
+}
+
+/// Same as [`sse_event_stream`], but feeds a caller-configured decoder.
+///
+/// Chunks may split UTF-8 sequences; incomplete tails are held until the next
+/// chunk and invalid bytes decode as U+FFFD. The decoder is flushed when the
+/// byte stream ends.
+pub fn sse_event_stream_with_decoder<S, B>(
+    byte_stream: S,
+    decoder: SseDecoder,
+) -> impl Stream<Item = ParsedEvent>
+where
+    S: Stream<Item = B>,
+    B: AsRef<[u8]>,
+{
+    let state = SseStreamState {
+        bytes: Box::pin(byte_stream),
+        decoder,
+        utf8_buf: Vec::new(),
+        pending: VecDeque::new(),
+        finished: false,
+    };
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((event, state));
+            }
+            if state.finished {
+                return None;
+            }
+            match state.bytes.next().await {
+                Some(chunk) => state.push_bytes(chunk.as_ref()),
+                None => state.finish(),
+            }
+        }
+    })
+}
+
+struct SseStreamState<S> {
+    bytes: Pin<Box<S>>,
+    decoder: SseDecoder,
+    utf8_buf: Vec<u8>,
+    pending: VecDeque<ParsedEvent>,
+    finished: bool,
+}
+
+impl<S> SseStreamState<S> {
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        self.utf8_buf.extend_from_slice(bytes);
+        let mut text = String::new();
+        loop {
+            match std::str::from_utf8(&self.utf8_buf) {
+                Ok(valid) => {
+                    text.push_str(valid);
+                    self.utf8_buf.clear();
+                    break;
+                }
+                Err(err) => {
+                    let valid = err.valid_up_to();
+                    text.push_str(
+                        std::str::from_utf8(&self.utf8_buf[..valid]).expect("valid utf8 prefix"),
+                    );
+                    let Some(invalid_len) = err.error_len() else {
+                        self.utf8_buf.drain(..valid);
+                        break;
+                    };
+                    text.push('\u{FFFD}');
+                    self.utf8_buf.drain(..valid + invalid_len);
+                }
+            }
+        }
+        if !text.is_empty() {
+            self.pending.extend(self.decoder.push(&text));
+        }
+    }
+
+    fn finish(&mut self) {
+        if !self.utf8_buf.is_empty() {
+            let tail = String::from_utf8_lossy(&self.utf8_buf).into_owned();
+            self.utf8_buf.clear();
+            self.pending.extend(self.decoder.push(&tail));
+        }
+        self.pending.extend(self.decoder.finish());
+        self.finished = true;
+    }
+}
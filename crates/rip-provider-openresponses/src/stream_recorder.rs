@@ -0,0 +1,100 @@
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use futures_util::stream::{Stream, StreamExt};
+
+use crate::{sse_event_stream, ParsedEvent, ParsedEventKind};
+
+/// Captures a live SSE stream as a replayable fixture pair: the raw bytes go
+/// to a `.sse` file and each decoded event payload to a `.jsonl` line (the
+/// `stream_all.sse` / `stream_all.jsonl` layout). Events pass through
+/// unchanged; write errors are held until [`StreamRecorder::finish`].
+#[derive(Clone)]
+pub struct StreamRecorder {
+    inner: Arc<Mutex<RecorderFiles>>,
+}
+
+struct RecorderFiles {
+    sse: File,
+    jsonl: File,
+    events_recorded: usize,
+    error: Option<io::Error>,
+}
+
+impl RecorderFiles {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        if self.error.is_none() {
+            if let Err(err) = self.sse.write_all(bytes) {
+                self.error = Some(err);
+            }
+        }
+    }
+
+    fn write_event(&mut self, event: &ParsedEvent) {
+        if self.error.is_some() || event.kind != ParsedEventKind::Event {
+            return;
+        }
+        let Some(data) = event.data.as_ref() else {
+            return;
+        };
+        let result = serde_json::to_string(data)
+            .map_err(io::Error::from)
+            .and_then(|line| writeln!(self.jsonl, "{line}"));
+        match result {
+            Ok(()) => self.events_recorded += 1,
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+impl StreamRecorder {
+    /// Creates (truncating) both fixture files.
+    pub fn create(sse_path: impl AsRef<Path>, jsonl_path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self {
+            inner: Arc::new(Mutex::new(RecorderFiles {
+                sse: File::create(sse_path)?,
+                jsonl: File::create(jsonl_path)?,
+                events_recorded: 0,
+                error: None,
+            })),
+        })
+    }
+
+    /// Decodes `byte_stream` like [`sse_event_stream`], recording bytes and
+    /// events as they flow through. `[DONE]` and invalid JSON events are only
+    /// kept in the `.sse` file.
+    pub fn record<S, B>(&self, byte_stream: S) -> impl Stream<Item = ParsedEvent>
+    where
+        S: Stream<Item = B>,
+        B: AsRef<[u8]>,
+    {
+        let bytes_sink = Arc::clone(&self.inner);
+        let events_sink = Arc::clone(&self.inner);
+        let bytes = byte_stream.inspect(move |chunk| {
+            bytes_sink
+                .lock()
+                .expect("recorder mutex")
+                .write_bytes(chunk.as_ref());
+        });
+        sse_event_stream(bytes).inspect(move |event| {
+            events_sink
+                .lock()
+                .expect("recorder mutex")
+                .write_event(event);
+        })
+    }
+
+    /// Flushes both files and returns the number of `.jsonl` lines written,
+    /// or the first write error.
+    pub fn finish(&self) -> io::Result<usize> {
+        let mut files = self.inner.lock().expect("recorder mutex");
+        if let Some(err) = files.error.take() {
+            return Err(err);
+        }
+        files.sse.flush()?;
+        files.jsonl.flush()?;
+        Ok(files.events_recorded)
+    }
+}
@@ -1,5 +1,9 @@
+use std::collections::BTreeMap;
+
 use rip_kernel::{Event, EventKind, ProviderEventStatus};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
+
+use crate::{ParsedEvent, ParsedEventKind};
 
 fn event_type(event: &Event) -> Option<&str> {
     match &event.kind {
@@ -59,3 +63,281 @@ pub fn extract_tool_call_argument_deltas(events: &[Event]) -> Vec<String> {
         .filter_map(|event| event_delta(event, "response.function_call_arguments.delta"))
         .collect()
 }
+
+/// Deep-merges a partial response resource into `base`. Objects merge key by
+/// key, `output` merges element-wise by index (extending `base` as needed),
+/// and any other value in `partial` replaces the one in `base`.
+pub fn merge_response_partial(base: &mut Value, partial: &Value) {
+    merge_value(base, partial, false);
+}
+
+fn merge_value(base: &mut Value, partial: &Value, index_arrays: bool) {
+    match (base, partial) {
+        (Value::Object(base), Value::Object(partial)) => {
+            for (key, value) in partial {
+                match base.get_mut(key) {
+                    Some(existing) => merge_value(existing, value, key == "output"),
+                    None => {
+                        base.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(partial)) if index_arrays => {
+            for (idx, value) in partial.iter().enumerate() {
+                match base.get_mut(idx) {
+                    Some(existing) => merge_value(existing, value, false),
+                    None => base.push(value.clone()),
+                }
+            }
+        }
+        (base, partial) => *base = partial.clone(),
+    }
+}
+
+/// Folds the `response` objects carried by `response.*` events into a single
+/// resource, in stream order. Returns `None` if no event carried one.
+pub fn assemble_response(events: &[Event]) -> Option<Value> {
+    let mut assembled: Option<Value> = None;
+    for event in events {
+        if !event_type(event).is_some_and(|kind| kind.starts_with("response.")) {
+            continue;
+        }
+        let EventKind::ProviderEvent {
+            data: Some(Value::Object(obj)),
+            ..
+        } = &event.kind
+        else {
+            continue;
+        };
+        let Some(partial @ Value::Object(_)) = obj.get("response") else {
+            continue;
+        };
+        match assembled.as_mut() {
+            Some(base) => merge_response_partial(base, partial),
+            None => assembled = Some(partial.clone()),
+        }
+    }
+    assembled
+}
+
+/// Incremental counterpart to [`assemble_response`]: feed parsed events one at
+/// a time and read the reconstructed response resource after each. Embedded
+/// `response` snapshots are merged as-is; output item, content part, text,
+/// annotation and function-call-argument events are folded into `output` by
+/// index.
+#[derive(Debug, Clone)]
+pub struct ResponseAssembler {
+    current: Value,
+}
+
+impl Default for ResponseAssembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResponseAssembler {
+    pub fn new() -> Self {
+        Self {
+            current: Value::Object(Map::new()),
+        }
+    }
+
+    /// The response as reconstructed so far (an empty object before any
+    /// response data arrives).
+    pub fn current(&self) -> &Value {
+        &self.current
+    }
+
+    pub fn push(&mut self, parsed: &ParsedEvent) {
+        if parsed.kind != ParsedEventKind::Event {
+            return;
+        }
+        let Some(Value::Object(data)) = parsed.data.as_ref() else {
+            return;
+        };
+        if let Some(partial @ Value::Object(_)) = data.get("response") {
+            merge_response_partial(&mut self.current, partial);
+        }
+
+        let output_index = index_field(data, "output_index");
+        let content_index = index_field(data, "content_index");
+        let event_type = data.get("type").and_then(|value| value.as_str());
+        match (event_type, output_index) {
+            (
+                Some("response.output_item.added" | "response.output_item.done"),
+                Some(output_index),
+            ) => {
+                if let Some(item) = data.get("item") {
+                    *self.output_item(output_index) = item.clone();
+                }
+            }
+            (
+                Some("response.content_part.added" | "response.content_part.done"),
+                Some(output_index),
+            ) => {
+                if let (Some(part), Some(content_index)) = (data.get("part"), content_index) {
+                    *self.content_part(output_index, content_index) = part.clone();
+                }
+            }
+            (Some("response.output_text.delta"), Some(output_index)) => {
+                if let (Some(delta), Some(content_index)) = (data.get("delta"), content_index) {
+                    let part = self.content_part(output_index, content_index);
+                    append_str_field(part, "text", delta);
+                }
+            }
+            (Some("response.output_text.done"), Some(output_index)) => {
+                if let (Some(text), Some(content_index)) = (data.get("text"), content_index) {
+                    let part = self.content_part(output_index, content_index);
+                    set_field(part, "text", text.clone());
+                }
+            }
+            (Some("response.output_text.annotation.added"), Some(output_index)) => {
+                if let (Some(annotation @ Value::Object(_)), Some(content_index)) =
+                    (data.get("annotation"), content_index)
+                {
+                    let annotation_index = index_field(data, "annotation_index");
+                    let part = self.content_part(output_index, content_index);
+                    let annotations = array_field(part, "annotations");
+                    match annotation_index.filter(|idx| *idx < annotations.len()) {
+                        Some(idx) => annotations[idx] = annotation.clone(),
+                        None => annotations.push(annotation.clone()),
+                    }
+                }
+            }
+            (Some("response.function_call_arguments.delta"), Some(output_index)) => {
+                if let Some(delta) = data.get("delta") {
+                    append_str_field(self.output_item(output_index), "arguments", delta);
+                }
+            }
+            (Some("response.function_call_arguments.done"), Some(output_index)) => {
+                if let Some(arguments) = data.get("arguments") {
+                    set_field(
+                        self.output_item(output_index),
+                        "arguments",
+                        arguments.clone(),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn output_item(&mut self, output_index: usize) -> &mut Value {
+        let output = array_field(&mut self.current, "output");
+        slot_at(output, output_index, || Value::Object(Map::new()))
+    }
+
+    fn content_part(&mut self, output_index: usize, content_index: usize) -> &mut Value {
+        let content = array_field(self.output_item(output_index), "content");
+        slot_at(
+            content,
+            content_index,
+            || json!({ "type": "output_text", "text": "" }),
+        )
+    }
+}
+
+/// Returns `items[index]`, appending a fresh slot when `index` is at or past
+/// the end. Indices come from the provider, so anything beyond `len` is capped
+/// to an append rather than padding the array out to an arbitrary size.
+fn slot_at(items: &mut Vec<Value>, index: usize, empty: impl FnOnce() -> Value) -> &mut Value {
+    if index >= items.len() {
+        items.push(empty());
+        let last = items.len() - 1;
+        return &mut items[last];
+    }
+    &mut items[index]
+}
+
+/// Per-slot counterpart to [`extract_text_deltas`]: concatenates
+/// `response.output_text.delta` deltas keyed by `(output_index,
+/// content_index)`. A `response.output_text.done` carrying `text` replaces
+/// its slot with that authoritative value.
+#[derive(Debug, Clone, Default)]
+pub struct TextAggregator {
+    slots: BTreeMap<(u64, u64), String>,
+}
+
+impl TextAggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, parsed: &ParsedEvent) {
+        if parsed.kind != ParsedEventKind::Event {
+            return;
+        }
+        let Some(Value::Object(data)) = parsed.data.as_ref() else {
+            return;
+        };
+        let (Some(output_index), Some(content_index)) = (
+            data.get("output_index").and_then(Value::as_u64),
+            data.get("content_index").and_then(Value::as_u64),
+        ) else {
+            return;
+        };
+        let slot = (output_index, content_index);
+        match data.get("type").and_then(Value::as_str) {
+            Some("response.output_text.delta") => {
+                if let Some(delta) = data.get("delta").and_then(Value::as_str) {
+                    self.slots.entry(slot).or_default().push_str(delta);
+                }
+            }
+            Some("response.output_text.done") => {
+                if let Some(text) = data.get("text").and_then(Value::as_str) {
+                    self.slots.insert(slot, text.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Aggregated text per `(output_index, content_index)`, in index order.
+    pub fn finish(self) -> BTreeMap<(u64, u64), String> {
+        self.slots
+    }
+}
+
+fn index_field(data: &Map<String, Value>, key: &str) -> Option<usize> {
+    data.get(key)
+        .and_then(|value| value.as_u64())
+        .and_then(|value| usize::try_from(value).ok())
+}
+
+fn array_field<'a>(value: &'a mut Value, key: &str) -> &'a mut Vec<Value> {
+    if !value.is_object() {
+        *value = Value::Object(Map::new());
+    }
+    let field = value
+        .as_object_mut()
+        .expect("object")
+        .entry(key.to_string())
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if !field.is_array() {
+        *field = Value::Array(Vec::new());
+    }
+    field.as_array_mut().expect("array")
+}
+
+fn set_field(value: &mut Value, key: &str, field: Value) {
+    if let Value::Object(obj) = value {
+        obj.insert(key.to_string(), field);
+    }
+}
+
+fn append_str_field(value: &mut Value, key: &str, delta: &Value) {
+    let Some(delta) = delta.as_str() else {
+        return;
+    };
+    let Value::Object(obj) = value else {
+        return;
+    };
+    match obj.get_mut(key) {
+        Some(Value::String(existing)) => existing.push_str(delta),
+        _ => {
+            obj.insert(key.to_string(), Value::String(delta.to_string()));
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::path::Path;
+
+use rip_openresponses::validate_stream_events;
+
+/// Validates every line of a JSONL stream fixture against the strict
+/// OpenResponses streaming schema via `validate_stream_events`, skipping
+/// blank lines.
+///
+/// Returns `(line_number, errors)` for each failing line (1-based); an
+/// unreadable file is reported as line 0.
+pub fn validate_fixture_stream(path: impl AsRef<Path>) -> Vec<(usize, Vec<String>)> {
+    let contents = match std::fs::read_to_string(path.as_ref()) {
+        Ok(contents) => contents,
+        Err(err) => return vec![(0, vec![format!("read fixture failed: {err}")])],
+    };
+
+    validate_stream_events(contents.lines())
+        .into_iter()
+        .zip(contents.lines())
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|((idx, result), _)| {
+            let errors = result.err()?;
+            Some((idx + 1, errors.iter().map(ToString::to_string).collect()))
+        })
+        .collect()
+}
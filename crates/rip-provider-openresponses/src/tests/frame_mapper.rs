@@ -0,0 +1,371 @@
+use std::sync::Arc;
+
+use rip_kernel::{Event, EventKind, ProviderEventStatus};
+use serde_json::Value;
+
+use super::*;
+use crate::frame_mapper::text_delta;
+
+#[test]
+fn maps_output_text_delta_to_provider_frame() {
+    let parsed = ParsedEvent {
+        kind: ParsedEventKind::Event,
+        event: Some("response.output_text.delta".to_string()),
+        id: None,
+        raw: "{\"type\":\"response.output_text.delta\",\"delta\":\"hi\"}".to_string(),
+        data: Some(serde_json::json!({
+            "type": "response.output_text.delta",
+            "delta": "hi"
+        })),
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+
+    let mut mapper = EventFrameMapper::new("session-1");
+    let frames = mapper.map(&parsed);
+    assert_eq!(frames.len(), 2);
+
+    let frame = &frames[0];
+    assert_eq!(frame.session_id, "session-1");
+    assert_eq!(frame.seq, 0);
+    match &frame.kind {
+        EventKind::ProviderEvent {
+            provider,
+            status,
+            event_name,
+            data,
+            raw,
+            ..
+        } => {
+            assert_eq!(provider, "openresponses");
+            assert_eq!(*status, ProviderEventStatus::Event);
+            assert_eq!(event_name.as_deref(), Some("response.output_text.delta"));
+            let data = data.as_ref().expect("data");
+            assert_eq!(data.get("delta").and_then(|v| v.as_str()), Some("hi"));
+            assert!(raw.is_none());
+        }
+        _ => panic!("expected provider_event"),
+    }
+
+    match &frames[1].kind {
+        EventKind::OutputTextDelta { delta, .. } => assert_eq!(delta, "hi"),
+        _ => panic!("expected output_text_delta"),
+    }
+}
+
+#[test]
+fn maps_reasoning_delta_to_reasoning_text_frame() {
+    let parsed = ParsedEvent {
+        kind: ParsedEventKind::Event,
+        event: Some("response.reasoning.delta".to_string()),
+        id: None,
+        raw: String::new(),
+        data: Some(serde_json::json!({
+            "type": "response.reasoning.delta",
+            "delta": "thinking"
+        })),
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+
+    let mut mapper = EventFrameMapper::new("session-1");
+    let frames = mapper.map(&parsed);
+    assert_eq!(frames.len(), 2);
+    assert!(matches!(frames[0].kind, EventKind::ProviderEvent { .. }));
+    assert_eq!(frames[1].seq, 1);
+    match &frames[1].kind {
+        EventKind::ReasoningTextDelta { delta } => assert_eq!(delta, "thinking"),
+        _ => panic!("expected reasoning_text_delta"),
+    }
+}
+
+#[test]
+fn maps_completed_to_provider_frame() {
+    let parsed = ParsedEvent {
+        kind: ParsedEventKind::Event,
+        event: Some("response.completed".to_string()),
+        id: None,
+        raw: "{\"type\":\"response.completed\"}".to_string(),
+        data: Some(serde_json::json!({
+            "type": "response.completed"
+        })),
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+
+    let mut mapper = EventFrameMapper::new("session-1");
+    let frames = mapper.map(&parsed);
+    assert_eq!(frames.len(), 1);
+    match &frames[0].kind {
+        EventKind::ProviderEvent {
+            status,
+            event_name,
+            data,
+            ..
+        } => {
+            assert_eq!(*status, ProviderEventStatus::Event);
+            assert_eq!(event_name.as_deref(), Some("response.completed"));
+            let data = data.as_ref().expect("data");
+            assert_eq!(
+                data.get("type").and_then(|v| v.as_str()),
+                Some("response.completed")
+            );
+        }
+        _ => panic!("expected provider_event"),
+    }
+}
+
+#[test]
+fn maps_usage_delta_to_partial_usage_frame() {
+    let parsed = ParsedEvent {
+        kind: ParsedEventKind::Event,
+        event: Some("response.usage.delta".to_string()),
+        id: None,
+        raw: String::new(),
+        data: Some(serde_json::json!({
+            "type": "response.usage.delta",
+            "usage": { "input_tokens": 12, "output_tokens": 3 }
+        })),
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+
+    let mut mapper = EventFrameMapper::new("session-1");
+    let frames = mapper.map(&parsed);
+    assert_eq!(frames.len(), 2);
+    assert!(matches!(frames[0].kind, EventKind::ProviderEvent { .. }));
+    match &frames[1].kind {
+        EventKind::UsageReported { usage, partial } => {
+            assert!(*partial);
+            assert_eq!(usage["input_tokens"], 12);
+            assert_eq!(usage["output_tokens"], 3);
+        }
+        other => panic!("expected usage_reported, got {other:?}"),
+    }
+    assert_eq!(frames[1].seq, 1);
+}
+
+#[test]
+fn maps_terminal_response_usage_to_final_usage_frame() {
+    let parsed = ParsedEvent {
+        kind: ParsedEventKind::Event,
+        event: Some("response.completed".to_string()),
+        id: None,
+        raw: String::new(),
+        data: Some(serde_json::json!({
+            "type": "response.completed",
+            "response": { "usage": { "input_tokens": 12, "output_tokens": 9 } }
+        })),
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+
+    let frames = EventFrameMapper::new("session-1").map(&parsed);
+    assert_eq!(frames.len(), 2);
+    match &frames[1].kind {
+        EventKind::UsageReported { usage, partial } => {
+            assert!(!*partial);
+            assert_eq!(usage["output_tokens"], 9);
+        }
+        other => panic!("expected usage_reported, got {other:?}"),
+    }
+
+    let without_usage = ParsedEvent {
+        data: Some(serde_json::json!({
+            "type": "response.completed",
+            "response": { "usage": null }
+        })),
+        ..parsed
+    };
+    assert_eq!(
+        EventFrameMapper::new("session-1").map(&without_usage).len(),
+        1
+    );
+}
+
+#[test]
+fn with_seq_continues_frame_numbering() {
+    let parsed = ParsedEvent {
+        kind: ParsedEventKind::Event,
+        event: None,
+        id: None,
+        raw: "{\"type\":\"response.output_text.delta\",\"delta\":\"hi\"}".to_string(),
+        data: Some(serde_json::json!({
+            "type": "response.output_text.delta",
+            "delta": "hi"
+        })),
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+
+    let mut mapper = EventFrameMapper::new("session-1").with_seq(7);
+    let frames = mapper.map(&parsed);
+    let seqs: Vec<u64> = frames.iter().map(|frame| frame.seq).collect();
+    assert_eq!(seqs, vec![7, 8]);
+}
+
+#[test]
+fn event_frame_mapper_uses_injected_id_generator() {
+    let parsed = ParsedEvent {
+        kind: ParsedEventKind::Event,
+        event: Some("response.output_text.delta".to_string()),
+        id: None,
+        raw: String::new(),
+        data: Some(serde_json::json!({
+            "type": "response.output_text.delta",
+            "delta": "hi"
+        })),
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+
+    let mut mapper = EventFrameMapper::new("session-1")
+        .with_id_generator(Arc::new(rip_kernel::SequentialIdGenerator::new("f")));
+    let mut ids: Vec<String> = mapper.map(&parsed).into_iter().map(|f| f.id).collect();
+    ids.extend(mapper.map(&parsed).into_iter().map(|f| f.id));
+    assert_eq!(ids, vec!["f0", "f1", "f2", "f3"]);
+}
+
+#[test]
+fn map_all_keeps_provider_before_derived_and_contiguous_seq() {
+    let event = |data: Value| ParsedEvent {
+        kind: ParsedEventKind::Event,
+        event: data
+            .get("type")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        id: None,
+        raw: data.to_string(),
+        data: Some(data),
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+    let batch = vec![
+        event(serde_json::json!({"type": "response.created"})),
+        event(serde_json::json!({"type": "response.output_item.added", "output_index": 0})),
+        event(serde_json::json!({"type": "response.reasoning_text.delta", "delta": "think"})),
+        event(serde_json::json!({"type": "response.output_text.delta", "delta": "he"})),
+        event(serde_json::json!({"type": "response.output_text.delta", "delta": "llo"})),
+        event(serde_json::json!({"type": "response.completed"})),
+        ParsedEvent {
+            kind: ParsedEventKind::Done,
+            event: None,
+            id: None,
+            raw: "[DONE]".to_string(),
+            data: None,
+            errors: Vec::new(),
+            response_errors: Vec::new(),
+        },
+    ];
+
+    let mut mapper = EventFrameMapper::new("session-1").with_seq(3);
+    let frames = mapper.map_all(&batch);
+    let seqs: Vec<u64> = frames.iter().map(|frame| frame.seq).collect();
+    assert_eq!(seqs, (3..3 + frames.len() as u64).collect::<Vec<_>>());
+
+    let kinds: Vec<String> = frames
+        .iter()
+        .map(|frame| match &frame.kind {
+            EventKind::ProviderEvent {
+                status: ProviderEventStatus::Done,
+                ..
+            } => "done".to_string(),
+            EventKind::ProviderEvent { event_name, .. } => event_name.clone().unwrap_or_default(),
+            EventKind::OutputTextDelta { delta, .. } => format!("delta:{delta}"),
+            _ => panic!("unexpected frame"),
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "response.created",
+            "response.output_item.added",
+            "response.reasoning_text.delta",
+            "response.output_text.delta",
+            "delta:he",
+            "response.output_text.delta",
+            "delta:llo",
+            "response.completed",
+            "done",
+        ]
+    );
+
+    let mut single = EventFrameMapper::new("session-1").with_seq(3);
+    let one_by_one: Vec<Event> = batch.iter().flat_map(|parsed| single.map(parsed)).collect();
+    assert_eq!(one_by_one.len(), frames.len());
+}
+
+#[test]
+fn done_sentinel_maps_to_provider_frame() {
+    let done = ParsedEvent {
+        kind: ParsedEventKind::Done,
+        event: None,
+        id: None,
+        raw: "[DONE]".to_string(),
+        data: None,
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+
+    let mut mapper = EventFrameMapper::new("session-1");
+    let frames = mapper.map(&done);
+    assert_eq!(frames.len(), 1);
+    match &frames[0].kind {
+        EventKind::ProviderEvent {
+            status, raw, data, ..
+        } => {
+            assert_eq!(*status, ProviderEventStatus::Done);
+            assert_eq!(raw.as_deref(), Some("[DONE]"));
+            assert!(data.is_none());
+        }
+        _ => panic!("expected provider_event"),
+    }
+}
+
+#[test]
+fn invalid_json_maps_to_provider_frame() {
+    let invalid = ParsedEvent {
+        kind: ParsedEventKind::InvalidJson,
+        event: Some("response.created".to_string()),
+        id: None,
+        raw: "{not json}".to_string(),
+        data: None,
+        errors: vec!["oops".to_string()],
+        response_errors: Vec::new(),
+    };
+
+    let mut mapper = EventFrameMapper::new("session-1");
+    let frames = mapper.map(&invalid);
+    assert_eq!(frames.len(), 1);
+    match &frames[0].kind {
+        EventKind::ProviderEvent {
+            status,
+            raw,
+            data,
+            errors,
+            ..
+        } => {
+            assert_eq!(*status, ProviderEventStatus::InvalidJson);
+            assert_eq!(raw.as_deref(), Some("{not json}"));
+            assert!(data.is_none());
+            assert_eq!(errors, &vec!["oops".to_string()]);
+        }
+        _ => panic!("expected provider_event"),
+    }
+}
+
+#[test]
+fn output_text_delta_filters_non_text_events() {
+    let parsed = ParsedEvent {
+        kind: ParsedEventKind::Event,
+        event: Some("response.completed".to_string()),
+        id: None,
+        raw: "{\"type\":\"response.completed\"}".to_string(),
+        data: Some(serde_json::json!({
+            "type": "response.completed"
+        })),
+        errors: Vec::new(),
+        response_errors: Vec::new(),
+    };
+    assert!(text_delta(&parsed, "response.output_text.delta").is_none());
+}
@@ -0,0 +1,5 @@
+use super::*;
+
+mod frame_mapper;
+mod sse_decoder;
+mod validation;
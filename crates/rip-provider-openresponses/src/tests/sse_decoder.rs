@@ -0,0 +1,317 @@
+use super::*;
+
+#[test]
+fn normalizes_missing_item_ids_for_validation() {
+    let payload = "event: response.output_item.added\n\
+data: {\"type\":\"response.output_item.added\",\"sequence_number\":1,\"output_index\":0,\"item\":{\"type\":\"function_call\",\"call_id\":\"call_1\",\"name\":\"ls\",\"arguments\":\"{}\",\"status\":\"in_progress\"}}\n\n";
+
+    let mut strict = SseDecoder::new();
+    let events = strict.push(payload);
+    assert_eq!(events.len(), 1);
+    assert!(events[0].errors.iter().any(|err| err.contains("id")));
+
+    let mut compat = SseDecoder::new_with_validation(ValidationOptions::compat_missing_item_ids());
+    let events = compat.push(payload);
+    assert_eq!(events.len(), 1);
+    assert!(events[0].errors.is_empty());
+}
+
+#[test]
+fn parsed_event_accessors_read_payload_fields() {
+    let mut decoder = SseDecoder::new_with_validation(ValidationOptions::compat_openrouter());
+    let events = decoder.push(
+        "event: response.output_text.delta\n\
+data: {\"type\":\"response.output_text.delta\",\"sequence_number\":3,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"hi\",\"logprobs\":[]}\n\n\
+event: response.completed\n\
+data: {\"type\":\"response.completed\",\"sequence_number\":4,\"response\":{\"id\":\"resp_1\",\"status\":\"completed\"}}\n\n\
+data: [DONE]\n\n",
+    );
+    assert_eq!(events.len(), 3);
+
+    let delta = &events[0];
+    assert_eq!(delta.event_type(), Some("response.output_text.delta"));
+    assert_eq!(delta.sequence_number(), Some(3));
+    assert_eq!(delta.item_id(), Some("msg_1"));
+    assert!(delta.response().is_none());
+
+    let completed = &events[1];
+    assert_eq!(completed.event_type(), Some("response.completed"));
+    assert_eq!(completed.sequence_number(), Some(4));
+    assert_eq!(completed.item_id(), None);
+    assert_eq!(
+        completed
+            .response()
+            .and_then(|response| response.get("id"))
+            .and_then(|id| id.as_str()),
+        Some("resp_1")
+    );
+
+    let done = &events[2];
+    assert_eq!(done.event_type(), None);
+    assert_eq!(done.sequence_number(), None);
+    assert!(done.response().is_none());
+}
+
+#[test]
+fn surfaces_suggested_retry_from_retry_field() {
+    let mut decoder = SseDecoder::new();
+    assert_eq!(decoder.suggested_retry_ms(), None);
+
+    let events = decoder.push("retry: 1500\ndata: [DONE]\n\n");
+    assert_eq!(events.len(), 1);
+    assert_eq!(decoder.suggested_retry_ms(), Some(1500));
+
+    decoder.push("retry: soon\n\n");
+    assert_eq!(decoder.suggested_retry_ms(), Some(1500));
+    decoder.push("retry:250\r\n\r\n");
+    assert_eq!(decoder.suggested_retry_ms(), Some(250));
+}
+
+#[test]
+fn large_comment_line_fires_callback_without_buffering() {
+    let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let mut decoder = SseDecoder::new().with_comment_callback(move |comment| {
+        seen_clone.lock().unwrap().push(comment.len());
+    });
+
+    let padding = "x".repeat(256 * 1024);
+    let events = decoder.push(&format!(": keep-alive {padding}"));
+    assert!(events.is_empty());
+    assert!(decoder.buffer.is_empty());
+    assert!(decoder.push(&padding).is_empty());
+    assert!(decoder.buffer.is_empty());
+
+    let events = decoder.push("\ndata: [DONE]\n\n");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ParsedEventKind::Done);
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![" keep-alive ".len() + padding.len()]
+    );
+
+    assert!(decoder.push(":ping\n\n").is_empty());
+    assert_eq!(seen.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn joins_multiple_data_lines_without_trailing_newline() {
+    let mut decoder = SseDecoder::new();
+    let events =
+        decoder.push("data: {\"type\":\ndata: \"error\",\ndata: \"sequence_number\":1}\n\n");
+    assert_eq!(events.len(), 1);
+    let raw = &events[0].raw;
+    assert_eq!(raw, "{\"type\":\n\"error\",\n\"sequence_number\":1}");
+    assert_eq!(raw.matches('\n').count(), 2);
+    assert!(!raw.ends_with('\n'));
+    assert_eq!(events[0].kind, ParsedEventKind::Event);
+}
+
+#[test]
+fn flags_unknown_event_names_when_unknown_types_rejected() {
+    let payload =
+        "event: response.made_up\ndata: {\"type\":\"response.made_up\",\"sequence_number\":1}\n\n";
+
+    let mut lenient = SseDecoder::new();
+    let events = lenient.push(payload);
+    assert_eq!(events.len(), 1);
+    assert!(!events[0]
+        .errors
+        .iter()
+        .any(|err| err.contains("not in the allowlist")));
+
+    let mut strict = SseDecoder::new_with_validation(
+        ValidationOptions::strict().with_unknown_event_types_rejected(),
+    );
+    let events = strict.push(payload);
+    assert_eq!(events.len(), 1);
+    assert!(events[0]
+        .errors
+        .iter()
+        .any(|err| err.contains("'response.made_up' is not in the allowlist")));
+
+    let events = strict.push("event: response.created\ndata: {\"type\":\"response.created\"}\n\n");
+    assert!(!events[0]
+        .errors
+        .iter()
+        .any(|err| err.contains("not in the allowlist")));
+}
+
+#[test]
+fn flags_duplicate_keys_only_when_rejected() {
+    let payload = "data: {\"type\":\"x\",\"type\":\"y\"}\n\n";
+    let mut lenient = SseDecoder::new();
+    let events = lenient.push(payload);
+    assert!(!events[0]
+        .errors
+        .iter()
+        .any(|err| err.contains("duplicate object key")));
+
+    let mut strict =
+        SseDecoder::new_with_validation(ValidationOptions::strict().with_duplicate_keys_rejected());
+    let events = strict.push(payload);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ParsedEventKind::Event);
+    assert!(events[0]
+        .errors
+        .iter()
+        .any(|err| err == "duplicate object key at '/type'"));
+
+    let events =
+        strict.push("data: {\"type\":\"x\",\"item\":{\"id\":1},\"list\":[{\"a\":1,\"a\":2}]}\n\n");
+    assert!(events[0]
+        .errors
+        .iter()
+        .any(|err| err == "duplicate object key at '/list/0/a'"));
+}
+
+#[test]
+fn id_field_is_attached_and_exposed_as_last_event_id() {
+    let mut decoder = SseDecoder::new();
+    assert_eq!(decoder.last_event_id(), None);
+    let events = decoder.push("id: evt_42\ndata: [DONE]\n\n");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].id.as_deref(), Some("evt_42"));
+    assert_eq!(decoder.last_event_id(), Some("evt_42"));
+
+    let events = decoder.push("data: [DONE]\n\nid:\ndata: [DONE]\n\n");
+    assert_eq!(events[0].id.as_deref(), Some("evt_42"));
+    assert_eq!(events[1].id, None);
+    assert_eq!(decoder.last_event_id(), None);
+}
+
+#[test]
+fn parses_done_sentinel() {
+    let mut decoder = SseDecoder::new();
+    let events = decoder.push("data: [DONE]\n\n");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ParsedEventKind::Done);
+}
+
+#[test]
+fn is_terminal_matches_done_and_final_response_events() {
+    let mut decoder = SseDecoder::new();
+    let events = decoder.push(
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1}\n\n\
+         data: {\"type\":\"response.completed\",\"sequence_number\":2,\"response\":{}}\n\n\
+         data: [DONE]\n\n",
+    );
+    assert_eq!(events.len(), 3);
+    assert!(!events[0].is_terminal());
+    assert!(events[1].is_terminal());
+    assert!(events[2].is_terminal());
+}
+
+#[test]
+fn parses_invalid_json() {
+    let mut decoder = SseDecoder::new();
+    let events = decoder.push("data: {not json}\n\n");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ParsedEventKind::InvalidJson);
+}
+
+#[test]
+fn buffer_overflow_yields_invalid_json_and_resets() {
+    let mut decoder = SseDecoder::new_with_limits(ValidationOptions::strict(), 16);
+    assert!(decoder.push("data: {\"type\":").is_empty());
+    let events = decoder.push("\"response.output_text.delta\"");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ParsedEventKind::InvalidJson);
+    assert_eq!(events[0].errors, vec!["sse buffer exceeded 16 bytes"]);
+
+    let events = decoder.push("\nevent: a\ndata: 0123456789\ndata: 0123456789\n");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event.as_deref(), Some("a"));
+    assert_eq!(events[0].errors, vec!["sse buffer exceeded 16 bytes"]);
+
+    let events = decoder.push("data: [DONE]\n\n");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ParsedEventKind::Done);
+}
+
+#[test]
+fn buffer_overflow_discards_rest_of_overlong_line() {
+    let mut decoder = SseDecoder::new_with_limits(ValidationOptions::strict(), 16);
+    let events = decoder.push("data: 0123456789abcdef");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].errors, vec!["sse buffer exceeded 16 bytes"]);
+
+    // Still the overlong line: its tail must not parse as a `data:` line.
+    assert!(decoder.push("data: [DONE]\n\n").is_empty());
+
+    let events = decoder.push("data: [DONE]\n\n");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ParsedEventKind::Done);
+}
+
+#[test]
+fn invalid_json_constructor_sets_fields() {
+    let parsed = ParsedEvent::invalid_json(
+        "raw".to_string(),
+        "boom".to_string(),
+        Some("response.created".to_string()),
+    );
+    assert_eq!(parsed.kind, ParsedEventKind::InvalidJson);
+    assert_eq!(parsed.event.as_deref(), Some("response.created"));
+    assert_eq!(parsed.raw, "raw");
+    assert_eq!(parsed.errors, vec!["boom".to_string()]);
+    assert!(parsed.data.is_none());
+    assert!(parsed.response_errors.is_empty());
+}
+
+#[test]
+fn captures_event_name_mismatch() {
+    let mut decoder = SseDecoder::new();
+    let payload = "event: response.created\n\
+                  data: {\"type\":\"response.completed\",\"sequence_number\":1,\"response\":{}}\n\n";
+    let events = decoder.push(payload);
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.kind, ParsedEventKind::Event);
+    assert!(event
+        .errors
+        .iter()
+        .any(|e| e.contains("does not match type")));
+}
+
+#[test]
+fn handles_split_chunks() {
+    let mut decoder = SseDecoder::new();
+    let part1 = "data: {\"type\":\"response.created\",\"sequence_number\":1,\n";
+    let part2 = "data: \"response\":{}}\n\n";
+    let mut events = decoder.push(part1);
+    assert!(events.is_empty());
+    events.extend(decoder.push(part2));
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ParsedEventKind::Event);
+}
+
+#[test]
+fn ignores_comment_lines() {
+    let mut decoder = SseDecoder::new();
+    let payload = ": keep-alive\n\
+                   data: {\"type\":\"response.created\",\"sequence_number\":1,\"response\":{}}\n\n";
+    let events = decoder.push(payload);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ParsedEventKind::Event);
+}
+
+#[test]
+fn empty_event_name_sets_none() {
+    let mut decoder = SseDecoder::new();
+    let payload = "event:\n\
+                  data: {\"type\":\"response.created\",\"sequence_number\":1,\"response\":{}}\n\n";
+    let events = decoder.push(payload);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, None);
+}
+
+#[test]
+fn finish_flushes_buffer() {
+    let mut decoder = SseDecoder::new();
+    let events =
+        decoder.push("data: {\"type\":\"response.created\",\"sequence_number\":1,\"response\":{}}");
+    assert!(events.is_empty());
+    let flushed = decoder.finish();
+    assert!(flushed.is_empty());
+}
@@ -0,0 +1,378 @@
+use super::*;
+use crate::validation::{
+    normalize_event_for_validation, normalize_output_item, normalize_response_resource,
+};
+
+#[test]
+fn captures_response_validation_errors() {
+    let mut decoder = SseDecoder::new();
+    let payload = "event: response.completed\n\
+                  data: {\"type\":\"response.completed\",\"sequence_number\":1,\"response\":{}}\n\n";
+    let events = decoder.push(payload);
+    assert_eq!(events.len(), 1);
+    let errors = &events[0].response_errors;
+    assert!(!errors.is_empty());
+    assert!(errors.iter().any(|err| err.contains("truncation")));
+    assert!(errors
+        .iter()
+        .any(|err| err.contains("previous_response_id")));
+}
+
+#[test]
+fn terminal_only_response_validation_skips_intermediate_frames() {
+    let mut decoder = SseDecoder::new_with_validation(
+        ValidationOptions::strict().with_terminal_response_validation_only(),
+    );
+    let payload = "event: response.in_progress\n\
+                  data: {\"type\":\"response.in_progress\",\"sequence_number\":1,\"response\":{}}\n\n\
+                  event: response.incomplete\n\
+                  data: {\"type\":\"response.incomplete\",\"sequence_number\":2,\"response\":{}}\n\n";
+    let events = decoder.push(payload);
+    assert_eq!(events.len(), 2);
+    assert!(
+        events[0].errors.is_empty(),
+        "errors: {:?}",
+        events[0].errors
+    );
+    assert!(events[0].response_errors.is_empty());
+    assert!(
+        events[1].errors.is_empty(),
+        "errors: {:?}",
+        events[1].errors
+    );
+    assert!(!events[1].response_errors.is_empty());
+}
+
+#[test]
+fn compat_openrouter_accepts_reasoning_text_and_missing_response_user() {
+    let mut decoder = SseDecoder::new_with_validation(ValidationOptions::compat_openrouter());
+    let payload = "event: response.created\n\
+                  data: {\"type\":\"response.created\",\"sequence_number\":1,\"response\":{\"background\":false,\"completed_at\":null,\"created_at\":1776635696,\"error\":null,\"frequency_penalty\":0,\"id\":\"resp_1\",\"incomplete_details\":null,\"instructions\":null,\"max_output_tokens\":null,\"max_tool_calls\":32,\"metadata\":{},\"model\":\"nvidia/nemotron-3-nano-30b-a3b:free\",\"object\":\"response\",\"output\":[],\"parallel_tool_calls\":false,\"presence_penalty\":0,\"previous_response_id\":null,\"prompt_cache_key\":null,\"reasoning\":null,\"safety_identifier\":null,\"service_tier\":\"auto\",\"status\":\"in_progress\",\"store\":false,\"temperature\":1,\"text\":{\"format\":{\"type\":\"text\"}},\"tool_choice\":\"auto\",\"tools\":[],\"top_logprobs\":0,\"top_p\":1,\"truncation\":\"disabled\",\"usage\":null}}\n\n\
+                  event: response.reasoning_text.delta\n\
+                  data: {\"type\":\"response.reasoning_text.delta\",\"sequence_number\":4,\"item_id\":\"rs_tmp_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"We\"}\n\n\
+                  event: response.reasoning_text.done\n\
+                  data: {\"type\":\"response.reasoning_text.done\",\"sequence_number\":5,\"item_id\":\"rs_tmp_1\",\"output_index\":0,\"content_index\":0,\"text\":\"We responded.\"}\n\n\
+                  event: response.completed\n\
+                  data: {\"type\":\"response.completed\",\"sequence_number\":6,\"response\":{\"background\":false,\"completed_at\":1776635696,\"created_at\":1776635696,\"error\":null,\"frequency_penalty\":0,\"id\":\"resp_1\",\"incomplete_details\":null,\"instructions\":null,\"max_output_tokens\":null,\"max_tool_calls\":32,\"metadata\":{},\"model\":\"nvidia/nemotron-3-nano-30b-a3b:free\",\"object\":\"response\",\"output\":[],\"parallel_tool_calls\":false,\"presence_penalty\":0,\"previous_response_id\":null,\"prompt_cache_key\":null,\"reasoning\":null,\"safety_identifier\":null,\"service_tier\":\"auto\",\"status\":\"completed\",\"store\":false,\"temperature\":1,\"text\":{\"format\":{\"type\":\"text\"}},\"tool_choice\":\"auto\",\"tools\":[],\"top_logprobs\":0,\"top_p\":1,\"truncation\":\"disabled\",\"usage\":null}}\n\n";
+    let events = decoder.push(payload);
+    assert_eq!(events.len(), 4);
+    for event in events {
+        assert!(
+            event.errors.is_empty(),
+            "unexpected event errors: {:?}",
+            event.errors
+        );
+        assert!(
+            event.response_errors.is_empty(),
+            "unexpected response errors: {:?}",
+            event.response_errors
+        );
+    }
+}
+
+#[test]
+fn normalize_event_inserts_item_id_for_function_call_arguments() {
+    let value = serde_json::json!({
+        "type": "response.function_call_arguments.delta",
+        "output_index": 2,
+        "delta": "{}"
+    });
+    let normalized =
+        normalize_event_for_validation(&value, ValidationOptions::compat_missing_item_ids());
+    assert_eq!(
+        normalized.get("item_id").and_then(|v| v.as_str()),
+        Some("item_2")
+    );
+}
+
+#[test]
+fn normalize_event_preserves_existing_item_id() {
+    let value = serde_json::json!({
+        "type": "response.function_call_arguments.done",
+        "output_index": 1,
+        "item_id": "item_custom"
+    });
+    let normalized =
+        normalize_event_for_validation(&value, ValidationOptions::compat_missing_item_ids());
+    assert_eq!(
+        normalized.get("item_id").and_then(|v| v.as_str()),
+        Some("item_custom")
+    );
+}
+
+#[test]
+fn normalize_event_for_validation_passthrough_non_object() {
+    let value = serde_json::json!("raw");
+    let normalized = normalize_event_for_validation(&value, ValidationOptions::strict());
+    assert_eq!(normalized, value);
+}
+
+#[test]
+fn compat_openrouter_normalizes_reasoning_text_event_types() {
+    let value = serde_json::json!({
+        "type": "response.reasoning_text.delta",
+        "sequence_number": 4,
+        "item_id": "rs_tmp_1",
+        "output_index": 0,
+        "content_index": 0,
+        "delta": "We"
+    });
+    let normalized = normalize_event_for_validation(&value, ValidationOptions::compat_openrouter());
+    assert_eq!(
+        normalized.get("type").and_then(|v| v.as_str()),
+        Some("response.reasoning.delta")
+    );
+}
+
+#[test]
+fn compat_openrouter_fills_missing_response_user_with_null() {
+    let value = serde_json::json!({
+        "type": "response.created",
+        "sequence_number": 1,
+        "response": {
+            "background": false,
+            "completed_at": null,
+            "created_at": 0,
+            "error": null,
+            "frequency_penalty": 0,
+            "id": "resp_1",
+            "incomplete_details": null,
+            "instructions": null,
+            "max_output_tokens": null,
+            "max_tool_calls": null,
+            "metadata": {},
+            "model": "fixture-model",
+            "object": "response",
+            "output": [],
+            "parallel_tool_calls": false,
+            "presence_penalty": 0,
+            "previous_response_id": null,
+            "prompt_cache_key": null,
+            "reasoning": null,
+            "safety_identifier": null,
+            "service_tier": "",
+            "status": "",
+            "store": false,
+            "temperature": 0,
+            "text": { "format": { "type": "text" } },
+            "tool_choice": "auto",
+            "tools": [],
+            "top_logprobs": 0,
+            "top_p": 0,
+            "truncation": "auto",
+            "usage": null
+        }
+    });
+    let normalized = normalize_event_for_validation(&value, ValidationOptions::compat_openrouter());
+    assert!(normalized
+        .get("response")
+        .and_then(|v| v.get("user"))
+        .is_some());
+    assert!(normalized
+        .get("response")
+        .and_then(|v| v.get("user"))
+        .unwrap()
+        .is_null());
+}
+
+#[test]
+fn compat_openrouter_fills_missing_reasoning_summary_with_null() {
+    let value = serde_json::json!({
+        "type": "response.created",
+        "sequence_number": 1,
+        "response": {
+            "background": false,
+            "completed_at": null,
+            "created_at": 0,
+            "error": null,
+            "frequency_penalty": 0,
+            "id": "resp_1",
+            "incomplete_details": null,
+            "instructions": null,
+            "max_output_tokens": null,
+            "max_tool_calls": null,
+            "metadata": {},
+            "model": "fixture-model",
+            "object": "response",
+            "output": [],
+            "parallel_tool_calls": false,
+            "presence_penalty": 0,
+            "previous_response_id": null,
+            "prompt_cache_key": null,
+            "reasoning": { "effort": "high" },
+            "safety_identifier": null,
+            "service_tier": "auto",
+            "status": "in_progress",
+            "store": false,
+            "temperature": 0,
+            "text": { "format": { "type": "text" } },
+            "tool_choice": "auto",
+            "tools": [],
+            "top_logprobs": 0,
+            "top_p": 0,
+            "truncation": "auto",
+            "usage": null
+        }
+    });
+    let normalized = normalize_event_for_validation(&value, ValidationOptions::compat_openrouter());
+    let reasoning = normalized
+        .get("response")
+        .and_then(|v| v.get("reasoning"))
+        .and_then(|v| v.as_object())
+        .expect("reasoning object");
+    assert_eq!(
+        reasoning.get("effort").and_then(|v| v.as_str()),
+        Some("high")
+    );
+    assert!(reasoning.get("summary").is_some());
+    assert!(reasoning.get("summary").unwrap().is_null());
+}
+
+#[test]
+fn compat_openrouter_accepts_response_reasoning_without_summary() {
+    let mut decoder = SseDecoder::new_with_validation(ValidationOptions::compat_openrouter());
+    let payload = "event: response.created\n\
+                  data: {\"type\":\"response.created\",\"sequence_number\":1,\"response\":{\"background\":false,\"completed_at\":null,\"created_at\":1776635696,\"error\":null,\"frequency_penalty\":0,\"id\":\"resp_1\",\"incomplete_details\":null,\"instructions\":null,\"max_output_tokens\":null,\"max_tool_calls\":32,\"metadata\":{},\"model\":\"google/gemma-4-26b-a4b-it-20260403\",\"object\":\"response\",\"output\":[],\"parallel_tool_calls\":false,\"presence_penalty\":0,\"previous_response_id\":null,\"prompt_cache_key\":null,\"reasoning\":{\"effort\":\"high\"},\"safety_identifier\":null,\"service_tier\":\"auto\",\"status\":\"in_progress\",\"store\":false,\"temperature\":1,\"text\":{\"format\":{\"type\":\"text\"}},\"tool_choice\":\"auto\",\"tools\":[],\"top_logprobs\":0,\"top_p\":1,\"truncation\":\"disabled\",\"usage\":null}}\n\n\
+                  event: response.completed\n\
+                  data: {\"type\":\"response.completed\",\"sequence_number\":6,\"response\":{\"background\":false,\"completed_at\":1776635696,\"created_at\":1776635696,\"error\":null,\"frequency_penalty\":0,\"id\":\"resp_1\",\"incomplete_details\":null,\"instructions\":null,\"max_output_tokens\":null,\"max_tool_calls\":32,\"metadata\":{},\"model\":\"google/gemma-4-26b-a4b-it-20260403\",\"object\":\"response\",\"output\":[{\"content\":[{\"text\":\"planning\",\"type\":\"reasoning_text\"}],\"format\":\"unknown\",\"id\":\"rs_tmp_1\",\"status\":\"completed\",\"summary\":[],\"type\":\"reasoning\"},{\"content\":[{\"annotations\":[],\"logprobs\":[],\"text\":\"done\",\"type\":\"output_text\"}],\"id\":\"msg_1\",\"role\":\"assistant\",\"status\":\"completed\",\"type\":\"message\"}],\"parallel_tool_calls\":false,\"presence_penalty\":0,\"previous_response_id\":null,\"prompt_cache_key\":null,\"reasoning\":{\"effort\":\"high\"},\"safety_identifier\":null,\"service_tier\":\"auto\",\"status\":\"completed\",\"store\":false,\"temperature\":1,\"text\":{\"format\":{\"type\":\"text\"}},\"tool_choice\":\"auto\",\"tools\":[],\"top_logprobs\":0,\"top_p\":1,\"truncation\":\"disabled\",\"usage\":{\"input_tokens\":1,\"input_tokens_details\":{\"cached_tokens\":0},\"output_tokens\":2,\"output_tokens_details\":{\"reasoning_tokens\":1},\"total_tokens\":3}}}\n\n";
+    let events = decoder.push(payload);
+    assert_eq!(events.len(), 2);
+    for event in events {
+        assert!(
+            event.errors.is_empty(),
+            "unexpected event errors: {:?}",
+            event.errors
+        );
+        assert!(
+            event.response_errors.is_empty(),
+            "unexpected response errors: {:?}",
+            event.response_errors
+        );
+    }
+}
+
+#[test]
+fn response_web_search_tool_normalization_is_validation_only() {
+    let value = serde_json::json!({
+        "type": "response.created",
+        "sequence_number": 1,
+        "response": {
+            "background": false,
+            "completed_at": null,
+            "created_at": 0,
+            "error": null,
+            "frequency_penalty": 0,
+            "id": "resp_1",
+            "incomplete_details": null,
+            "instructions": null,
+            "max_output_tokens": null,
+            "max_tool_calls": 32,
+            "metadata": {},
+            "model": "gpt-5.4-mini",
+            "object": "response",
+            "output": [],
+            "parallel_tool_calls": false,
+            "presence_penalty": 0,
+            "previous_response_id": null,
+            "prompt_cache_key": null,
+            "reasoning": { "effort": "none", "summary": null },
+            "safety_identifier": null,
+            "service_tier": "auto",
+            "status": "in_progress",
+            "store": true,
+            "temperature": 1,
+            "text": { "format": { "type": "text" }, "verbosity": "medium" },
+            "tool_choice": "auto",
+            "tools": [{
+                "type": "web_search",
+                "search_context_size": "low",
+                "user_location": {
+                    "type": "approximate",
+                    "country": "US",
+                    "region": null,
+                    "city": null,
+                    "timezone": null
+                }
+            }],
+            "top_logprobs": 0,
+            "top_p": 1,
+            "truncation": "disabled",
+            "usage": null,
+            "user": null
+        }
+    });
+    let mut decoder = SseDecoder::new_with_validation(
+        ValidationOptions::strict().with_response_web_search_tools(),
+    );
+    let raw = format!("event: response.created\ndata: {value}\n\n");
+    let events = decoder.push(&raw);
+
+    assert_eq!(events.len(), 1);
+    assert!(
+        events[0].response_errors.is_empty(),
+        "unexpected response errors: {:?}",
+        events[0].response_errors
+    );
+    assert_eq!(
+        events[0]
+            .data
+            .as_ref()
+            .and_then(|data| data.get("response"))
+            .and_then(|response| response.get("tools"))
+            .and_then(|tools| tools.as_array())
+            .and_then(|tools| tools.first())
+            .and_then(|tool| tool.get("type"))
+            .and_then(|value| value.as_str()),
+        Some("web_search")
+    );
+}
+
+#[test]
+fn normalize_output_item_prefers_call_id() {
+    let mut value = serde_json::json!({
+        "type": "function_call",
+        "call_id": "call_9"
+    });
+    normalize_output_item(&mut value, Some(3));
+    assert_eq!(value.get("id").and_then(|v| v.as_str()), Some("call_9"));
+}
+
+#[test]
+fn normalize_output_item_sets_item_id_when_missing() {
+    let mut value = serde_json::json!({
+        "type": "function_call",
+        "call_id": ""
+    });
+    normalize_output_item(&mut value, Some(2));
+    assert_eq!(value.get("id").and_then(|v| v.as_str()), Some("item_2"));
+}
+
+#[test]
+fn normalize_output_item_sets_output_id_when_missing() {
+    let mut value = serde_json::json!({
+        "type": "function_call_output",
+        "call_id": ""
+    });
+    normalize_output_item(&mut value, Some(0));
+    assert_eq!(value.get("id").and_then(|v| v.as_str()), Some("output_0"));
+}
+
+#[test]
+fn normalize_response_resource_sets_missing_ids() {
+    let mut value = serde_json::json!({
+        "output": [
+            {"type": "function_call", "call_id": "call_a"},
+            {"type": "function_call_output", "call_id": "call_b"}
+        ]
+    });
+    normalize_response_resource(&mut value, ValidationOptions::compat_missing_item_ids());
+    let output = value
+        .get("output")
+        .and_then(|v| v.as_array())
+        .expect("output");
+    assert_eq!(output[0].get("id").and_then(|v| v.as_str()), Some("call_a"));
+    assert_eq!(
+        output[1].get("id").and_then(|v| v.as_str()),
+        Some("output_call_b")
+    );
+}
@@ -0,0 +1,273 @@
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationOptions {
+    pub(crate) normalize_missing_item_ids: bool,
+    pub(crate) normalize_missing_response_user: bool,
+    pub(crate) normalize_reasoning_text_events: bool,
+    pub(crate) normalize_missing_reasoning_summary: bool,
+    pub(crate) normalize_response_web_search_tools: bool,
+    pub(crate) allow_unknown_event_types: bool,
+    pub(crate) validate_response_on_terminal_only: bool,
+    pub(crate) reject_duplicate_keys: bool,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            normalize_missing_item_ids: false,
+            normalize_missing_response_user: false,
+            normalize_reasoning_text_events: false,
+            normalize_missing_reasoning_summary: false,
+            normalize_response_web_search_tools: false,
+            allow_unknown_event_types: true,
+            validate_response_on_terminal_only: false,
+            reject_duplicate_keys: false,
+        }
+    }
+}
+
+impl ValidationOptions {
+    pub fn strict() -> Self {
+        Self::default()
+    }
+
+    pub fn compat_missing_item_ids() -> Self {
+        Self::strict().with_missing_item_ids()
+    }
+
+    pub fn compat_openrouter() -> Self {
+        Self::strict()
+            .with_missing_item_ids()
+            .with_missing_response_user()
+            .with_reasoning_text_events()
+            .with_missing_reasoning_summary()
+    }
+
+    pub fn with_missing_item_ids(mut self) -> Self {
+        self.normalize_missing_item_ids = true;
+        self
+    }
+
+    pub fn with_missing_response_user(mut self) -> Self {
+        self.normalize_missing_response_user = true;
+        self
+    }
+
+    pub fn with_reasoning_text_events(mut self) -> Self {
+        self.normalize_reasoning_text_events = true;
+        self
+    }
+
+    pub fn with_missing_reasoning_summary(mut self) -> Self {
+        self.normalize_missing_reasoning_summary = true;
+        self
+    }
+
+    pub fn with_response_web_search_tools(mut self) -> Self {
+        self.normalize_response_web_search_tools = true;
+        self
+    }
+
+    /// Flags events whose name (the `event:` field, else the data `type`) is
+    /// not in `allowed_stream_event_types()`.
+    pub fn with_unknown_event_types_rejected(mut self) -> Self {
+        self.allow_unknown_event_types = false;
+        self
+    }
+
+    /// Validates the embedded `response` only on [`TERMINAL_EVENT_TYPES`](crate::TERMINAL_EVENT_TYPES);
+    /// intermediate frames carry partial snapshots whose errors would be
+    /// superseded anyway, so only their envelope is checked.
+    pub fn with_terminal_response_validation_only(mut self) -> Self {
+        self.validate_response_on_terminal_only = true;
+        self
+    }
+
+    /// Flags data payloads that repeat an object key; `serde_json` otherwise
+    /// keeps the last value silently.
+    pub fn with_duplicate_keys_rejected(mut self) -> Self {
+        self.reject_duplicate_keys = true;
+        self
+    }
+}
+
+pub(crate) fn normalize_event_for_validation(
+    value: &Value,
+    validation: ValidationOptions,
+) -> Value {
+    let mut normalized = value.clone();
+    let Some(obj) = normalized.as_object_mut() else {
+        return normalized;
+    };
+
+    if validation.normalize_reasoning_text_events {
+        normalize_reasoning_text_event_type(obj);
+    }
+
+    let output_index = obj.get("output_index").and_then(|value| value.as_u64());
+    if let Some(item) = obj.get_mut("item") {
+        normalize_output_item(item, output_index);
+    }
+
+    if let Some(response) = obj.get_mut("response") {
+        normalize_response_resource(response, validation);
+    }
+
+    if let Some(event_type) = obj.get("type").and_then(|value| value.as_str()) {
+        if matches!(
+            event_type,
+            "response.function_call_arguments.delta" | "response.function_call_arguments.done"
+        ) && obj
+            .get("item_id")
+            .and_then(|value| value.as_str())
+            .map(|value| value.is_empty())
+            .unwrap_or(true)
+        {
+            if let Some(output_index) = obj.get("output_index").and_then(|value| value.as_u64()) {
+                obj.insert(
+                    "item_id".to_string(),
+                    Value::String(format!("item_{output_index}")),
+                );
+            }
+        }
+    }
+
+    normalized
+}
+
+fn normalize_reasoning_text_event_type(obj: &mut serde_json::Map<String, Value>) {
+    let Some(Value::String(event_type)) = obj.get_mut("type") else {
+        return;
+    };
+
+    match event_type.as_str() {
+        "response.reasoning_text.delta" => {
+            *event_type = "response.reasoning.delta".to_string();
+        }
+        "response.reasoning_text.done" => {
+            *event_type = "response.reasoning.done".to_string();
+        }
+        _ => {}
+    }
+}
+
+pub(crate) fn normalize_response_resource(response: &mut Value, validation: ValidationOptions) {
+    let Some(obj) = response.as_object_mut() else {
+        return;
+    };
+    if validation.normalize_missing_response_user && !obj.contains_key("user") {
+        obj.insert("user".to_string(), Value::Null);
+    }
+    if validation.normalize_missing_reasoning_summary {
+        normalize_response_reasoning_metadata(obj);
+    }
+    if validation.normalize_response_web_search_tools {
+        normalize_response_web_search_tools(obj);
+    }
+    let Some(output) = obj.get_mut("output") else {
+        return;
+    };
+    let Some(items) = output.as_array_mut() else {
+        return;
+    };
+    for (idx, item) in items.iter_mut().enumerate() {
+        normalize_output_item(item, Some(idx as u64));
+    }
+}
+
+fn normalize_response_web_search_tools(obj: &mut serde_json::Map<String, Value>) {
+    let Some(tools) = obj.get_mut("tools").and_then(Value::as_array_mut) else {
+        return;
+    };
+
+    for tool in tools {
+        let Some(tool_obj) = tool.as_object_mut() else {
+            continue;
+        };
+        let Some(tool_type) = tool_obj.get("type").and_then(Value::as_str) else {
+            continue;
+        };
+        if tool_type != "web_search" && tool_type != "web_search_2025_08_26" {
+            continue;
+        }
+
+        // The request schema already supports canonical `web_search`, but the current
+        // ResponseResource schema still validates echoed tools through the older preview tool.
+        // This is validation-only: provider_event data keeps the raw provider payload.
+        tool_obj.insert(
+            "type".to_string(),
+            Value::String("web_search_preview".to_string()),
+        );
+        tool_obj
+            .entry("user_location".to_string())
+            .or_insert(Value::Null);
+        tool_obj
+            .entry("search_context_size".to_string())
+            .or_insert_with(|| Value::String("medium".to_string()));
+    }
+}
+
+fn normalize_response_reasoning_metadata(obj: &mut serde_json::Map<String, Value>) {
+    let Some(reasoning) = obj.get_mut("reasoning").and_then(Value::as_object_mut) else {
+        return;
+    };
+
+    if !reasoning.contains_key("effort") {
+        reasoning.insert("effort".to_string(), Value::Null);
+    }
+    if !reasoning.contains_key("summary") {
+        reasoning.insert("summary".to_string(), Value::Null);
+    }
+}
+
+pub(crate) fn normalize_output_item(item: &mut Value, output_index: Option<u64>) {
+    let Some(obj) = item.as_object_mut() else {
+        return;
+    };
+    if obj
+        .get("id")
+        .and_then(|value| value.as_str())
+        .map(|value| !value.is_empty())
+        .unwrap_or(false)
+    {
+        return;
+    }
+
+    let item_type = obj.get("type").and_then(|value| value.as_str());
+    match item_type {
+        Some("function_call") => {
+            if let Some(call_id) = obj
+                .get("call_id")
+                .and_then(|value| value.as_str())
+                .filter(|value| !value.is_empty())
+            {
+                obj.insert("id".to_string(), Value::String(call_id.to_string()));
+                return;
+            }
+            if let Some(output_index) = output_index {
+                obj.insert(
+                    "id".to_string(),
+                    Value::String(format!("item_{output_index}")),
+                );
+            }
+        }
+        Some("function_call_output") => {
+            if let Some(call_id) = obj
+                .get("call_id")
+                .and_then(|value| value.as_str())
+                .filter(|value| !value.is_empty())
+            {
+                obj.insert("id".to_string(), Value::String(format!("output_{call_id}")));
+                return;
+            }
+            if let Some(output_index) = output_index {
+                obj.insert(
+                    "id".to_string(),
+                    Value::String(format!("output_{output_index}")),
+                );
+            }
+        }
+        _ => {}
+    }
+}
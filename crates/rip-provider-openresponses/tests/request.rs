@@ -1,3 +1,4 @@
+use rip_kernel::{Event, EventKind};
 use rip_provider_openresponses::{
     CreateResponseBuilder, CreateResponsePayload, ItemParam, SpecificToolChoiceParam,
     ToolChoiceParam, ToolChoiceValue, ToolParam,
@@ -28,6 +29,63 @@ fn item_param_reports_validation_errors() {
     assert!(!item.errors().is_empty());
 }
 
+#[test]
+fn item_param_function_call_output_from_tool_folds_events() {
+    let event = |seq: u64, kind: EventKind| Event {
+        id: format!("e{seq}"),
+        session_id: "s1".to_string(),
+        timestamp_ms: 0,
+        seq,
+        kind,
+    };
+    let events = vec![
+        event(
+            0,
+            EventKind::ToolStdout {
+                tool_id: "t1".to_string(),
+                chunk: "hello ".to_string(),
+            },
+        ),
+        event(
+            1,
+            EventKind::ToolStdout {
+                tool_id: "t1".to_string(),
+                chunk: "world".to_string(),
+            },
+        ),
+        event(
+            2,
+            EventKind::ToolEnded {
+                tool_id: "t1".to_string(),
+                exit_code: 0,
+                duration_ms: 5,
+                artifacts: None,
+            },
+        ),
+    ];
+
+    let item = ItemParam::function_call_output_from_tool("call_1", "bash", &events);
+    assert!(item.errors().is_empty(), "errors: {:?}", item.errors());
+    assert_eq!(item.value()["type"], "function_call_output");
+    assert_eq!(item.value()["call_id"], "call_1");
+    let output: serde_json::Value =
+        serde_json::from_str(item.value()["output"].as_str().expect("output string"))
+            .expect("output json");
+    assert_eq!(output["tool"], "bash");
+    assert_eq!(output["stdout"], "hello world");
+    assert_eq!(output["ok"], true);
+    assert_eq!(output["exit_code"], 0);
+
+    let unfinished = ItemParam::function_call_output_from_tool("call_2", "bash", &events[..1]);
+    let output: serde_json::Value = serde_json::from_str(
+        unfinished.value()["output"]
+            .as_str()
+            .expect("output string"),
+    )
+    .expect("output json");
+    assert_eq!(output["ok"], false);
+}
+
 #[test]
 fn item_param_value_roundtrip() {
     let item = ItemParam::new(json!({
@@ -183,6 +241,27 @@ fn tool_choice_allowed_tools_with_mode_is_valid() {
     assert!(choice.errors().is_empty());
 }
 
+#[test]
+fn tool_choice_allowed_from_tools_derives_specific_choices() {
+    let tools = vec![
+        ToolParam::function("echo"),
+        ToolParam::new(json!({ "type": "mcp", "server_label": "srv" })),
+    ];
+    let choice = ToolChoiceParam::allowed_from_tools(&tools, Some(ToolChoiceValue::Required));
+    assert!(choice.errors().is_empty(), "{:?}", choice.errors());
+    assert_eq!(
+        choice.value(),
+        &json!({
+            "type": "allowed_tools",
+            "mode": "required",
+            "tools": [
+                { "type": "function", "name": "echo" },
+                { "type": "mcp", "server_label": "srv" }
+            ]
+        })
+    );
+}
+
 #[test]
 fn tool_choice_allowed_tools_without_mode_is_valid() {
     let choice = ToolChoiceParam::allowed_tools(vec![SpecificToolChoiceParam::function("echo")]);
@@ -0,0 +1,61 @@
+#![cfg(feature = "async")]
+
+use futures_util::stream::{self, StreamExt};
+use rip_provider_openresponses::{sse_event_stream, ParsedEventKind, StreamRecorder};
+
+#[tokio::test]
+async fn sse_event_stream_decodes_chunked_bytes() {
+    let payload = "event: response.output_text.delta\n\
+data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"h\u{e9}llo\",\"logprobs\":[]}\n\n\
+data: [DONE]\n\n";
+    let bytes = payload.as_bytes();
+    let split = payload.find('\u{e9}').expect("multibyte char") + 1;
+    let chunks = vec![
+        bytes[..7].to_vec(),
+        bytes[7..split].to_vec(),
+        bytes[split..].to_vec(),
+    ];
+
+    let events: Vec<_> = sse_event_stream(stream::iter(chunks)).collect().await;
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].kind, ParsedEventKind::Event);
+    assert!(events[0].errors.is_empty(), "{:?}", events[0].errors);
+    assert_eq!(
+        events[0]
+            .data
+            .as_ref()
+            .and_then(|value| value.get("delta"))
+            .and_then(|value| value.as_str()),
+        Some("h\u{e9}llo")
+    );
+    assert_eq!(events[1].kind, ParsedEventKind::Done);
+}
+
+#[tokio::test]
+async fn stream_recorder_writes_sse_and_jsonl_fixtures() {
+    let sse = include_str!("../fixtures/openresponses/stream_all.sse");
+    let dir = std::env::temp_dir().join(format!("rip-recorder-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).expect("tmp dir");
+    let sse_path = dir.join("capture.sse");
+    let jsonl_path = dir.join("capture.jsonl");
+
+    let recorder = StreamRecorder::create(&sse_path, &jsonl_path).expect("recorder");
+    let chunks: Vec<Vec<u8>> = sse.as_bytes().chunks(97).map(<[u8]>::to_vec).collect();
+    let events: Vec<_> = recorder.record(stream::iter(chunks)).collect().await;
+    let recorded = recorder.finish().expect("finish");
+
+    let passthrough: Vec<_> = sse_event_stream(stream::iter([sse])).collect().await;
+    assert_eq!(events.len(), passthrough.len());
+    let decoded = events
+        .iter()
+        .filter(|event| event.kind == ParsedEventKind::Event)
+        .count();
+    assert_eq!(recorded, decoded);
+
+    assert_eq!(std::fs::read_to_string(&sse_path).expect("sse"), sse);
+    let jsonl = std::fs::read_to_string(&jsonl_path).expect("jsonl");
+    assert_eq!(jsonl.lines().count(), decoded);
+    assert!(rip_provider_openresponses::validate_fixture_stream(&jsonl_path).is_empty());
+
+    std::fs::remove_dir_all(&dir).expect("cleanup");
+}
@@ -1,6 +1,6 @@
 use rip_kernel::{EventKind, ProviderEventStatus};
 use rip_openresponses::allowed_stream_event_types;
-use rip_provider_openresponses::{EventFrameMapper, SseDecoder};
+use rip_provider_openresponses::{validate_fixture_stream, EventFrameMapper, SseDecoder};
 
 #[test]
 fn stream_fixture_maps_all_events() {
@@ -15,21 +15,26 @@ fn stream_fixture_maps_all_events() {
         frames.extend(mapper.map(event));
     }
 
-    let expected_output_text = parsed
-        .iter()
-        .filter(|event| {
-            matches!(
-                event.kind,
-                rip_provider_openresponses::ParsedEventKind::Event
-            ) && event
-                .data
-                .as_ref()
-                .and_then(|value| value.get("type"))
-                .and_then(|value| value.as_str())
-                == Some("response.output_text.delta")
-        })
-        .count();
-    let expected = allowed_stream_event_types().len() + 1 + expected_output_text;
+    let count_events_of_type = |event_type: &str| {
+        parsed
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event.kind,
+                    rip_provider_openresponses::ParsedEventKind::Event
+                ) && event
+                    .data
+                    .as_ref()
+                    .and_then(|value| value.get("type"))
+                    .and_then(|value| value.as_str())
+                    == Some(event_type)
+            })
+            .count()
+    };
+    let expected_output_text = count_events_of_type("response.output_text.delta");
+    let expected_reasoning_text = count_events_of_type("response.reasoning.delta");
+    let expected =
+        allowed_stream_event_types().len() + 1 + expected_output_text + expected_reasoning_text;
     assert_eq!(frames.len(), expected);
 
     for (idx, frame) in frames.iter().enumerate() {
@@ -63,7 +68,7 @@ fn stream_fixture_maps_all_events() {
                     assert!(data.is_none());
                 }
             },
-            EventKind::OutputTextDelta { .. } => {}
+            EventKind::OutputTextDelta { .. } | EventKind::ReasoningTextDelta { .. } => {}
             _ => panic!("unexpected frame type"),
         }
     }
@@ -80,6 +85,12 @@ fn stream_fixture_maps_all_events() {
         .count();
     assert_eq!(output_text_frames, expected_output_text);
 
+    let reasoning_text_frames = frames
+        .iter()
+        .filter(|frame| matches!(frame.kind, EventKind::ReasoningTextDelta { .. }))
+        .count();
+    assert_eq!(reasoning_text_frames, expected_reasoning_text);
+
     let mut last_sequence = None;
     for event in parsed
         .iter()
@@ -98,3 +109,34 @@ fn stream_fixture_maps_all_events() {
         last_sequence = Some(sequence);
     }
 }
+
+#[test]
+fn stream_jsonl_fixture_has_no_validation_failures() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/fixtures/openresponses/stream_all.jsonl"
+    );
+    let failures = validate_fixture_stream(path);
+    assert!(failures.is_empty(), "fixture failures: {failures:?}");
+}
+
+#[test]
+fn validate_fixture_stream_reports_failures_by_line() {
+    let dir = std::env::temp_dir().join(format!("rip-fixture-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("dir");
+    let path = dir.join("stream.jsonl");
+    let valid = include_str!("../fixtures/openresponses/stream_all.jsonl")
+        .lines()
+        .next()
+        .expect("line");
+    std::fs::write(
+        &path,
+        format!("{valid}\nnot json\n{{\"type\":\"bogus\"}}\n"),
+    )
+    .expect("write");
+
+    let failures = validate_fixture_stream(&path);
+    let lines: Vec<usize> = failures.iter().map(|(line, _)| *line).collect();
+    assert_eq!(lines, vec![2, 3]);
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -1,7 +1,8 @@
 use rip_kernel::{Event, EventKind, ProviderEventStatus};
 use rip_provider_openresponses::{
-    extract_reasoning_deltas, extract_text_deltas, extract_tool_call_argument_deltas,
-    EventFrameMapper, SseDecoder,
+    assemble_response, extract_reasoning_deltas, extract_text_deltas,
+    extract_tool_call_argument_deltas, merge_response_partial, EventFrameMapper, ResponseAssembler,
+    SseDecoder, TextAggregator,
 };
 use serde_json::json;
 
@@ -82,3 +83,219 @@ fn extract_text_deltas_skips_non_object_data() {
     let deltas = extract_text_deltas(&[event]);
     assert!(deltas.is_empty());
 }
+
+fn response_event(seq: u64, event_type: &str, response: serde_json::Value) -> Event {
+    Event {
+        id: format!("e{seq}"),
+        session_id: "s1".to_string(),
+        timestamp_ms: 0,
+        seq,
+        kind: EventKind::ProviderEvent {
+            provider: "openresponses".to_string(),
+            status: ProviderEventStatus::Event,
+            event_name: Some(event_type.to_string()),
+            data: Some(json!({
+                "type": event_type,
+                "sequence_number": seq,
+                "response": response
+            })),
+            raw: None,
+            errors: Vec::new(),
+            response_errors: Vec::new(),
+        },
+    }
+}
+
+#[test]
+fn merge_response_partial_merges_output_by_index() {
+    let mut base = json!({
+        "id": "resp_1",
+        "status": "in_progress",
+        "model": "gpt",
+        "output": [
+            {"id": "msg_1", "type": "message", "status": "in_progress", "content": []}
+        ]
+    });
+    merge_response_partial(
+        &mut base,
+        &json!({
+            "status": "completed",
+            "output": [
+                {"status": "completed", "content": [{"type": "output_text", "text": "hi"}]},
+                {"id": "fc_1", "type": "function_call", "name": "ls"}
+            ]
+        }),
+    );
+
+    assert_eq!(base["id"], "resp_1");
+    assert_eq!(base["model"], "gpt");
+    assert_eq!(base["status"], "completed");
+    assert_eq!(
+        base["output"],
+        json!([
+            {
+                "id": "msg_1",
+                "type": "message",
+                "status": "completed",
+                "content": [{"type": "output_text", "text": "hi"}]
+            },
+            {"id": "fc_1", "type": "function_call", "name": "ls"}
+        ])
+    );
+}
+
+#[test]
+fn assemble_response_folds_created_and_completed() {
+    let events = vec![
+        response_event(
+            1,
+            "response.created",
+            json!({"id": "resp_1", "status": "in_progress", "model": "gpt", "output": []}),
+        ),
+        response_event(
+            2,
+            "response.completed",
+            json!({
+                "id": "resp_1",
+                "status": "completed",
+                "output": [
+                    {"id": "msg_1", "type": "message", "content": [{"type": "output_text", "text": "done"}]}
+                ]
+            }),
+        ),
+    ];
+
+    let assembled = assemble_response(&events).expect("response");
+    assert_eq!(assembled["status"], "completed");
+    assert_eq!(assembled["model"], "gpt");
+    assert_eq!(
+        assembled["output"],
+        json!([
+            {"id": "msg_1", "type": "message", "content": [{"type": "output_text", "text": "done"}]}
+        ])
+    );
+    assert!(assemble_response(&[]).is_none());
+}
+
+#[test]
+fn response_assembler_tracks_growing_output_text() {
+    let mut decoder = SseDecoder::new();
+    let parsed = decoder.push(concat!(
+        "data: {\"type\":\"response.created\",\"sequence_number\":0,\"response\":{\"id\":\"resp_1\",\"status\":\"in_progress\",\"output\":[]}}\n\n",
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"Hel\"}\n\n",
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":2,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"lo\"}\n\n",
+        "data: {\"type\":\"response.completed\",\"sequence_number\":3,\"response\":{\"id\":\"resp_1\",\"status\":\"completed\",\"output\":[{\"type\":\"message\",\"id\":\"msg_1\",\"role\":\"assistant\",\"content\":[{\"type\":\"output_text\",\"text\":\"Hello\"}]}]}}\n\n",
+    ));
+    assert_eq!(parsed.len(), 4);
+
+    let text = |value: &serde_json::Value| {
+        value["output"][0]["content"][0]["text"]
+            .as_str()
+            .map(str::to_string)
+    };
+    let mut assembler = ResponseAssembler::new();
+
+    assembler.push(&parsed[0]);
+    assert_eq!(assembler.current()["status"], "in_progress");
+    assert_eq!(text(assembler.current()), None);
+
+    assembler.push(&parsed[1]);
+    assert_eq!(text(assembler.current()).as_deref(), Some("Hel"));
+
+    assembler.push(&parsed[2]);
+    assert_eq!(text(assembler.current()).as_deref(), Some("Hello"));
+
+    assembler.push(&parsed[3]);
+    assert_eq!(assembler.current()["status"], "completed");
+    assert_eq!(text(assembler.current()).as_deref(), Some("Hello"));
+    assert_eq!(assembler.current()["output"][0]["id"], "msg_1");
+}
+
+#[test]
+fn response_assembler_attaches_output_text_annotations() {
+    let mut decoder = SseDecoder::new();
+    let parsed = decoder.push(concat!(
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"See docs\"}\n\n",
+        "data: {\"type\":\"response.output_text.annotation.added\",\"sequence_number\":2,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"annotation_index\":0,\"annotation\":{\"type\":\"url_citation\",\"url\":\"https://example.com\",\"title\":\"Docs\",\"start_index\":4,\"end_index\":8}}\n\n",
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":3,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\".\"}\n\n",
+    ));
+    assert_eq!(parsed.len(), 3);
+
+    let mut assembler = ResponseAssembler::new();
+    for event in &parsed {
+        assembler.push(event);
+    }
+    let part = &assembler.current()["output"][0]["content"][0];
+    assert_eq!(part["text"], "See docs.");
+    assert_eq!(
+        part["annotations"],
+        serde_json::json!([{
+            "type": "url_citation",
+            "url": "https://example.com",
+            "title": "Docs",
+            "start_index": 4,
+            "end_index": 8
+        }])
+    );
+}
+
+#[test]
+fn response_assembler_caps_out_of_range_indices_to_an_append() {
+    let mut decoder = SseDecoder::new();
+    let parsed = decoder.push(concat!(
+        "data: {\"type\":\"response.output_item.added\",\"sequence_number\":1,\"output_index\":4294967295,\"item\":{\"type\":\"message\",\"id\":\"msg_1\"}}\n\n",
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":2,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":1000000000,\"delta\":\"Hi\"}\n\n",
+    ));
+    assert_eq!(parsed.len(), 2);
+
+    let mut assembler = ResponseAssembler::new();
+    for event in &parsed {
+        assembler.push(event);
+    }
+    let output = assembler.current()["output"].as_array().expect("output");
+    assert_eq!(output.len(), 1);
+    assert_eq!(output[0]["id"], "msg_1");
+    let content = output[0]["content"].as_array().expect("content");
+    assert_eq!(content.len(), 1);
+    assert_eq!(content[0]["text"], "Hi");
+}
+
+#[test]
+fn text_aggregator_keeps_interleaved_output_indices_apart() {
+    let mut decoder = SseDecoder::new();
+    let parsed = decoder.push(concat!(
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"Hel\"}\n\n",
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":2,\"item_id\":\"msg_2\",\"output_index\":1,\"content_index\":0,\"delta\":\"Wor\"}\n\n",
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":3,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"lo\"}\n\n",
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":4,\"item_id\":\"msg_2\",\"output_index\":1,\"content_index\":0,\"delta\":\"ld\"}\n\n",
+    ));
+    assert_eq!(parsed.len(), 4);
+
+    let mut aggregator = TextAggregator::new();
+    for event in &parsed {
+        aggregator.push(event);
+    }
+    let texts = aggregator.finish();
+    assert_eq!(texts.len(), 2);
+    assert_eq!(texts[&(0, 0)], "Hello");
+    assert_eq!(texts[&(1, 0)], "World");
+}
+
+#[test]
+fn text_aggregator_done_replaces_slot_with_authoritative_text() {
+    let mut decoder = SseDecoder::new();
+    let parsed = decoder.push(concat!(
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"Helo\"}\n\n",
+        "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":2,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":1,\"delta\":\"more\"}\n\n",
+        "data: {\"type\":\"response.output_text.done\",\"sequence_number\":3,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"text\":\"Hello\"}\n\n",
+    ));
+    assert_eq!(parsed.len(), 3);
+
+    let mut aggregator = TextAggregator::new();
+    for event in &parsed {
+        aggregator.push(event);
+    }
+    let texts = aggregator.finish();
+    assert_eq!(texts[&(0, 0)], "Hello");
+    assert_eq!(texts[&(0, 1)], "more");
+}
@@ -6,7 +6,9 @@ use std::{
 };
 
 use futures_util::future::BoxFuture;
-use rip_kernel::{CheckpointAction, Event, EventKind};
+use rip_kernel::{
+    CheckpointAction, Event, EventKind, HookContext, HookEngine, HookEventKind, HookOutcome,
+};
 use serde::Deserialize;
 use serde_json::Value;
 use tokio::sync::Semaphore;
@@ -126,6 +128,7 @@ pub struct ToolRunner {
     registry: Arc<ToolRegistry>,
     semaphore: Arc<Semaphore>,
     checkpoint_hook: Option<Arc<dyn CheckpointHook>>,
+    hooks: Option<Arc<HookEngine>>,
 }
 
 impl ToolRunner {
@@ -134,6 +137,7 @@ impl ToolRunner {
             registry,
             semaphore: Arc::new(Semaphore::new(max_concurrency.max(1))),
             checkpoint_hook: None,
+            hooks: None,
         }
     }
 
@@ -147,9 +151,17 @@ impl ToolRunner {
             registry,
             semaphore: Arc::new(Semaphore::new(max_concurrency.max(1))),
             checkpoint_hook: Some(hook),
+            hooks: None,
         }
     }
 
+    /// Runs `ToolStarting` hooks from `hooks` before each invocation; an
+    /// `Abort` outcome fails the tool without executing it.
+    pub fn with_hooks(mut self, hooks: Arc<HookEngine>) -> Self {
+        self.hooks = Some(hooks);
+        self
+    }
+
     pub async fn run(
         &self,
         session_id: &str,
@@ -161,7 +173,10 @@ impl ToolRunner {
         let started_at = Instant::now();
 
         let mut events = Vec::new();
-        self.emit_checkpoint_events(session_id, seq, &invocation, &mut events);
+        let veto = self.run_tool_starting_hooks(session_id, *seq, &invocation);
+        if veto.is_none() {
+            self.emit_checkpoint_events(session_id, seq, &invocation, &mut events);
+        }
         events.push(self.emit(
             session_id,
             seq,
@@ -172,6 +187,17 @@ impl ToolRunner {
                 timeout_ms: invocation.timeout_ms,
             },
         ));
+        if let Some(reason) = veto {
+            events.push(self.emit(
+                session_id,
+                seq,
+                EventKind::ToolFailed {
+                    tool_id,
+                    error: format!("vetoed by hook: {reason}"),
+                },
+            ));
+            return events;
+        }
 
         let handler = match self.registry.get(&invocation.name) {
             Some(handler) => handler,
@@ -399,6 +425,44 @@ impl ToolRunner {
         }
     }
 
+    fn run_tool_starting_hooks(
+        &self,
+        session_id: &str,
+        seq: u64,
+        invocation: &ToolInvocation,
+    ) -> Option<String> {
+        let hooks = self.hooks.as_ref()?;
+        // Fail closed: a path-declaring tool whose paths cannot be worked out
+        // must not slip past path-based hooks as if it declared none.
+        let affected_paths = match files_for_invocation(invocation) {
+            Ok(files) => files.map(|files| {
+                files
+                    .iter()
+                    .map(|path| path.to_string_lossy().replace('\\', "/"))
+                    .collect()
+            }),
+            Err(err) => {
+                return Some(format!(
+                    "{} affected paths could not be determined: {err}",
+                    invocation.name
+                ))
+            }
+        };
+        let ctx = HookContext {
+            session_id: session_id.to_string(),
+            seq,
+            timestamp_ms: now_ms(),
+            event: HookEventKind::ToolStarting,
+            output: None,
+            tool_name: Some(invocation.name.clone()),
+            affected_paths,
+        };
+        match hooks.run(&ctx) {
+            HookOutcome::Continue | HookOutcome::Replace { .. } => None,
+            HookOutcome::Abort { reason } => Some(reason),
+        }
+    }
+
     fn emit(&self, session_id: &str, seq: &mut u64, kind: EventKind) -> Event {
         let event = Event {
             id: Uuid::new_v4().to_string(),
@@ -505,6 +569,94 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn path_allowlist_hook_vetoes_writes_outside_allowed_root() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let registry = Arc::new(ToolRegistry::default());
+        let counter = calls.clone();
+        registry.register(
+            "write",
+            Arc::new(move |_invocation| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async { ToolOutput::success(vec![]) })
+            }),
+        );
+        let hooks = Arc::new(HookEngine::new());
+        hooks.register(rip_kernel::path_allowlist_hook(["src"]));
+        let runner = ToolRunner::new(registry, 1).with_hooks(hooks);
+
+        let write = |path: &str| ToolInvocation {
+            name: "write".to_string(),
+            args: serde_json::json!({"path": path, "content": "hi"}),
+            timeout_ms: None,
+        };
+        let mut seq = 0;
+        let vetoed = runner
+            .run("session-1", &mut seq, write("docs/notes.txt"))
+            .await;
+        assert!(matches!(vetoed[0].kind, EventKind::ToolStarted { .. }));
+        assert!(matches!(
+            &vetoed.last().expect("event").kind,
+            EventKind::ToolFailed { error, .. }
+                if error == "vetoed by hook: write targets docs/notes.txt outside allowed roots"
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        let allowed = runner.run("session-1", &mut seq, write("src/lib.rs")).await;
+        assert!(matches!(
+            allowed.last().expect("event").kind,
+            EventKind::ToolEnded { exit_code: 0, .. }
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let malformed = runner
+            .run(
+                "session-1",
+                &mut seq,
+                ToolInvocation {
+                    name: "write".to_string(),
+                    args: serde_json::json!({"content": "no path"}),
+                    timeout_ms: None,
+                },
+            )
+            .await;
+        assert!(matches!(
+            &malformed.last().expect("event").kind,
+            EventKind::ToolFailed { error, .. }
+                if error.starts_with("vetoed by hook: write affected paths could not be determined")
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn path_allowlist_hook_does_not_cover_tools_without_declared_paths() {
+        let registry = Arc::new(ToolRegistry::default());
+        registry.register(
+            "bash",
+            Arc::new(|_invocation| Box::pin(async { ToolOutput::success(vec![]) })),
+        );
+        let hooks = Arc::new(HookEngine::new());
+        hooks.register(rip_kernel::path_allowlist_hook(["src"]));
+        let runner = ToolRunner::new(registry, 1).with_hooks(hooks);
+
+        let mut seq = 0;
+        let events = runner
+            .run(
+                "session-1",
+                &mut seq,
+                ToolInvocation {
+                    name: "bash".to_string(),
+                    args: serde_json::json!({"command": "echo hi > docs/notes.txt"}),
+                    timeout_ms: None,
+                },
+            )
+            .await;
+        assert!(matches!(
+            events.last().expect("event").kind,
+            EventKind::ToolEnded { exit_code: 0, .. }
+        ));
+    }
+
     #[tokio::test]
     async fn runs_tool_and_streams_output() {
         let registry = Arc::new(ToolRegistry::default());
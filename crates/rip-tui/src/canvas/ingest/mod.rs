@@ -37,7 +37,7 @@ use crate::provider_event;
 pub(super) fn apply(canvas: &mut CanvasModel, event: &Event) {
     match &event.kind {
         EventKind::SessionStarted { input } => turns::on_session_started(canvas, event, input),
-        EventKind::OutputTextDelta { delta } => turns::append_agent_delta(canvas, delta),
+        EventKind::OutputTextDelta { delta, .. } => turns::append_agent_delta(canvas, delta),
         EventKind::SessionEnded { .. } => turns::finalize_agent_turn(canvas, event.timestamp_ms),
         EventKind::ToolStarted {
             tool_id,
@@ -79,6 +79,8 @@ fn output_delta_then_session_ended_closes_the_agent_turn() {
         110,
         EventKind::OutputTextDelta {
             delta: "hello".to_string(),
+            actor_id: None,
+            origin: None,
         },
     ));
     canvas.ingest(&event(
@@ -690,6 +692,9 @@ fn context_notice_upserts_from_selecting_to_compiled() {
             compiler_strategy: "recent_messages_v1".to_string(),
             from_seq: 0,
             from_message_id: None,
+            included_messages: None,
+            included_tokens: None,
+            dropped_messages: None,
             actor_id: "user".to_string(),
             origin: "cli".to_string(),
         },
@@ -125,6 +125,8 @@ mod tests {
             1,
             EventKind::OutputTextDelta {
                 delta: "hello".to_string(),
+                actor_id: None,
+                origin: None,
             },
         ));
         render_once(&state, RenderMode::Decoded, 100);
@@ -198,6 +200,8 @@ mod tests {
             5,
             EventKind::OutputTextDelta {
                 delta: "output".to_string(),
+                actor_id: None,
+                origin: None,
             },
         ));
         state.update(event(
@@ -276,6 +280,9 @@ mod tests {
                 compiler_strategy: "recent_messages_v1".to_string(),
                 from_seq: 1,
                 from_message_id: None,
+                included_messages: None,
+                included_tokens: None,
+                dropped_messages: None,
                 actor_id: "user".to_string(),
                 origin: "cli".to_string(),
             },
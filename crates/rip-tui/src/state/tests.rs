@@ -31,6 +31,8 @@ fn computes_ttft_and_e2e() {
         1300,
         EventKind::OutputTextDelta {
             delta: "a".to_string(),
+            actor_id: None,
+            origin: None,
         },
     ));
     state.update(event(
@@ -575,6 +577,8 @@ fn update_tracks_timings_derived_state_and_artifacts() {
             150,
             EventKind::OutputTextDelta {
                 delta: "world".to_string(),
+                actor_id: None,
+                origin: None,
             },
         ),
         event(
@@ -752,6 +756,9 @@ fn update_tracks_timings_derived_state_and_artifacts() {
                 compiler_strategy: "recent_messages_v1".to_string(),
                 from_seq: 1,
                 from_message_id: Some("m1".to_string()),
+                included_messages: None,
+                included_tokens: None,
+                dropped_messages: None,
                 actor_id: "user".to_string(),
                 origin: "cli".to_string(),
             },
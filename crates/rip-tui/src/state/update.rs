@@ -77,17 +77,17 @@ impl TuiState {
                     self.set_status_message("waiting for model...");
                 }
             }
-            EventKind::OpenResponsesResponseHeaders { .. } => {
-                if self.openresponses_response_headers_ms.is_none() {
-                    self.openresponses_response_headers_ms = Some(event.timestamp_ms);
-                }
+            EventKind::OpenResponsesResponseHeaders { .. }
+                if self.openresponses_response_headers_ms.is_none() =>
+            {
+                self.openresponses_response_headers_ms = Some(event.timestamp_ms);
             }
-            EventKind::OpenResponsesResponseFirstByte { .. } => {
-                if self.openresponses_response_first_byte_ms.is_none() {
-                    self.openresponses_response_first_byte_ms = Some(event.timestamp_ms);
-                }
+            EventKind::OpenResponsesResponseFirstByte { .. }
+                if self.openresponses_response_first_byte_ms.is_none() =>
+            {
+                self.openresponses_response_first_byte_ms = Some(event.timestamp_ms);
             }
-            EventKind::OutputTextDelta { delta: _ } => {
+            EventKind::OutputTextDelta { .. } => {
                 if self.first_output_ms.is_none() {
                     self.first_output_ms = Some(event.timestamp_ms);
                 }
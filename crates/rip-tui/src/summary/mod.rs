@@ -4,8 +4,10 @@ use crate::provider_event;
 
 pub fn event_type(event: &Event) -> &'static str {
     match &event.kind {
+        EventKind::SessionQueued { .. } => "session_queued",
         EventKind::SessionStarted { .. } => "session_started",
         EventKind::OutputTextDelta { .. } => "output_text_delta",
+        EventKind::ReasoningTextDelta { .. } => "reasoning_text_delta",
         EventKind::SessionEnded { .. } => "session_ended",
         EventKind::ContinuityCreated { .. } => "continuity_created",
         EventKind::ContinuityMessageAppended { .. } => "continuity_message_appended",
@@ -33,6 +35,7 @@ pub fn event_type(event: &Event) -> &'static str {
         EventKind::ToolEnded { .. } => "tool_ended",
         EventKind::ToolFailed { .. } => "tool_failed",
         EventKind::ProviderEvent { .. } => "provider_event",
+        EventKind::UsageReported { .. } => "usage_reported",
         EventKind::OpenResponsesRequest { .. } => "openresponses_request",
         EventKind::OpenResponsesRequestStarted { .. } => "openresponses_request_started",
         EventKind::OpenResponsesResponseHeaders { .. } => "openresponses_response_headers",
@@ -46,6 +49,7 @@ pub fn event_type(event: &Event) -> &'static str {
         EventKind::ToolTaskCancelled { .. } => "tool_task_cancelled",
         EventKind::ToolTaskOutputDelta { .. } => "tool_task_output_delta",
         EventKind::ToolTaskStdinWritten { .. } => "tool_task_stdin_written",
+        EventKind::ToolTaskStdinClosed { .. } => "tool_task_stdin_closed",
         EventKind::ToolTaskResized { .. } => "tool_task_resized",
         EventKind::ToolTaskSignalled { .. } => "tool_task_signalled",
     }
@@ -53,8 +57,12 @@ pub fn event_type(event: &Event) -> &'static str {
 
 pub fn event_summary(event: &Event) -> String {
     match &event.kind {
+        EventKind::SessionQueued {
+            max_concurrent_sessions,
+        } => format!("limit={max_concurrent_sessions}"),
         EventKind::SessionStarted { input } => format!("{:?}", truncate(input, 64)),
-        EventKind::OutputTextDelta { delta } => format!("{:?}", truncate(delta, 64)),
+        EventKind::OutputTextDelta { delta, .. } => format!("{:?}", truncate(delta, 64)),
+        EventKind::ReasoningTextDelta { delta } => format!("{:?}", truncate(delta, 64)),
         EventKind::SessionEnded { reason } => format!("{:?}", truncate(reason, 64)),
         EventKind::ContinuityCreated { workspace, title } => {
             if let Some(title) = title.as_deref().filter(|t| !t.is_empty()) {
@@ -299,6 +307,15 @@ pub fn event_summary(event: &Event) -> String {
         EventKind::OpenResponsesResponseFirstByte { request_index } => {
             format!("req={} (first_byte)", request_index)
         }
+        EventKind::UsageReported { usage, partial } => {
+            let tokens = |key: &str| usage.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+            let suffix = if *partial { " (partial)" } else { "" };
+            format!(
+                "in={} out={}{suffix}",
+                tokens("input_tokens"),
+                tokens("output_tokens")
+            )
+        }
         EventKind::CheckpointCreated { label, .. } | EventKind::CheckpointRewound { label, .. } => {
             format!("{:?}", truncate(label, 64))
         }
@@ -311,6 +328,7 @@ pub fn event_summary(event: &Event) -> String {
         EventKind::ToolTaskStdinWritten { chunk_b64, .. } => {
             format!("{:?}", truncate(chunk_b64, 64))
         }
+        EventKind::ToolTaskStdinClosed { .. } => "eof".to_string(),
         EventKind::ToolTaskResized { rows, cols, .. } => format!("{rows}x{cols}"),
         EventKind::ToolTaskSignalled { signal, .. } => signal.to_string(),
     }
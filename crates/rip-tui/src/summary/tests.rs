@@ -14,6 +14,12 @@ fn make_event(kind: EventKind) -> Event {
 #[test]
 fn event_type_maps_variants() {
     let cases = [
+        (
+            EventKind::SessionQueued {
+                max_concurrent_sessions: 2,
+            },
+            "session_queued",
+        ),
         (
             EventKind::SessionStarted {
                 input: "hi".to_string(),
@@ -23,6 +29,8 @@ fn event_type_maps_variants() {
         (
             EventKind::OutputTextDelta {
                 delta: "hi".to_string(),
+                actor_id: None,
+                origin: None,
             },
             "output_text_delta",
         ),
@@ -83,6 +91,9 @@ fn event_type_maps_variants() {
                 compiler_strategy: "recent_messages_v1".to_string(),
                 from_seq: 3,
                 from_message_id: Some("m1".to_string()),
+                included_messages: None,
+                included_tokens: None,
+                dropped_messages: None,
                 actor_id: "user".to_string(),
                 origin: "cli".to_string(),
             },
@@ -547,6 +558,12 @@ fn event_type_maps_remaining_variants() {
             },
             "tool_task_stdin_written",
         ),
+        (
+            EventKind::ToolTaskStdinClosed {
+                task_id: "task-1".to_string(),
+            },
+            "tool_task_stdin_closed",
+        ),
         (
             EventKind::ToolTaskResized {
                 task_id: "task-1".to_string(),
@@ -571,6 +588,11 @@ fn event_type_maps_remaining_variants() {
 
 #[test]
 fn event_summary_formats_remaining_variants() {
+    let queued = event_summary(&make_event(EventKind::SessionQueued {
+        max_concurrent_sessions: 2,
+    }));
+    assert_eq!(queued, "limit=2");
+
     let created = event_summary(&make_event(EventKind::ContinuityCreated {
         workspace: "/workspace".to_string(),
         title: Some("My Thread".to_string()),
@@ -625,6 +647,9 @@ fn event_summary_formats_remaining_variants() {
         compiler_strategy: "recent_messages_v1".to_string(),
         from_seq: 1,
         from_message_id: None,
+        included_messages: None,
+        included_tokens: None,
+        dropped_messages: None,
         actor_id: "user".to_string(),
         origin: "cli".to_string(),
     }));
@@ -890,6 +915,12 @@ fn event_summary_formats_remaining_variants() {
         })),
         "\"aGk=\""
     );
+    assert_eq!(
+        event_summary(&make_event(EventKind::ToolTaskStdinClosed {
+            task_id: "task-1".to_string(),
+        })),
+        "eof"
+    );
     assert_eq!(
         event_summary(&make_event(EventKind::ToolTaskResized {
             task_id: "task-1".to_string(),
@@ -31,6 +31,8 @@ fn basic_state() -> TuiState {
         1200,
         EventKind::OutputTextDelta {
             delta: "hello".to_string(),
+            actor_id: None,
+            origin: None,
         },
     ));
     state.update(event(
@@ -100,6 +102,8 @@ fn follow_run_state_mid_tool() -> TuiState {
         1200,
         EventKind::OutputTextDelta {
             delta: "Got it. I'll draft a 5-slide outline, then refine it.\n".to_string(),
+            actor_id: None,
+            origin: None,
         },
     ));
     state.update(event(
@@ -719,6 +723,8 @@ fn multi_turn_continuity_state() -> TuiState {
         1100,
         EventKind::OutputTextDelta {
             delta: "Kicked off clippy in the background.".to_string(),
+            actor_id: None,
+            origin: None,
         },
     ));
     state.update(event(
@@ -743,6 +749,8 @@ fn multi_turn_continuity_state() -> TuiState {
         2080,
         EventKind::OutputTextDelta {
             delta: "Let's outline the deploy checklist.".to_string(),
+            actor_id: None,
+            origin: None,
         },
     ));
     state.update(event(
@@ -814,6 +822,8 @@ fn markdown_rendering_state() -> TuiState {
                     ---\n\n\
                     See the [runbook](https://example.com/runbook) for details.\n"
                 .to_string(),
+            actor_id: None,
+            origin: None,
         },
     ));
     state.update(event(
@@ -0,0 +1,199 @@
+//! Minimal ustar reader/writer for checkpoint bundles. Only regular files (and
+//! directory entries, which are skipped on read) are supported; that is all
+//! `Workspace::export_checkpoints` produces.
+
+use std::io::{self, Read, Write};
+
+const BLOCK_LEN: usize = 512;
+const NAME_LEN: usize = 100;
+const PREFIX_LEN: usize = 155;
+
+pub(crate) struct ArchiveWriter<W: Write> {
+    out: W,
+    written: u64,
+    max_bytes: u64,
+}
+
+impl<W: Write> ArchiveWriter<W> {
+    pub(crate) fn new(out: W, max_bytes: u64) -> Self {
+        Self {
+            out,
+            written: 0,
+            max_bytes,
+        }
+    }
+
+    pub(crate) fn append(&mut self, path: &str, body: &[u8]) -> io::Result<()> {
+        let header = file_header(path, body.len() as u64)?;
+        self.write(&header)?;
+        self.write(body)?;
+        let padding = padded_len(body.len()) - body.len();
+        self.write(&[0u8; BLOCK_LEN][..padding])
+    }
+
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.write(&[0u8; BLOCK_LEN * 2])?;
+        self.out.flush()
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.written += bytes.len() as u64;
+        if self.written > self.max_bytes {
+            return Err(io::Error::new(
+                io::ErrorKind::FileTooLarge,
+                format!("checkpoint archive exceeds {} bytes", self.max_bytes),
+            ));
+        }
+        self.out.write_all(bytes)
+    }
+}
+
+/// Reads every regular-file entry as `(path, body)`. Inputs larger than
+/// `max_bytes` are rejected with `FileTooLarge`; anything that is not a
+/// well-formed ustar stream is `InvalidData`.
+pub(crate) fn read_entries(input: impl Read, max_bytes: u64) -> io::Result<Vec<(String, Vec<u8>)>> {
+    let mut bytes = Vec::new();
+    input.take(max_bytes + 1).read_to_end(&mut bytes)?;
+    if bytes.len() as u64 > max_bytes {
+        return Err(io::Error::new(
+            io::ErrorKind::FileTooLarge,
+            format!("checkpoint archive exceeds {max_bytes} bytes"),
+        ));
+    }
+
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    loop {
+        let Some(header) = bytes.get(offset..offset + BLOCK_LEN) else {
+            return Err(invalid("archive is truncated"));
+        };
+        if header.iter().all(|byte| *byte == 0) {
+            return Ok(entries);
+        }
+        offset += BLOCK_LEN;
+
+        let expected = parse_octal(&header[148..156])?;
+        let actual: u64 = header
+            .iter()
+            .enumerate()
+            .map(|(index, byte)| {
+                if (148..156).contains(&index) {
+                    u64::from(b' ')
+                } else {
+                    u64::from(*byte)
+                }
+            })
+            .sum();
+        if expected != actual {
+            return Err(invalid("archive header checksum mismatch"));
+        }
+
+        let size = usize::try_from(parse_octal(&header[124..136])?)
+            .map_err(|_| invalid("archive entry size overflows"))?;
+        let path = entry_path(header)?;
+        let body = offset
+            .checked_add(size)
+            .and_then(|end| bytes.get(offset..end))
+            .ok_or_else(|| invalid("archive entry body is truncated"))?;
+        match header[156] {
+            b'0' | 0 => entries.push((path, body.to_vec())),
+            b'5' => {}
+            other => {
+                return Err(invalid(&format!(
+                    "unsupported archive entry type {:?} for {path}",
+                    other as char
+                )))
+            }
+        }
+        offset += padded_len(size);
+    }
+}
+
+fn file_header(path: &str, size: u64) -> io::Result<[u8; BLOCK_LEN]> {
+    let (prefix, name) = split_path(path.as_bytes()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("archive path too long: {path}"),
+        )
+    })?;
+    let mut header = [0u8; BLOCK_LEN];
+    header[..name.len()].copy_from_slice(name);
+    write_octal(&mut header[100..108], 0o644)?;
+    write_octal(&mut header[108..116], 0)?;
+    write_octal(&mut header[116..124], 0)?;
+    write_octal(&mut header[124..136], size)?;
+    write_octal(&mut header[136..148], 0)?;
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    header[345..345 + prefix.len()].copy_from_slice(prefix);
+
+    header[148..156].fill(b' ');
+    let checksum: u64 = header.iter().map(|byte| u64::from(*byte)).sum();
+    header[148..156].copy_from_slice(format!("{checksum:06o}\0 ").as_bytes());
+    Ok(header)
+}
+
+/// Splits `path` into ustar `(prefix, name)` fields at a `/`.
+fn split_path(path: &[u8]) -> Option<(&[u8], &[u8])> {
+    if path.len() <= NAME_LEN {
+        return Some((&[], path));
+    }
+    path.iter()
+        .enumerate()
+        .filter(|(_, byte)| **byte == b'/')
+        .map(|(index, _)| (&path[..index], &path[index + 1..]))
+        .find(|(prefix, name)| prefix.len() <= PREFIX_LEN && name.len() <= NAME_LEN)
+}
+
+fn entry_path(header: &[u8]) -> io::Result<String> {
+    let name = trim_nul(&header[..NAME_LEN]);
+    let prefix = if &header[257..262] == b"ustar" {
+        trim_nul(&header[345..345 + PREFIX_LEN])
+    } else {
+        &[]
+    };
+    let mut path = Vec::with_capacity(prefix.len() + 1 + name.len());
+    if !prefix.is_empty() {
+        path.extend_from_slice(prefix);
+        path.push(b'/');
+    }
+    path.extend_from_slice(name);
+    String::from_utf8(path).map_err(|_| invalid("archive entry path is not UTF-8"))
+}
+
+fn trim_nul(field: &[u8]) -> &[u8] {
+    let end = field
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(field.len());
+    &field[..end]
+}
+
+fn write_octal(field: &mut [u8], value: u64) -> io::Result<()> {
+    let digits = format!("{value:0width$o}", width = field.len() - 1);
+    if digits.len() >= field.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "archive header field overflows",
+        ));
+    }
+    field[..digits.len()].copy_from_slice(digits.as_bytes());
+    field[digits.len()] = 0;
+    Ok(())
+}
+
+fn parse_octal(field: &[u8]) -> io::Result<u64> {
+    let text = std::str::from_utf8(trim_nul(field))
+        .map_err(|_| invalid("archive header field is not octal"))?
+        .trim();
+    u64::from_str_radix(text, 8).map_err(|_| invalid("archive header field is not octal"))
+}
+
+fn padded_len(len: usize) -> usize {
+    len.div_ceil(BLOCK_LEN) * BLOCK_LEN
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
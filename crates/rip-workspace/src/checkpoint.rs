@@ -0,0 +1,364 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{validate_relative, Workspace};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub id: String,
+    pub session_id: String,
+    pub label: String,
+    pub created_at_ms: u64,
+    pub files: Vec<CheckpointFile>,
+    /// Set for incremental checkpoints; inherited file bodies live in this
+    /// checkpoint (or further down its base chain).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_checkpoint_id: Option<String>,
+}
+
+impl Checkpoint {
+    /// Deterministic JSON for storing checkpoints in version control: object
+    /// keys sorted, paths `/`-separated, and `files` ordered by path. Keys
+    /// come out sorted because `serde_json::Map` is a `BTreeMap` without the
+    /// `preserve_order` feature; the canonical-json test pins that.
+    pub fn to_canonical_json(&self) -> String {
+        let mut canonical = self.clone();
+        for file in &mut canonical.files {
+            file.path = file.path.replace('\\', "/");
+        }
+        canonical.files.sort_by(|a, b| a.path.cmp(&b.path));
+        serde_json::to_value(&canonical)
+            .unwrap_or(serde_json::Value::Null)
+            .to_string()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointFile {
+    pub path: String,
+    pub exists: bool,
+    pub sha256: Option<String>,
+    /// The body was unchanged from the base checkpoint and is not stored here.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub inherited: bool,
+}
+
+impl Workspace {
+    pub fn create_checkpoint(
+        &self,
+        session_id: &str,
+        label: impl Into<String>,
+        files: &[PathBuf],
+    ) -> io::Result<Checkpoint> {
+        self.store_checkpoint(session_id, label.into(), files, None, |_, _| false)
+    }
+
+    /// Like `create_checkpoint`, but only stores bodies of files whose content
+    /// differs from `base_checkpoint_id`; unchanged files are recorded as
+    /// `inherited` and resolved through the base chain on rewind.
+    pub fn create_incremental_checkpoint(
+        &self,
+        session_id: &str,
+        label: impl Into<String>,
+        files: &[PathBuf],
+        base_checkpoint_id: &str,
+    ) -> io::Result<Checkpoint> {
+        let base = self.load_checkpoint(session_id, base_checkpoint_id)?;
+        let base_hashes: BTreeMap<&str, Option<&str>> = base
+            .files
+            .iter()
+            .map(|file| (file.path.as_str(), file.sha256.as_deref()))
+            .collect();
+
+        self.store_checkpoint(
+            session_id,
+            label.into(),
+            files,
+            Some(base.id.clone()),
+            |path, hash| base_hashes.get(path) == Some(&Some(hash)),
+        )
+    }
+
+    /// Snapshots `files` into a new checkpoint. Bodies for which
+    /// `unchanged(path, sha256)` holds are recorded as `inherited` from
+    /// `base_checkpoint_id` instead of being stored.
+    fn store_checkpoint(
+        &self,
+        session_id: &str,
+        label: String,
+        files: &[PathBuf],
+        base_checkpoint_id: Option<String>,
+        unchanged: impl Fn(&str, &str) -> bool,
+    ) -> io::Result<Checkpoint> {
+        let checkpoint_id = Uuid::new_v4().to_string();
+        let created_at_ms = (self.clock)();
+        let checkpoint_root = self.checkpoints_dir.join(session_id).join(&checkpoint_id);
+        let files_root = checkpoint_root.join("files");
+        fs::create_dir_all(&files_root)?;
+
+        let mut entries = Vec::new();
+
+        for path in files {
+            let rel = self.to_relative(path)?;
+            let rel_str = rel.to_string_lossy().to_string();
+
+            if path.exists() {
+                let bytes = fs::read(path)?;
+                let hash = hash_bytes(&bytes);
+                let inherited = unchanged(&rel_str, &hash);
+                if !inherited {
+                    let dest = files_root.join(&rel);
+                    if let Some(parent) = dest.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(&dest, &bytes)?;
+                }
+                entries.push(CheckpointFile {
+                    path: rel_str,
+                    exists: true,
+                    sha256: Some(hash),
+                    inherited,
+                });
+            } else {
+                entries.push(CheckpointFile {
+                    path: rel_str,
+                    exists: false,
+                    sha256: None,
+                    inherited: false,
+                });
+            }
+        }
+
+        let checkpoint = Checkpoint {
+            id: checkpoint_id,
+            session_id: session_id.to_string(),
+            label,
+            created_at_ms,
+            files: entries,
+            base_checkpoint_id,
+        };
+        self.write_checkpoint_metadata(&checkpoint_root, &checkpoint)?;
+
+        Ok(checkpoint)
+    }
+
+    pub fn list_checkpoints(&self, session_id: &str) -> io::Result<Vec<Checkpoint>> {
+        let session_dir = self.checkpoints_dir.join(session_id);
+        if !session_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut checkpoints = Vec::new();
+        for entry in fs::read_dir(session_dir)? {
+            let entry = entry?;
+            let path = entry.path().join("checkpoint.json");
+            if path.exists() {
+                let payload = fs::read(&path)?;
+                let checkpoint: Checkpoint = serde_json::from_slice(&payload)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+                checkpoints.push(checkpoint);
+            }
+        }
+
+        checkpoints.sort_by(|a, b| {
+            a.created_at_ms
+                .cmp(&b.created_at_ms)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        Ok(checkpoints)
+    }
+
+    pub fn rewind_to_checkpoint(&self, session_id: &str, checkpoint_id: &str) -> io::Result<()> {
+        let checkpoint = self.load_checkpoint(session_id, checkpoint_id)?;
+
+        let targets = checkpoint
+            .files
+            .iter()
+            .map(|file| Ok((file, self.root.join(checkpoint_rel_path(&file.path)?))))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let mut undo = BTreeMap::new();
+
+        for (_, target_path) in &targets {
+            if target_path.exists() {
+                let bytes = fs::read(target_path)?;
+                undo.insert(target_path.clone(), Some(bytes));
+            } else {
+                undo.insert(target_path.clone(), None);
+            }
+        }
+
+        let apply_result = (|| -> io::Result<()> {
+            for (file, target_path) in &targets {
+                if file.exists {
+                    let source_path = self.stored_file_path(&checkpoint, file)?;
+                    let bytes = fs::read(&source_path)?;
+                    if let Some(parent) = target_path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(target_path, &bytes)?;
+                } else if target_path.exists() {
+                    fs::remove_file(target_path)?;
+                }
+            }
+            Ok(())
+        })();
+
+        if let Err(err) = apply_result {
+            for (path, previous) in undo {
+                match previous {
+                    Some(bytes) => {
+                        if let Some(parent) = path.parent() {
+                            let _ = fs::create_dir_all(parent);
+                        }
+                        let _ = fs::write(&path, bytes);
+                    }
+                    None => {
+                        let _ = fs::remove_file(&path);
+                    }
+                }
+            }
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Re-hashes the stored file bodies of a checkpoint and returns the paths
+    /// whose content no longer matches the recorded `sha256` (including stored
+    /// bodies that have gone missing).
+    pub fn verify_checkpoint(
+        &self,
+        session_id: &str,
+        checkpoint_id: &str,
+    ) -> io::Result<Vec<String>> {
+        let checkpoint = self.load_checkpoint(session_id, checkpoint_id)?;
+
+        let mut mismatched = Vec::new();
+        for file in &checkpoint.files {
+            let Some(expected) = file.sha256.as_deref() else {
+                continue;
+            };
+            let source_path = self.stored_file_path(&checkpoint, file)?;
+            let matches = match fs::read(&source_path) {
+                Ok(bytes) => hash_bytes(&bytes) == expected,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => false,
+                Err(err) => return Err(err),
+            };
+            if !matches {
+                mismatched.push(file.path.clone());
+            }
+        }
+        Ok(mismatched)
+    }
+
+    pub(crate) fn load_checkpoint(
+        &self,
+        session_id: &str,
+        checkpoint_id: &str,
+    ) -> io::Result<Checkpoint> {
+        let metadata_path = self
+            .checkpoints_dir
+            .join(session_id)
+            .join(checkpoint_id)
+            .join("checkpoint.json");
+        let payload = fs::read(&metadata_path)?;
+        serde_json::from_slice(&payload)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub(crate) fn write_checkpoint_metadata(
+        &self,
+        checkpoint_root: &Path,
+        checkpoint: &Checkpoint,
+    ) -> io::Result<()> {
+        let payload = serde_json::to_vec_pretty(checkpoint)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(checkpoint_root.join("checkpoint.json"), payload)
+    }
+
+    /// Where the body of `file` is stored, following the base chain for
+    /// inherited entries.
+    fn stored_file_path(
+        &self,
+        checkpoint: &Checkpoint,
+        file: &CheckpointFile,
+    ) -> io::Result<PathBuf> {
+        let owner_id = body_owner(checkpoint, file, |id| {
+            self.load_checkpoint(&checkpoint.session_id, id)
+        })?;
+        Ok(self
+            .checkpoints_dir
+            .join(&checkpoint.session_id)
+            .join(owner_id)
+            .join("files")
+            .join(checkpoint_rel_path(&file.path)?))
+    }
+}
+
+/// Id of the checkpoint that stores the body of `file`: `checkpoint` itself,
+/// or for inherited entries the base that `load` reaches down the chain.
+pub(crate) fn body_owner(
+    checkpoint: &Checkpoint,
+    file: &CheckpointFile,
+    load: impl Fn(&str) -> io::Result<Checkpoint>,
+) -> io::Result<String> {
+    let mut owner_id = checkpoint.id.clone();
+    let mut base_id = checkpoint.base_checkpoint_id.clone();
+    let mut inherited = file.inherited;
+    let mut visited = BTreeSet::new();
+    while inherited {
+        let Some(next_id) = base_id else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("inherited file without base checkpoint: {}", file.path),
+            ));
+        };
+        if !visited.insert(next_id.clone()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("checkpoint base chain cycles at {next_id}"),
+            ));
+        }
+        let base = load(&next_id)?;
+        let entry = base
+            .files
+            .iter()
+            .find(|entry| entry.path == file.path)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("base checkpoint {next_id} missing file: {}", file.path),
+                )
+            })?;
+        inherited = entry.inherited;
+        owner_id = base.id;
+        base_id = base.base_checkpoint_id;
+    }
+    Ok(owner_id)
+}
+
+fn hash_bytes(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    let digest = hasher.finalize();
+    hex::encode(digest)
+}
+
+/// A recorded `CheckpointFile::path`, checked to be workspace-relative.
+/// Checkpoint metadata may come from an imported archive, so it is never
+/// joined onto a root unchecked.
+pub(crate) fn checkpoint_rel_path(path: &str) -> io::Result<PathBuf> {
+    let rel = PathBuf::from(path);
+    validate_relative(&rel).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    Ok(rel)
+}
+
+#[cfg(test)]
+mod tests;
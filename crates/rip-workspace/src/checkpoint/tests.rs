@@ -0,0 +1,348 @@
+use tempfile::tempdir;
+
+use super::*;
+
+#[test]
+fn create_and_rewind_checkpoint() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+
+    let file_a = root.join("a.txt");
+    fs::write(&file_a, b"one").expect("write");
+
+    let checkpoint = workspace
+        .create_checkpoint("s1", "initial", &[file_a.clone(), root.join("b.txt")])
+        .expect("checkpoint");
+
+    fs::write(&file_a, b"two").expect("write");
+    let file_b = root.join("b.txt");
+    fs::write(&file_b, b"new").expect("write");
+
+    workspace
+        .rewind_to_checkpoint("s1", &checkpoint.id)
+        .expect("rewind");
+
+    assert_eq!(fs::read_to_string(&file_a).unwrap(), "one");
+    assert!(!file_b.exists());
+}
+
+#[test]
+fn rewind_rejects_checkpoint_paths_outside_the_workspace() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path().join("ws");
+    let workspace = Workspace::new(&root).expect("workspace");
+    let checkpoint_root = workspace.checkpoints_dir().join("s1").join("cp1");
+    fs::create_dir_all(&checkpoint_root).expect("mkdir");
+    let outside = dir.path().join("outside.txt");
+    fs::write(&outside, b"keep").expect("write");
+    let checkpoint = Checkpoint {
+        id: "cp1".to_string(),
+        session_id: "s1".to_string(),
+        label: "evil".to_string(),
+        created_at_ms: 1,
+        files: vec![CheckpointFile {
+            path: "../outside.txt".to_string(),
+            exists: false,
+            sha256: None,
+            inherited: false,
+        }],
+        base_checkpoint_id: None,
+    };
+    workspace
+        .write_checkpoint_metadata(&checkpoint_root, &checkpoint)
+        .expect("metadata");
+
+    let err = workspace
+        .rewind_to_checkpoint("s1", "cp1")
+        .expect_err("escaping path");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(fs::read_to_string(&outside).unwrap(), "keep");
+}
+
+#[test]
+fn incremental_checkpoint_stores_only_changed_files() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+
+    let file_a = root.join("a.txt");
+    let file_b = root.join("b.txt");
+    fs::write(&file_a, b"one").expect("write");
+    fs::write(&file_b, b"two").expect("write");
+    let files = [file_a.clone(), file_b.clone()];
+
+    let base = workspace
+        .create_checkpoint("s1", "base", &files)
+        .expect("base");
+    fs::write(&file_b, b"TWO").expect("write");
+    let incremental = workspace
+        .create_incremental_checkpoint("s1", "incremental", &files, &base.id)
+        .expect("incremental");
+    assert_eq!(
+        incremental.base_checkpoint_id.as_deref(),
+        Some(base.id.as_str())
+    );
+
+    let stored = workspace
+        .checkpoints_dir()
+        .join("s1")
+        .join(&incremental.id)
+        .join("files");
+    assert!(!stored.join("a.txt").exists());
+    assert_eq!(fs::read_to_string(stored.join("b.txt")).unwrap(), "TWO");
+    assert!(incremental.files[0].inherited);
+    assert!(!incremental.files[1].inherited);
+    assert!(workspace
+        .verify_checkpoint("s1", &incremental.id)
+        .expect("verify")
+        .is_empty());
+
+    let chained = workspace
+        .create_incremental_checkpoint("s1", "chained", &files, &incremental.id)
+        .expect("chained");
+    assert!(chained.files.iter().all(|file| file.inherited));
+
+    fs::write(&file_a, b"changed").expect("write");
+    fs::write(&file_b, b"changed").expect("write");
+    workspace
+        .rewind_to_checkpoint("s1", &chained.id)
+        .expect("rewind");
+    assert_eq!(fs::read_to_string(&file_a).unwrap(), "one");
+    assert_eq!(fs::read_to_string(&file_b).unwrap(), "TWO");
+
+    let err = workspace
+        .create_incremental_checkpoint("s1", "orphan", &files, "missing")
+        .expect_err("missing base");
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn verify_checkpoint_reports_corrupted_files() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+
+    let file_a = root.join("a.txt");
+    let file_b = root.join("b.txt");
+    fs::write(&file_a, b"one").expect("write");
+    fs::write(&file_b, b"two").expect("write");
+
+    let checkpoint = workspace
+        .create_checkpoint("s1", "initial", &[file_a, file_b, root.join("c.txt")])
+        .expect("checkpoint");
+    assert!(workspace
+        .verify_checkpoint("s1", &checkpoint.id)
+        .expect("verify")
+        .is_empty());
+
+    let stored_b = root
+        .join(".rip")
+        .join("checkpoints")
+        .join("s1")
+        .join(&checkpoint.id)
+        .join("files")
+        .join("b.txt");
+    fs::write(&stored_b, b"corrupt").expect("corrupt");
+
+    let mismatched = workspace
+        .verify_checkpoint("s1", &checkpoint.id)
+        .expect("verify");
+    assert_eq!(mismatched, vec!["b.txt".to_string()]);
+    assert!(workspace.verify_checkpoint("s1", "missing").is_err());
+}
+
+#[test]
+fn create_checkpoint_accepts_string_label() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let file_a = root.join("a.txt");
+    fs::write(&file_a, b"one").expect("write");
+
+    let checkpoint = workspace
+        .create_checkpoint("s1", "label".to_string(), std::slice::from_ref(&file_a))
+        .expect("checkpoint");
+    assert_eq!(checkpoint.label, "label");
+}
+
+#[test]
+fn list_checkpoints_sorted() {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static TICK: AtomicU64 = AtomicU64::new(1_000);
+
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root)
+        .expect("workspace")
+        .with_clock(|| TICK.fetch_add(1, Ordering::SeqCst));
+    let file_a = root.join("a.txt");
+    fs::write(&file_a, b"one").expect("write");
+
+    let cp1 = workspace
+        .create_checkpoint("s1", "first", std::slice::from_ref(&file_a))
+        .expect("checkpoint");
+    let cp2 = workspace
+        .create_checkpoint("s1", "second", std::slice::from_ref(&file_a))
+        .expect("checkpoint");
+
+    let list = workspace.list_checkpoints("s1").expect("list");
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[0].id, cp1.id);
+    assert_eq!(list[1].id, cp2.id);
+}
+
+#[test]
+fn list_checkpoints_breaks_timestamp_ties_by_id() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root)
+        .expect("workspace")
+        .with_clock(|| 1_000);
+    let file_a = root.join("a.txt");
+    fs::write(&file_a, b"one").expect("write");
+
+    let mut ids: Vec<String> = (0..4)
+        .map(|idx| {
+            workspace
+                .create_checkpoint("s1", format!("cp{idx}"), std::slice::from_ref(&file_a))
+                .expect("checkpoint")
+                .id
+        })
+        .collect();
+    ids.sort();
+
+    let first = workspace.list_checkpoints("s1").expect("list");
+    assert!(first
+        .iter()
+        .all(|checkpoint| checkpoint.created_at_ms == 1_000));
+    let listed: Vec<String> = first.into_iter().map(|checkpoint| checkpoint.id).collect();
+    assert_eq!(listed, ids);
+    let again: Vec<String> = workspace
+        .list_checkpoints("s1")
+        .expect("list")
+        .into_iter()
+        .map(|checkpoint| checkpoint.id)
+        .collect();
+    assert_eq!(again, listed);
+}
+
+#[test]
+fn list_checkpoints_empty_session() {
+    let dir = tempdir().expect("tmp");
+    let workspace = Workspace::new(dir.path()).expect("workspace");
+    let list = workspace.list_checkpoints("missing").expect("list");
+    assert!(list.is_empty());
+}
+
+#[test]
+fn create_checkpoint_records_missing_file() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let missing = root.join("missing.txt");
+
+    let checkpoint = workspace
+        .create_checkpoint("s1", "missing", std::slice::from_ref(&missing))
+        .expect("checkpoint");
+
+    assert_eq!(checkpoint.files.len(), 1);
+    assert!(!checkpoint.files[0].exists);
+}
+
+#[test]
+fn create_checkpoint_rejects_outside_paths() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let outside = root.parent().unwrap().join("outside.txt");
+    let err = workspace
+        .create_checkpoint("s1", "outside", std::slice::from_ref(&outside))
+        .expect_err("error");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn rewind_missing_checkpoint_errors() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let err = workspace
+        .rewind_to_checkpoint("s1", "missing")
+        .expect_err("err");
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn rewind_failure_rolls_back() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let file_a = root.join("a.txt");
+    fs::write(&file_a, b"one").expect("write");
+
+    let checkpoint = workspace
+        .create_checkpoint("s1", "initial", std::slice::from_ref(&file_a))
+        .expect("checkpoint");
+
+    fs::write(&file_a, b"two").expect("write");
+
+    let checkpoint_file = root
+        .join(".rip")
+        .join("checkpoints")
+        .join("s1")
+        .join(&checkpoint.id)
+        .join("files")
+        .join("a.txt");
+    fs::remove_file(&checkpoint_file).expect("remove");
+
+    let err = workspace
+        .rewind_to_checkpoint("s1", &checkpoint.id)
+        .expect_err("rewind");
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    assert_eq!(fs::read_to_string(&file_a).unwrap(), "two");
+}
+
+#[test]
+fn list_checkpoints_invalid_metadata_errors() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let session_dir = root.join(".rip").join("checkpoints").join("s1");
+    fs::create_dir_all(&session_dir).expect("dir");
+    let bad = session_dir.join("bad.json");
+    fs::write(&bad, "{not json}").expect("write");
+    let entry_dir = session_dir.join("bad-checkpoint");
+    fs::create_dir_all(&entry_dir).expect("dir");
+    fs::rename(&bad, entry_dir.join("checkpoint.json")).expect("move");
+
+    let err = workspace.list_checkpoints("s1").expect_err("err");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn canonical_json_is_identical_for_equivalent_checkpoints() {
+    let file = |path: &str, sha: &str| CheckpointFile {
+        path: path.to_string(),
+        exists: true,
+        sha256: Some(sha.to_string()),
+        inherited: false,
+    };
+    let checkpoint = |files: Vec<CheckpointFile>| Checkpoint {
+        id: "cp-1".to_string(),
+        session_id: "s1".to_string(),
+        label: "label".to_string(),
+        created_at_ms: 42,
+        files,
+        base_checkpoint_id: None,
+    };
+
+    let a = checkpoint(vec![file("src/b.rs", "bb"), file("a.txt", "aa")]);
+    let b = checkpoint(vec![file("a.txt", "aa"), file("src\\b.rs", "bb")]);
+    let canonical = a.to_canonical_json();
+    assert_eq!(canonical, b.to_canonical_json());
+    assert_eq!(
+        canonical,
+        r#"{"created_at_ms":42,"files":[{"exists":true,"path":"a.txt","sha256":"aa"},{"exists":true,"path":"src/b.rs","sha256":"bb"}],"id":"cp-1","label":"label","session_id":"s1"}"#
+    );
+}
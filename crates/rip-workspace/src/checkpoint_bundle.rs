@@ -0,0 +1,249 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::{Component, Path};
+
+use crate::archive::{self, ArchiveWriter};
+use crate::checkpoint::{body_owner, checkpoint_rel_path};
+use crate::{normalize_rel, Checkpoint, Workspace};
+
+/// Upper bound on a checkpoint export archive; imports larger than this are
+/// rejected too, so anything `export_checkpoints` writes can be read back.
+pub const MAX_CHECKPOINT_ARCHIVE_BYTES: usize = 64 * 1024 * 1024;
+
+impl Workspace {
+    /// Writes every checkpoint of `session_id` (metadata and stored file
+    /// bodies) to `out` as a tar stream of `<checkpoint_id>/...` entries.
+    /// Archives larger than `MAX_CHECKPOINT_ARCHIVE_BYTES` fail with
+    /// `FileTooLarge`. Returns the number of checkpoints exported.
+    pub fn export_checkpoints(&self, session_id: &str, out: impl io::Write) -> io::Result<usize> {
+        let checkpoints = self.list_checkpoints(session_id)?;
+        if checkpoints.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no checkpoints for session: {session_id}"),
+            ));
+        }
+
+        let session_dir = self.checkpoints_dir.join(session_id);
+        let mut writer = ArchiveWriter::new(out, MAX_CHECKPOINT_ARCHIVE_BYTES as u64);
+        for checkpoint in &checkpoints {
+            let checkpoint_root = session_dir.join(&checkpoint.id);
+            writer.append(
+                &format!("{}/checkpoint.json", checkpoint.id),
+                &fs::read(checkpoint_root.join("checkpoint.json"))?,
+            )?;
+            for file in checkpoint
+                .files
+                .iter()
+                .filter(|file| file.exists && !file.inherited)
+            {
+                let rel = checkpoint_rel_path(&file.path)?;
+                writer.append(
+                    &format!("{}/files/{}", checkpoint.id, normalize_rel(&rel)),
+                    &fs::read(checkpoint_root.join("files").join(&rel))?,
+                )?;
+            }
+        }
+        writer.finish()?;
+        Ok(checkpoints.len())
+    }
+
+    /// Restores checkpoints from a tar stream written by `export_checkpoints`
+    /// into `session_id` (which may differ from the exporting session).
+    /// The whole archive is validated before anything is written: every
+    /// entry and every recorded file path must stay inside the workspace, and
+    /// every stored body (including those inherited down a base chain) must be
+    /// in the archive or already in `session_id`; otherwise it fails with
+    /// `InvalidData`. An id that already exists in `session_id` fails with
+    /// `AlreadyExists`. Returns the imported checkpoints ordered like
+    /// `list_checkpoints`.
+    pub fn import_checkpoints(
+        &self,
+        session_id: &str,
+        input: impl io::Read,
+    ) -> io::Result<Vec<Checkpoint>> {
+        let mut bundles: BTreeMap<String, ImportedCheckpoint> = BTreeMap::new();
+        for (path, body) in archive::read_entries(input, MAX_CHECKPOINT_ARCHIVE_BYTES as u64)? {
+            let (checkpoint_id, rest) = path
+                .split_once('/')
+                .filter(|(checkpoint_id, _)| checkpoint_id_is_valid(checkpoint_id))
+                .ok_or_else(|| invalid_archive_entry(&path))?;
+            let bundle = bundles.entry(checkpoint_id.to_string()).or_default();
+            if rest == "checkpoint.json" {
+                bundle.metadata = Some(body);
+            } else if let Some(rel) = rest.strip_prefix("files/") {
+                let rel = checkpoint_rel_path(rel).map_err(|_| invalid_archive_entry(&path))?;
+                bundle.bodies.insert(normalize_rel(&rel), body);
+            } else {
+                return Err(invalid_archive_entry(&path));
+            }
+        }
+        if bundles.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "archive contains no checkpoints",
+            ));
+        }
+
+        let mut imported = Vec::new();
+        for (checkpoint_id, bundle) in &bundles {
+            let metadata = bundle.metadata.as_deref().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("checkpoint {checkpoint_id} has no checkpoint.json"),
+                )
+            })?;
+            let mut checkpoint: Checkpoint = serde_json::from_slice(metadata)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            validate_imported_checkpoint(checkpoint_id, &checkpoint, &bundle.bodies)?;
+            checkpoint.session_id = session_id.to_string();
+            imported.push(checkpoint);
+        }
+
+        let session_dir = self.checkpoints_dir.join(session_id);
+        if let Some(existing) = imported
+            .iter()
+            .find(|checkpoint| session_dir.join(&checkpoint.id).exists())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "checkpoint {} already exists in session {session_id}",
+                    existing.id
+                ),
+            ));
+        }
+        self.check_imported_bodies(session_id, &imported, &bundles)?;
+
+        for checkpoint in &imported {
+            let checkpoint_root = session_dir.join(&checkpoint.id);
+            let files_root = checkpoint_root.join("files");
+            fs::create_dir_all(&files_root)?;
+            for (rel, body) in &bundles[&checkpoint.id].bodies {
+                let dest = files_root.join(rel);
+                if let Some(parent) = dest.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(dest, body)?;
+            }
+            self.write_checkpoint_metadata(&checkpoint_root, checkpoint)?;
+        }
+
+        imported.sort_by(|a, b| {
+            a.created_at_ms
+                .cmp(&b.created_at_ms)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        Ok(imported)
+    }
+
+    /// Fails with `InvalidData` unless every existing file of `imported` has
+    /// its body either in the archive or, for bases outside the archive,
+    /// already stored in `session_id`, so a later rewind cannot fail partway.
+    fn check_imported_bodies(
+        &self,
+        session_id: &str,
+        imported: &[Checkpoint],
+        bundles: &BTreeMap<String, ImportedCheckpoint>,
+    ) -> io::Result<()> {
+        let archived: BTreeMap<&str, &Checkpoint> = imported
+            .iter()
+            .map(|checkpoint| (checkpoint.id.as_str(), checkpoint))
+            .collect();
+        let load = |id: &str| match archived.get(id) {
+            Some(checkpoint) => Ok((*checkpoint).clone()),
+            None => self.load_checkpoint(session_id, id).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("base checkpoint {id} is not available: {err}"),
+                )
+            }),
+        };
+        for checkpoint in imported {
+            for file in checkpoint.files.iter().filter(|file| file.exists) {
+                let owner_id = body_owner(checkpoint, file, load)?;
+                let rel = checkpoint_rel_path(&file.path)?;
+                let present = match bundles.get(&owner_id) {
+                    Some(bundle) => bundle.bodies.contains_key(&normalize_rel(&rel)),
+                    None => self
+                        .checkpoints_dir
+                        .join(session_id)
+                        .join(&owner_id)
+                        .join("files")
+                        .join(&rel)
+                        .is_file(),
+                };
+                if !present {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "checkpoint {} archive is missing the body of {}",
+                            checkpoint.id, file.path
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn checkpoint_id_is_valid(checkpoint_id: &str) -> bool {
+    let mut components = Path::new(checkpoint_id).components();
+    matches!(components.next(), Some(Component::Normal(_))) && components.next().is_none()
+}
+
+#[derive(Default)]
+struct ImportedCheckpoint {
+    metadata: Option<Vec<u8>>,
+    bodies: BTreeMap<String, Vec<u8>>,
+}
+
+fn validate_imported_checkpoint(
+    checkpoint_id: &str,
+    checkpoint: &Checkpoint,
+    bodies: &BTreeMap<String, Vec<u8>>,
+) -> io::Result<()> {
+    if checkpoint.id != checkpoint_id {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "checkpoint {checkpoint_id} metadata has id {}",
+                checkpoint.id
+            ),
+        ));
+    }
+    if let Some(base_id) = checkpoint.base_checkpoint_id.as_deref() {
+        if !checkpoint_id_is_valid(base_id) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("checkpoint {checkpoint_id} has invalid base id: {base_id}"),
+            ));
+        }
+    }
+    let mut stored = BTreeSet::new();
+    for file in &checkpoint.files {
+        let rel = normalize_rel(&checkpoint_rel_path(&file.path)?);
+        if file.exists && !file.inherited {
+            stored.insert(rel);
+        }
+    }
+    if let Some(extra) = bodies.keys().find(|rel| !stored.contains(*rel)) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("checkpoint {checkpoint_id} archive has unlisted file: {extra}"),
+        ));
+    }
+    Ok(())
+}
+
+fn invalid_archive_entry(path: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unexpected archive entry: {path}"),
+    )
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,172 @@
+use tempfile::tempdir;
+
+use super::*;
+
+#[test]
+fn export_then_import_restores_checkpoints_in_fresh_workspace() {
+    let source_dir = tempdir().expect("tmp");
+    let source_root = source_dir.path();
+    let source = Workspace::new(source_root).expect("workspace");
+
+    let file_a = source_root.join("nested").join("a.txt");
+    fs::create_dir_all(file_a.parent().unwrap()).expect("mkdir");
+    fs::write(&file_a, b"one").expect("write");
+    let files = [file_a.clone(), source_root.join("b.txt")];
+    let base = source
+        .create_checkpoint("s1", "base", &files)
+        .expect("base");
+    fs::write(&file_a, b"two").expect("write");
+    let incremental = source
+        .create_incremental_checkpoint("s1", "incremental", &files, &base.id)
+        .expect("incremental");
+
+    let mut archive = Vec::new();
+    let exported = source
+        .export_checkpoints("s1", &mut archive)
+        .expect("export");
+    assert_eq!(exported, 2);
+
+    let target_dir = tempdir().expect("tmp");
+    let target_root = target_dir.path();
+    let target = Workspace::new(target_root).expect("workspace");
+    let imported = target
+        .import_checkpoints("s2", archive.as_slice())
+        .expect("import");
+    assert_eq!(imported.len(), 2);
+
+    let listed = target.list_checkpoints("s2").expect("list");
+    let ids: Vec<String> = listed.iter().map(|cp| cp.id.clone()).collect();
+    let source_ids: Vec<String> = source
+        .list_checkpoints("s1")
+        .expect("list")
+        .into_iter()
+        .map(|cp| cp.id)
+        .collect();
+    assert_eq!(ids, source_ids);
+    assert!(listed.iter().all(|cp| cp.session_id == "s2"));
+    assert!(target
+        .verify_checkpoint("s2", &incremental.id)
+        .expect("verify")
+        .is_empty());
+
+    target.rewind_to_checkpoint("s2", &base.id).expect("rewind");
+    assert_eq!(
+        fs::read_to_string(target_root.join("nested").join("a.txt")).unwrap(),
+        "one"
+    );
+}
+
+#[test]
+fn export_checkpoints_errors_for_unknown_session() {
+    let dir = tempdir().expect("tmp");
+    let workspace = Workspace::new(dir.path()).expect("workspace");
+    let err = workspace
+        .export_checkpoints("missing", Vec::new())
+        .expect_err("no checkpoints");
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+fn archive_of(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut writer = ArchiveWriter::new(&mut bytes, MAX_CHECKPOINT_ARCHIVE_BYTES as u64);
+    for (path, body) in entries {
+        writer.append(path, body).expect("append");
+    }
+    writer.finish().expect("finish");
+    bytes
+}
+
+#[test]
+fn import_rejects_archives_with_paths_outside_the_workspace() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path().join("ws");
+    let workspace = Workspace::new(&root).expect("workspace");
+    let metadata = serde_json::json!({
+        "id": "cp1",
+        "session_id": "s0",
+        "label": "evil",
+        "created_at_ms": 1,
+        "files": [
+            { "path": "ok.txt", "exists": true, "sha256": null },
+            { "path": "../evil.txt", "exists": true, "sha256": null }
+        ]
+    })
+    .to_string();
+    let malicious_metadata = archive_of(&[
+        ("cp1/checkpoint.json", metadata.as_bytes()),
+        ("cp1/files/ok.txt", b"ok"),
+    ]);
+    let malicious_entry = archive_of(&[("cp1/files/../../../evil.txt", b"pwned")]);
+
+    for archive in [malicious_metadata, malicious_entry] {
+        let err = workspace
+            .import_checkpoints("s1", archive.as_slice())
+            .expect_err("escaping path");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+    assert!(!workspace.checkpoints_dir().join("s1").exists());
+    assert!(!dir.path().join("evil.txt").exists());
+}
+
+#[test]
+fn import_rejects_checkpoint_ids_already_in_the_session() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let file = root.join("a.txt");
+    fs::write(&file, b"one").expect("write");
+    let checkpoint = workspace
+        .create_checkpoint("s1", "base", std::slice::from_ref(&file))
+        .expect("checkpoint");
+    let mut archive = Vec::new();
+    workspace
+        .export_checkpoints("s1", &mut archive)
+        .expect("export");
+
+    fs::write(&file, b"two").expect("write");
+    let err = workspace
+        .import_checkpoints("s1", archive.as_slice())
+        .expect_err("id collision");
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    assert!(workspace
+        .verify_checkpoint("s1", &checkpoint.id)
+        .expect("verify")
+        .is_empty());
+}
+
+#[test]
+fn import_rejects_archives_missing_stored_bodies() {
+    let dir = tempdir().expect("tmp");
+    let workspace = Workspace::new(dir.path()).expect("workspace");
+    let listed_without_body = serde_json::json!({
+        "id": "cp1",
+        "session_id": "s0",
+        "label": "base",
+        "created_at_ms": 1,
+        "files": [{ "path": "a.txt", "exists": true, "sha256": null }]
+    })
+    .to_string();
+    let inherited_from_missing_base = serde_json::json!({
+        "id": "cp2",
+        "session_id": "s0",
+        "label": "incremental",
+        "created_at_ms": 2,
+        "base_checkpoint_id": "cp0",
+        "files": [{ "path": "a.txt", "exists": true, "sha256": null, "inherited": true }]
+    })
+    .to_string();
+
+    for archive in [
+        archive_of(&[("cp1/checkpoint.json", listed_without_body.as_bytes())]),
+        archive_of(&[(
+            "cp2/checkpoint.json",
+            inherited_from_missing_base.as_bytes(),
+        )]),
+    ] {
+        let err = workspace
+            .import_checkpoints("s1", archive.as_slice())
+            .expect_err("missing body");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+    assert!(!workspace.checkpoints_dir().join("s1").exists());
+}
@@ -2,14 +2,23 @@ use std::fmt;
 use std::io;
 use std::path::{Component, Path, PathBuf};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Patch {
     ops: Vec<PatchOp>,
 }
 
 impl Patch {
     pub fn parse(input: &str) -> Result<Self, PatchParseError> {
-        parse_patch(input).map(|ops| Self { ops })
+        Self::parse_with_options(input, PatchParseOptions::default())
+    }
+
+    pub fn parse_with_options(
+        input: &str,
+        options: PatchParseOptions,
+    ) -> Result<Self, PatchParseError> {
+        parse_patch(input, options).map(|ops| Self { ops })
     }
 
     pub fn ops(&self) -> &[PatchOp] {
@@ -32,49 +41,137 @@ impl Patch {
         }
         paths
     }
+
+    /// Builds the patch that undoes this one: ops run in reverse order, adds
+    /// become deletes, and update hunks swap `before`/`after` (moves flip
+    /// direction). Deletes cannot be inverted because the patch does not carry
+    /// the removed content.
+    pub fn invert(&self) -> Result<Patch, PatchParseError> {
+        let mut ops = Vec::with_capacity(self.ops.len());
+        for op in self.ops.iter().rev() {
+            let inverted = match op {
+                PatchOp::AddFile { path, .. } => PatchOp::DeleteFile { path: path.clone() },
+                PatchOp::DeleteFile { path } => {
+                    return Err(PatchParseError::Invalid {
+                        message: format!(
+                            "cannot invert delete without original content: {}",
+                            path.display()
+                        ),
+                    })
+                }
+                PatchOp::UpdateFile {
+                    path,
+                    moved_to,
+                    hunks,
+                } => PatchOp::UpdateFile {
+                    path: moved_to.clone().unwrap_or_else(|| path.clone()),
+                    moved_to: moved_to.as_ref().map(|_| path.clone()),
+                    hunks: hunks
+                        .iter()
+                        .map(|hunk| PatchHunk {
+                            before: hunk.after.clone(),
+                            after: hunk.before.clone(),
+                        })
+                        .collect(),
+                },
+            };
+            ops.push(inverted);
+        }
+        Ok(Patch { ops })
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum PatchOp {
     AddFile {
+        #[serde(serialize_with = "serialize_rel_path")]
         path: PathBuf,
         content: String,
     },
     DeleteFile {
+        #[serde(serialize_with = "serialize_rel_path")]
         path: PathBuf,
     },
     UpdateFile {
+        #[serde(serialize_with = "serialize_rel_path")]
         path: PathBuf,
+        #[serde(serialize_with = "serialize_opt_rel_path")]
         moved_to: Option<PathBuf>,
         hunks: Vec<PatchHunk>,
     },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct PatchHunk {
     pub before: Vec<String>,
     pub after: Vec<String>,
 }
 
+/// Size limits enforced while parsing, so a pathological patch fails fast
+/// instead of being materialized and applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatchParseOptions {
+    pub max_ops: usize,
+    pub max_hunks_per_op: usize,
+}
+
+impl Default for PatchParseOptions {
+    fn default() -> Self {
+        Self {
+            max_ops: 10_000,
+            max_hunks_per_op: 10_000,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct PatchParseError {
-    pub message: String,
+pub enum PatchParseError {
+    Invalid {
+        message: String,
+    },
+    /// The patch exceeds a [`PatchParseOptions`] limit.
+    TooLarge {
+        message: String,
+    },
+}
+
+impl PatchParseError {
+    pub fn message(&self) -> &str {
+        match self {
+            PatchParseError::Invalid { message } | PatchParseError::TooLarge { message } => message,
+        }
+    }
 }
 
 impl fmt::Display for PatchParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.message)
+        write!(f, "{}", self.message())
     }
 }
 
 impl std::error::Error for PatchParseError {}
 
-fn parse_patch(input: &str) -> Result<Vec<PatchOp>, PatchParseError> {
+fn serialize_rel_path<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&path.to_string_lossy().replace('\\', "/"))
+}
+
+fn serialize_opt_rel_path<S: Serializer>(
+    path: &Option<PathBuf>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match path {
+        Some(path) => serialize_rel_path(path, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn parse_patch(input: &str, options: PatchParseOptions) -> Result<Vec<PatchOp>, PatchParseError> {
     let mut lines = input.lines();
     match lines.next() {
         Some("*** Begin Patch") => {}
         _ => {
-            return Err(PatchParseError {
+            return Err(PatchParseError::Invalid {
                 message: "missing '*** Begin Patch' header".to_string(),
             })
         }
@@ -86,6 +183,11 @@ fn parse_patch(input: &str) -> Result<Vec<PatchOp>, PatchParseError> {
         if line == "*** End Patch" {
             return Ok(ops);
         }
+        if ops.len() >= options.max_ops {
+            return Err(PatchParseError::TooLarge {
+                message: format!("patch exceeds {} ops", options.max_ops),
+            });
+        }
 
         if let Some(path) = line.strip_prefix("*** Add File: ") {
             let path = parse_rel_path(path)?;
@@ -96,7 +198,7 @@ fn parse_patch(input: &str) -> Result<Vec<PatchOp>, PatchParseError> {
                 }
                 let next = pending.next().expect("peeked");
                 let Some(rest) = next.strip_prefix('+') else {
-                    return Err(PatchParseError {
+                    return Err(PatchParseError::Invalid {
                         message: format!("add file line must start with '+': {next}"),
                     });
                 };
@@ -141,29 +243,29 @@ fn parse_patch(input: &str) -> Result<Vec<PatchOp>, PatchParseError> {
                 }
                 if next.starts_with("@@") {
                     if !current.is_empty() {
-                        hunks.push(std::mem::take(&mut current));
+                        push_hunk(&mut hunks, std::mem::take(&mut current), &path, options)?;
                     }
                     continue;
                 }
                 let mut chars = next.chars();
-                let prefix = chars.next().ok_or_else(|| PatchParseError {
+                let prefix = chars.next().ok_or_else(|| PatchParseError::Invalid {
                     message: "empty patch line".to_string(),
                 })?;
                 let rest = chars.as_str().to_string();
                 match prefix {
                     ' ' | '+' | '-' => current.push((prefix, rest)),
                     _ => {
-                        return Err(PatchParseError {
+                        return Err(PatchParseError::Invalid {
                             message: format!("invalid patch line prefix '{prefix}': {next}"),
                         })
                     }
                 }
             }
             if !current.is_empty() {
-                hunks.push(current);
+                push_hunk(&mut hunks, current, &path, options)?;
             }
             if hunks.is_empty() {
-                return Err(PatchParseError {
+                return Err(PatchParseError::Invalid {
                     message: format!("update file has no hunks: {}", path.display()),
                 });
             }
@@ -196,31 +298,50 @@ fn parse_patch(input: &str) -> Result<Vec<PatchOp>, PatchParseError> {
             continue;
         }
 
-        return Err(PatchParseError {
+        return Err(PatchParseError::Invalid {
             message: format!("unexpected line: {line}"),
         });
     }
 
-    Err(PatchParseError {
+    Err(PatchParseError::Invalid {
         message: "missing '*** End Patch' footer".to_string(),
     })
 }
 
+fn push_hunk(
+    hunks: &mut Vec<Vec<(char, String)>>,
+    hunk: Vec<(char, String)>,
+    path: &Path,
+    options: PatchParseOptions,
+) -> Result<(), PatchParseError> {
+    if hunks.len() >= options.max_hunks_per_op {
+        return Err(PatchParseError::TooLarge {
+            message: format!(
+                "update file exceeds {} hunks: {}",
+                options.max_hunks_per_op,
+                path.display()
+            ),
+        });
+    }
+    hunks.push(hunk);
+    Ok(())
+}
+
 fn parse_rel_path(raw: &str) -> Result<PathBuf, PatchParseError> {
     let trimmed = raw.trim();
     if trimmed.is_empty() {
-        return Err(PatchParseError {
+        return Err(PatchParseError::Invalid {
             message: "path cannot be empty".to_string(),
         });
     }
     let path = PathBuf::from(trimmed);
     if path.is_absolute() {
-        return Err(PatchParseError {
+        return Err(PatchParseError::Invalid {
             message: "absolute paths are not allowed".to_string(),
         });
     }
     if path.components().any(|c| matches!(c, Component::ParentDir)) {
-        return Err(PatchParseError {
+        return Err(PatchParseError::Invalid {
             message: "path escapes workspace root".to_string(),
         });
     }
@@ -323,6 +444,81 @@ mod tests {
         assert_eq!(parsed.affected_paths().len(), 3);
     }
 
+    #[test]
+    fn serializes_ops_and_hunks_to_json() {
+        let patch = r#"*** Begin Patch
+*** Add File: dir\a.txt
++one
+*** Update File: b.txt
+*** Move to: moved/b.txt
+@@
+ keep
+-gone
++new
++extra
+*** Delete File: c.txt
+*** End Patch"#;
+        let parsed = Patch::parse(patch).expect("parse");
+        let json = serde_json::to_value(&parsed).expect("json");
+        let ops = json["ops"].as_array().expect("ops");
+        assert_eq!(ops.len(), 3);
+
+        assert_eq!(ops[0]["type"], "add_file");
+        assert_eq!(ops[0]["path"], "dir/a.txt");
+        assert_eq!(ops[0]["content"], "one\n");
+
+        assert_eq!(ops[1]["type"], "update_file");
+        assert_eq!(ops[1]["path"], "b.txt");
+        assert_eq!(ops[1]["moved_to"], "moved/b.txt");
+        let hunks = ops[1]["hunks"].as_array().expect("hunks");
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(hunks[0]["before"].as_array().expect("before").len(), 2);
+        assert_eq!(hunks[0]["after"].as_array().expect("after").len(), 3);
+
+        assert_eq!(ops[2]["type"], "delete_file");
+        assert_eq!(ops[2]["path"], "c.txt");
+    }
+
+    #[test]
+    fn invert_swaps_ops_and_hunks() {
+        let patch = r#"*** Begin Patch
+*** Add File: a.txt
++one
+*** Update File: b.txt
+*** Move to: c.txt
+@@
+-old
++new
+*** End Patch"#;
+        let inverted = Patch::parse(patch)
+            .expect("parse")
+            .invert()
+            .expect("invert");
+        assert_eq!(
+            inverted.ops(),
+            &[
+                PatchOp::UpdateFile {
+                    path: PathBuf::from("c.txt"),
+                    moved_to: Some(PathBuf::from("b.txt")),
+                    hunks: vec![PatchHunk {
+                        before: vec!["new".to_string()],
+                        after: vec!["old".to_string()],
+                    }],
+                },
+                PatchOp::DeleteFile {
+                    path: PathBuf::from("a.txt"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn invert_rejects_delete() {
+        let patch = "*** Begin Patch\n*** Delete File: a.txt\n*** End Patch";
+        let err = Patch::parse(patch).expect("parse").invert().unwrap_err();
+        assert!(err.to_string().contains("cannot invert delete"));
+    }
+
     #[test]
     fn apply_hunks_replaces_lines() {
         let hunks = vec![PatchHunk {
@@ -369,6 +565,35 @@ mod tests {
         assert!(err.to_string().contains("invalid patch line prefix"));
     }
 
+    #[test]
+    fn parse_rejects_patch_exceeding_max_ops() {
+        let patch = "*** Begin Patch\n*** Add File: a.txt\n+a\n*** Add File: b.txt\n+b\n*** Delete File: c.txt\n*** End Patch";
+        let options = PatchParseOptions {
+            max_ops: 2,
+            ..PatchParseOptions::default()
+        };
+        let err = Patch::parse_with_options(patch, options).unwrap_err();
+        assert!(matches!(err, PatchParseError::TooLarge { .. }));
+        assert!(err.to_string().contains("exceeds 2 ops"));
+        assert_eq!(Patch::parse(patch).expect("default limits").ops().len(), 3);
+    }
+
+    #[test]
+    fn parse_rejects_update_exceeding_max_hunks_per_op() {
+        let patch = "*** Begin Patch\n*** Update File: a.txt\n@@\n-a\n+A\n@@\n-b\n+B\n@@\n-c\n+C\n*** End Patch";
+        let options = PatchParseOptions {
+            max_hunks_per_op: 2,
+            ..PatchParseOptions::default()
+        };
+        let err = Patch::parse_with_options(patch, options).unwrap_err();
+        assert!(matches!(err, PatchParseError::TooLarge { .. }));
+        assert!(err.to_string().contains("exceeds 2 hunks: a.txt"));
+        match &Patch::parse(patch).expect("default limits").ops()[0] {
+            PatchOp::UpdateFile { hunks, .. } => assert_eq!(hunks.len(), 3),
+            other => panic!("expected update, got {other:?}"),
+        }
+    }
+
     #[test]
     fn parse_rejects_empty_path() {
         let err = parse_rel_path("   ").unwrap_err();
@@ -0,0 +1,190 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::patch::{self, Patch, PatchOp};
+use crate::{normalize_rel, Workspace};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PatchApplyResult {
+    pub changed_files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PatchApplyOptions {
+    /// Line endings for content written by add/update ops.
+    pub line_ending: LineEnding,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineEnding {
+    /// Write content exactly as the patch (or hunk result) produced it.
+    #[default]
+    Preserve,
+    Lf,
+    Crlf,
+}
+
+impl LineEnding {
+    fn apply<'a>(self, text: &'a str) -> Cow<'a, str> {
+        match self {
+            LineEnding::Preserve => Cow::Borrowed(text),
+            LineEnding::Lf => Cow::Owned(text.replace("\r\n", "\n")),
+            LineEnding::Crlf => Cow::Owned(text.replace("\r\n", "\n").replace('\n', "\r\n")),
+        }
+    }
+}
+
+impl Workspace {
+    pub fn apply_patch(&self, patch: &str) -> io::Result<PatchApplyResult> {
+        self.apply_patch_with_options(patch, PatchApplyOptions::default())
+    }
+
+    pub fn apply_patch_with_options(
+        &self,
+        patch: &str,
+        options: PatchApplyOptions,
+    ) -> io::Result<PatchApplyResult> {
+        let patch = Patch::parse(patch)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+        self.apply_parsed_patch_with_options(&patch, options)
+    }
+
+    pub fn apply_parsed_patch(&self, patch: &Patch) -> io::Result<PatchApplyResult> {
+        self.apply_parsed_patch_with_options(patch, PatchApplyOptions::default())
+    }
+
+    pub fn apply_parsed_patch_with_options(
+        &self,
+        patch: &Patch,
+        options: PatchApplyOptions,
+    ) -> io::Result<PatchApplyResult> {
+        let mut seen = BTreeSet::new();
+        let mut undo: Vec<(PathBuf, Option<Vec<u8>>)> = Vec::new();
+        let mut changed_files: Vec<String> = Vec::new();
+
+        let mut record_undo = |path: &PathBuf| -> io::Result<()> {
+            if !seen.insert(path.clone()) {
+                return Ok(());
+            }
+            let previous = if path.exists() {
+                Some(fs::read(path)?)
+            } else {
+                None
+            };
+            undo.push((path.clone(), previous));
+            Ok(())
+        };
+
+        let apply_result = (|| -> io::Result<()> {
+            for op in patch.ops() {
+                match op {
+                    PatchOp::AddFile { path, content } => {
+                        let dest = self.safe_join(path)?;
+                        if dest.exists() {
+                            return Err(io::Error::new(
+                                io::ErrorKind::AlreadyExists,
+                                format!("file already exists: {}", path.display()),
+                            ));
+                        }
+                        record_undo(&dest)?;
+                        if let Some(parent) = dest.parent() {
+                            fs::create_dir_all(parent)?;
+                        }
+                        let content = options.line_ending.apply(content);
+                        fs::write(&dest, content.as_bytes())?;
+                        changed_files.push(normalize_rel(path));
+                    }
+                    PatchOp::DeleteFile { path } => {
+                        let dest = self.safe_join(path)?;
+                        if !dest.exists() {
+                            return Err(io::Error::new(
+                                io::ErrorKind::NotFound,
+                                format!("file not found: {}", path.display()),
+                            ));
+                        }
+                        record_undo(&dest)?;
+                        fs::remove_file(&dest)?;
+                        changed_files.push(normalize_rel(path));
+                    }
+                    PatchOp::UpdateFile {
+                        path,
+                        moved_to,
+                        hunks,
+                    } => {
+                        let dest = self.safe_join(path)?;
+                        if !dest.exists() {
+                            return Err(io::Error::new(
+                                io::ErrorKind::NotFound,
+                                format!("file not found: {}", path.display()),
+                            ));
+                        }
+                        record_undo(&dest)?;
+                        let bytes = fs::read(&dest)?;
+                        let original_text = String::from_utf8(bytes).map_err(|err| {
+                            io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                format!("file is not valid UTF-8: {err}"),
+                            )
+                        })?;
+                        let updated = patch::apply_hunks_to_text(&original_text, hunks, path)?;
+                        let updated = options.line_ending.apply(&updated);
+                        fs::write(&dest, updated.as_bytes())?;
+                        changed_files.push(normalize_rel(path));
+
+                        if let Some(moved_to) = moved_to {
+                            let target = self.safe_join(moved_to)?;
+                            if target.exists() {
+                                return Err(io::Error::new(
+                                    io::ErrorKind::AlreadyExists,
+                                    format!("move target already exists: {}", moved_to.display()),
+                                ));
+                            }
+                            record_undo(&target)?;
+                            if let Some(parent) = target.parent() {
+                                fs::create_dir_all(parent)?;
+                            }
+                            fs::rename(&dest, &target)?;
+                            changed_files.push(normalize_rel(moved_to));
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })();
+
+        if let Err(err) = apply_result {
+            let _ = self.revert_paths(undo);
+            return Err(err);
+        }
+
+        changed_files.sort();
+        changed_files.dedup();
+        Ok(PatchApplyResult { changed_files })
+    }
+
+    fn revert_paths(&self, undo: Vec<(PathBuf, Option<Vec<u8>>)>) -> io::Result<()> {
+        for (path, previous) in undo.into_iter().rev() {
+            match previous {
+                Some(bytes) => {
+                    if let Some(parent) = path.parent() {
+                        let _ = fs::create_dir_all(parent);
+                    }
+                    let _ = fs::write(path, bytes);
+                }
+                None => {
+                    let _ = fs::remove_file(path);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
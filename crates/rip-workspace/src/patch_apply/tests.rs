@@ -0,0 +1,192 @@
+use tempfile::tempdir;
+
+use super::*;
+
+#[test]
+fn apply_patch_creates_updates_and_deletes() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+
+    let patch = r#"*** Begin Patch
+*** Add File: a.txt
++one
++two
+*** End Patch"#;
+    let result = workspace.apply_patch(patch).expect("apply");
+    assert!(result.changed_files.contains(&"a.txt".to_string()));
+    assert_eq!(
+        fs::read_to_string(root.join("a.txt")).unwrap(),
+        "one\ntwo\n"
+    );
+
+    let patch = r#"*** Begin Patch
+*** Update File: a.txt
+@@
+-one
++ONE
+ two
+*** End Patch"#;
+    let _ = workspace.apply_patch(patch).expect("apply");
+    assert_eq!(
+        fs::read_to_string(root.join("a.txt")).unwrap(),
+        "ONE\ntwo\n"
+    );
+
+    let patch = r#"*** Begin Patch
+*** Delete File: a.txt
+*** End Patch"#;
+    let _ = workspace.apply_patch(patch).expect("apply");
+    assert!(!root.join("a.txt").exists());
+}
+
+#[test]
+fn inverted_patch_restores_original_content() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    fs::write(root.join("a.txt"), "one\ntwo\nthree\n").expect("write");
+
+    let patch = Patch::parse(
+        r#"*** Begin Patch
+*** Update File: a.txt
+@@
+ one
+-two
++TWO
++two-and-a-half
+ three
+*** Add File: b.txt
++new
+*** End Patch"#,
+    )
+    .expect("parse");
+    workspace.apply_parsed_patch(&patch).expect("apply");
+    assert_eq!(
+        fs::read_to_string(root.join("a.txt")).unwrap(),
+        "one\nTWO\ntwo-and-a-half\nthree\n"
+    );
+
+    let inverse = patch.invert().expect("invert");
+    workspace
+        .apply_parsed_patch(&inverse)
+        .expect("apply inverse");
+    assert_eq!(
+        fs::read_to_string(root.join("a.txt")).unwrap(),
+        "one\ntwo\nthree\n"
+    );
+    assert!(!root.join("b.txt").exists());
+}
+
+#[test]
+fn apply_patch_is_atomic_on_error() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+
+    let patch = r#"*** Begin Patch
+*** Add File: a.txt
++one
+*** Update File: missing.txt
+@@
+-nope
++ok
+*** End Patch"#;
+    let _ = workspace.apply_patch(patch).expect_err("error");
+    assert!(!root.join("a.txt").exists());
+}
+
+#[test]
+fn apply_patch_with_crlf_option_normalizes_added_files() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let options = PatchApplyOptions {
+        line_ending: LineEnding::Crlf,
+    };
+
+    let patch = r#"*** Begin Patch
+*** Add File: a.txt
++one
++two
+*** End Patch"#;
+    workspace
+        .apply_patch_with_options(patch, options)
+        .expect("apply");
+    assert_eq!(fs::read(root.join("a.txt")).unwrap(), b"one\r\ntwo\r\n");
+
+    let patch = r#"*** Begin Patch
+*** Add File: b.txt
++one
+*** End Patch"#;
+    workspace.apply_patch(patch).expect("apply");
+    assert_eq!(fs::read(root.join("b.txt")).unwrap(), b"one\n");
+}
+
+#[test]
+fn apply_patch_rejects_existing_add() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let file = root.join("a.txt");
+    fs::write(&file, b"one").expect("write");
+
+    let patch = r#"*** Begin Patch
+*** Add File: a.txt
++two
+*** End Patch"#;
+    let err = workspace.apply_patch(patch).expect_err("err");
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(fs::read_to_string(&file).unwrap(), "one");
+}
+
+#[test]
+fn apply_patch_rejects_delete_missing_file() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let patch = r#"*** Begin Patch
+*** Delete File: missing.txt
+*** End Patch"#;
+    let err = workspace.apply_patch(patch).expect_err("err");
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn apply_patch_rejects_invalid_utf8() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let file = root.join("a.txt");
+    fs::write(&file, vec![0xff, 0xfe]).expect("write");
+
+    let patch = r#"*** Begin Patch
+*** Update File: a.txt
+@@
+-bad
++good
+*** End Patch"#;
+    let err = workspace.apply_patch(patch).expect_err("err");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn apply_patch_rejects_move_target_exists() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    let file = root.join("a.txt");
+    let target = root.join("b.txt");
+    fs::write(&file, b"one").expect("write");
+    fs::write(&target, b"two").expect("write");
+
+    let patch = r#"*** Begin Patch
+*** Update File: a.txt
+*** Move to: b.txt
+@@
+-one
++one
+*** End Patch"#;
+    let err = workspace.apply_patch(patch).expect_err("err");
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+}
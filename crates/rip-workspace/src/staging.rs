@@ -0,0 +1,53 @@
+use std::collections::BTreeSet;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Checkpoint, Workspace};
+
+impl Workspace {
+    /// Starts accumulating a file set for a later `create_checkpoint`.
+    pub fn begin_staging(&self) -> StagingHandle<'_> {
+        StagingHandle {
+            workspace: self,
+            paths: BTreeSet::new(),
+        }
+    }
+}
+
+/// Paths staged for the next checkpoint; see `Workspace::begin_staging`.
+/// Staging the same file twice (by relative or absolute path) records it once.
+pub struct StagingHandle<'a> {
+    workspace: &'a Workspace,
+    paths: BTreeSet<PathBuf>,
+}
+
+impl StagingHandle<'_> {
+    /// Adds `path` (absolute, or relative to the workspace root). Paths outside
+    /// the workspace are rejected with `InvalidInput`.
+    pub fn stage(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        let rel = self.workspace.to_relative(path.as_ref())?;
+        self.paths.insert(rel);
+        Ok(())
+    }
+
+    pub fn staged_paths(&self) -> impl Iterator<Item = &Path> {
+        self.paths.iter().map(PathBuf::as_path)
+    }
+
+    /// Snapshots every staged path into a new checkpoint.
+    pub fn commit_checkpoint(
+        self,
+        session_id: &str,
+        label: impl Into<String>,
+    ) -> io::Result<Checkpoint> {
+        let files: Vec<PathBuf> = self
+            .paths
+            .iter()
+            .map(|rel| self.workspace.root.join(rel))
+            .collect();
+        self.workspace.create_checkpoint(session_id, label, &files)
+    }
+}
+
+#[cfg(test)]
+mod tests;
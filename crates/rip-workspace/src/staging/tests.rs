@@ -0,0 +1,35 @@
+use std::fs;
+
+use tempfile::tempdir;
+
+use super::*;
+
+#[test]
+fn staged_files_are_deduped_into_one_checkpoint() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path();
+    let workspace = Workspace::new(root).expect("workspace");
+    for name in ["a.txt", "b.txt", "c.txt", "unstaged.txt"] {
+        fs::write(root.join(name), name).expect("write");
+    }
+
+    let mut staging = workspace.begin_staging();
+    staging.stage("a.txt").expect("stage a");
+    staging.stage(root.join("b.txt")).expect("stage b");
+    staging.stage(root.join("a.txt")).expect("stage a again");
+    staging.stage("c.txt").expect("stage c");
+    staging.stage("b.txt").expect("stage b again");
+    let err = staging
+        .stage(root.parent().unwrap().join("outside.txt"))
+        .expect_err("outside");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+    let checkpoint = staging.commit_checkpoint("s1", "staged").expect("commit");
+    let paths: Vec<&str> = checkpoint
+        .files
+        .iter()
+        .map(|file| file.path.as_str())
+        .collect();
+    assert_eq!(paths, ["a.txt", "b.txt", "c.txt"]);
+    assert!(checkpoint.files.iter().all(|file| file.exists));
+}
@@ -0,0 +1,37 @@
+use tempfile::tempdir;
+
+use super::*;
+
+#[test]
+fn safe_join_rejects_absolute_and_parent_paths() {
+    let dir = tempdir().expect("tmp");
+    let workspace = Workspace::new(dir.path()).expect("workspace");
+    let err = workspace.safe_join(Path::new("/abs.txt")).expect_err("err");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+    let err = workspace
+        .safe_join(Path::new("../escape.txt"))
+        .expect_err("err");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn exposes_root_and_checkpoints_dir() {
+    let dir = tempdir().expect("tmp");
+    let workspace = Workspace::new(dir.path()).expect("workspace");
+    assert_eq!(workspace.root(), dir.path());
+    assert_eq!(
+        workspace.checkpoints_dir(),
+        dir.path().join(".rip").join("checkpoints")
+    );
+    assert!(workspace.checkpoints_dir().is_dir());
+
+    let joined = workspace.safe_join(Path::new("src/a.txt")).expect("join");
+    assert!(joined.starts_with(workspace.root()));
+}
+
+#[test]
+fn normalize_rel_converts_backslashes() {
+    let path = Path::new("a\\b");
+    assert_eq!(normalize_rel(path), "a/b");
+}
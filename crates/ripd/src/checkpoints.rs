@@ -2,22 +2,58 @@ use std::path::PathBuf;
 
 use rip_tools::{CheckpointHook, CheckpointRecord, CheckpointRequest, CheckpointRewindRecord};
 use rip_workspace::Workspace;
+use uuid::Uuid;
+
+use crate::local_authority::now_ms;
 
 pub struct WorkspaceCheckpointHook {
     workspace: Workspace,
+    root: PathBuf,
+    dry_run: bool,
 }
 
 impl WorkspaceCheckpointHook {
     pub fn new(root: PathBuf) -> std::io::Result<Self> {
         Ok(Self {
-            workspace: Workspace::new(root)?,
+            workspace: Workspace::new(&root)?,
+            root,
+            dry_run: false,
         })
     }
+
+    /// In dry-run mode `create` records nothing on disk; it returns a synthetic
+    /// record with an empty file set and the intended paths folded into the label.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    fn dry_run_record(&self, request: CheckpointRequest) -> CheckpointRecord {
+        let paths: Vec<String> = request
+            .files
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&self.root)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect();
+        CheckpointRecord {
+            id: format!("dry-run-{}", Uuid::new_v4()),
+            label: format!("{} (dry-run: {})", request.label, paths.join(", ")),
+            created_at_ms: now_ms(),
+            files: Vec::new(),
+        }
+    }
 }
 
 impl CheckpointHook for WorkspaceCheckpointHook {
     #[cfg_attr(test, inline(never))]
     fn create(&self, request: CheckpointRequest) -> Result<CheckpointRecord, String> {
+        if self.dry_run {
+            return Ok(self.dry_run_record(request));
+        }
         let checkpoint = self
             .workspace
             .create_checkpoint(&request.session_id, request.label, &request.files)
@@ -72,8 +108,12 @@ impl CheckpointHook for WorkspaceCheckpointHook {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rip_tools::{CheckpointHook, CheckpointRequest};
+    use rip_kernel::EventKind;
+    use rip_tools::{
+        CheckpointHook, CheckpointRequest, ToolInvocation, ToolOutput, ToolRegistry, ToolRunner,
+    };
     use std::fs;
+    use std::sync::Arc;
     use tempfile::tempdir;
 
     #[test]
@@ -160,4 +200,44 @@ mod tests {
             .expect_err("outside path should fail");
         assert!(err.contains("checkpoint create failed"));
     }
+
+    #[tokio::test]
+    async fn dry_run_emits_checkpoint_without_writing() {
+        let dir = tempdir().expect("tmp");
+        let root = dir.path().to_path_buf();
+        let hook = WorkspaceCheckpointHook::new(root.clone())
+            .expect("hook")
+            .with_dry_run(true);
+        let registry = Arc::new(ToolRegistry::default());
+        registry.register(
+            "write",
+            Arc::new(|_| Box::pin(async { ToolOutput::success(Vec::new()) })),
+        );
+        let runner = ToolRunner::with_checkpoint_hook(registry, 1, Arc::new(hook));
+
+        let mut seq = 0;
+        let events = runner
+            .run(
+                "s1",
+                &mut seq,
+                ToolInvocation {
+                    name: "write".to_string(),
+                    args: serde_json::json!({ "path": "src/a.txt" }),
+                    timeout_ms: None,
+                },
+            )
+            .await;
+
+        match &events[0].kind {
+            EventKind::CheckpointCreated {
+                label, files, auto, ..
+            } => {
+                assert!(*auto);
+                assert!(files.is_empty());
+                assert_eq!(label, "auto:write (dry-run: src/a.txt)");
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+        assert!(!root.join(".rip").join("checkpoints").join("s1").exists());
+    }
 }
@@ -33,6 +33,11 @@ pub struct RipConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub openresponses: Option<OpenResponsesDefaults>,
 
+    /// Caps concurrently running sessions; `RIP_MAX_CONCURRENT_SESSIONS`
+    /// takes precedence. Unset or 0 means unlimited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_sessions: Option<usize>,
+
     #[serde(default, flatten)]
     pub extra: BTreeMap<String, Value>,
 }
@@ -143,6 +148,11 @@ pub struct OpenResponsesDefaults {
     pub web_search: Option<OpenResponsesWebSearchConfig>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reasoning: Option<OpenResponsesReasoningConfig>,
+    /// Tool `type`s the model may be offered; unset offers every tool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_tool_types: Option<Vec<String>>,
+    #[serde(default)]
+    pub reject_disallowed_tool_types: bool,
 }
 
 /// An overlay for OpenResponses defaults where fields are optional.
@@ -205,6 +215,8 @@ pub struct OpenResponsesResolvedConfig {
     pub reasoning: Option<OpenResponsesReasoningConfig>,
     pub reasoning_effort_source: Option<String>,
     pub reasoning_summary_source: Option<String>,
+    pub allowed_tool_types: Option<Vec<String>>,
+    pub reject_disallowed_tool_types: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -714,6 +726,8 @@ pub fn resolve_openresponses_config(
             reasoning,
             reasoning_effort_source,
             reasoning_summary_source,
+            allowed_tool_types: defaults.allowed_tool_types,
+            reject_disallowed_tool_types: defaults.reject_disallowed_tool_types,
         }),
         loaded,
     )
@@ -195,7 +195,7 @@ pub(crate) fn compile_hierarchical_summaries_recent_messages_v1(
         );
     }
 
-    req.summaries.sort_by(|a, b| a.to_seq.cmp(&b.to_seq));
+    req.summaries.sort_by_key(|a| a.to_seq);
     let latest_to_seq = req
         .summaries
         .iter()
@@ -403,7 +403,7 @@ fn is_valid_session_snapshot(events: &[Event], session_id: &str) -> bool {
 fn aggregate_output_text_from_events(events: &[Event]) -> String {
     let mut out = String::new();
     for event in events {
-        if let EventKind::OutputTextDelta { delta } = &event.kind {
+        if let EventKind::OutputTextDelta { delta, .. } = &event.kind {
             out.push_str(delta);
         }
     }
@@ -448,6 +448,8 @@ mod tests {
             seq: 1,
             kind: EventKind::OutputTextDelta {
                 delta: "from_log".to_string(),
+                actor_id: None,
+                origin: None,
             },
         })
         .expect("append");
@@ -480,6 +482,8 @@ mod tests {
                 seq: 1,
                 kind: EventKind::OutputTextDelta {
                     delta: "from_snapshot".to_string(),
+                    actor_id: None,
+                    origin: None,
                 },
             },
             Event {
@@ -579,6 +583,8 @@ mod tests {
             seq: 1,
             kind: EventKind::OutputTextDelta {
                 delta: "from_log".to_string(),
+                actor_id: None,
+                origin: None,
             },
         })
         .expect("append");
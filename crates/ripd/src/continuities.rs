@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -31,6 +31,7 @@ mod compaction_manual;
 mod compaction_status;
 mod compile;
 mod cursor;
+mod cut_rule;
 mod index;
 #[cfg(test)]
 mod tests;
@@ -45,6 +46,7 @@ pub(crate) use self::types::{
     ProviderCursorUpdatedPayload,
 };
 
+pub use self::cut_rule::{CompactionCutRule, MessageMeta};
 pub use self::types::{
     CompactionAutoResultCheckpointV1, CompactionAutoScheduleV1Request,
     CompactionAutoScheduleV1Response, CompactionAutoV1Request, CompactionAutoV1Response,
@@ -71,6 +73,7 @@ pub struct ContinuityStore {
     sender: broadcast::Sender<Event>,
     index: Mutex<ContinuityIndexV1>,
     next_seq: Mutex<HashMap<String, u64>>,
+    cut_rule: Mutex<Option<Arc<dyn CompactionCutRule>>>,
 }
 
 impl ContinuityStore {
@@ -90,6 +93,7 @@ impl ContinuityStore {
             sender,
             index: Mutex::new(index),
             next_seq: Mutex::new(HashMap::new()),
+            cut_rule: Mutex::new(None),
         })
     }
 
@@ -112,6 +116,40 @@ impl ContinuityStore {
         Ok(events)
     }
 
+    /// `ContinuityMessageAppended` events from the 1-based `from_ordinal` on, paired
+    /// with their ordinals. Seeks via the message ordinal index when it is in sync
+    /// and falls back to a full replay otherwise; an unknown continuity is
+    /// `NotFound`.
+    pub fn messages_from_ordinal(
+        &self,
+        continuity_id: &str,
+        from_ordinal: u64,
+    ) -> io::Result<Vec<(u64, Event)>> {
+        let from_ordinal = from_ordinal.max(1);
+        if let Ok(Some(messages)) = self
+            .stream_cache
+            .messages_from_ordinal_messages_runs_v1(continuity_id, from_ordinal)
+        {
+            return Ok((from_ordinal..).zip(messages).collect());
+        }
+
+        let events = self.replay_events(continuity_id)?;
+        if events.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "continuity not found",
+            ));
+        }
+        Ok((1..)
+            .zip(
+                events.into_iter().filter(|event| {
+                    matches!(event.kind, EventKind::ContinuityMessageAppended { .. })
+                }),
+            )
+            .skip_while(|(ordinal, _)| *ordinal < from_ordinal)
+            .collect())
+    }
+
     pub(crate) fn workspace_root(&self) -> &Path {
         &self.workspace_root
     }
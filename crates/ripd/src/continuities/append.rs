@@ -130,6 +130,9 @@ impl ContinuityStore {
                     compiler_strategy: payload.compiler_strategy,
                     from_seq: payload.from_seq,
                     from_message_id: payload.from_message_id,
+                    included_messages: payload.included_messages,
+                    included_tokens: payload.included_tokens,
+                    dropped_messages: payload.dropped_messages,
                     actor_id: payload.actor_id,
                     origin: payload.origin,
                 },
@@ -88,33 +88,13 @@ impl ContinuityStore {
         let execute = req.execute.unwrap_or(true);
         let dry_run = req.dry_run.unwrap_or(false);
 
-        let cut_rule_id = format!("stride_messages_v1/{stride}");
+        let cut_points =
+            self.plan_compaction_cut_points_v1(thread_id, stride, max_new_checkpoints as u64)?;
+        let cut_rule_id = cut_points.cut_rule_id.clone();
         let policy_id = format!(
-            "compaction_auto_schedule_v1/stride_messages_v1/{stride}/max_new_checkpoints_v1/{max_new_checkpoints}/block_on_inflight_v1/{block_on_inflight}"
+            "compaction_auto_schedule_v1/{cut_rule_id}/max_new_checkpoints_v1/{max_new_checkpoints}/block_on_inflight_v1/{block_on_inflight}"
         );
-
-        let cut_points = self.compaction_cut_points_v1(
-            thread_id,
-            CompactionCutPointsV1Request {
-                stride_messages: Some(stride),
-                limit: Some(32),
-            },
-        )?;
-
-        let mut planned: Vec<CompactionPlannedCutPointV1> = Vec::new();
-        for cp in &cut_points.cut_points {
-            if planned.len() as u32 >= max_new_checkpoints {
-                break;
-            }
-            if cp.already_checkpointed {
-                continue;
-            }
-            planned.push(CompactionPlannedCutPointV1 {
-                target_message_ordinal: cp.target_message_ordinal,
-                to_seq: cp.to_seq,
-                to_message_id: cp.to_message_id.clone(),
-            });
-        }
+        let planned = cut_points.planned;
 
         if planned.is_empty() {
             return Ok(CompactionAutoScheduleV1Response {
@@ -306,30 +286,9 @@ impl ContinuityStore {
         }
         let max_new = req.max_new_checkpoints.unwrap_or(1).clamp(1, 32) as u64;
         let dry_run = req.dry_run.unwrap_or(false);
-        let cut_rule_id = format!("stride_messages_v1/{stride}");
-
-        let cut_points = self.compaction_cut_points_v1(
-            thread_id,
-            CompactionCutPointsV1Request {
-                stride_messages: Some(stride),
-                limit: Some(32),
-            },
-        )?;
-
-        let mut planned: Vec<CompactionPlannedCutPointV1> = Vec::new();
-        for cp in &cut_points.cut_points {
-            if planned.len() as u64 >= max_new {
-                break;
-            }
-            if cp.already_checkpointed {
-                continue;
-            }
-            planned.push(CompactionPlannedCutPointV1 {
-                target_message_ordinal: cp.target_message_ordinal,
-                to_seq: cp.to_seq,
-                to_message_id: cp.to_message_id.clone(),
-            });
-        }
+        let cut_points = self.plan_compaction_cut_points_v1(thread_id, stride, max_new)?;
+        let cut_rule_id = cut_points.cut_rule_id.clone();
+        let planned = cut_points.planned;
 
         if planned.is_empty() || dry_run {
             return Ok(CompactionAutoV1Response {
@@ -327,17 +327,13 @@ impl ContinuityStore {
             match &event.kind {
                 EventKind::ContinuityJobEnded {
                     job_id, job_kind, ..
-                } => {
-                    if job_kind == COMPACTION_JOB_KIND_SUMMARIZER_V1 {
-                        ended.insert(job_id.clone());
-                    }
+                } if job_kind == COMPACTION_JOB_KIND_SUMMARIZER_V1 => {
+                    ended.insert(job_id.clone());
                 }
                 EventKind::ContinuityJobSpawned {
                     job_id, job_kind, ..
-                } => {
-                    if job_kind == COMPACTION_JOB_KIND_SUMMARIZER_V1 && !ended.contains(job_id) {
-                        return Some(job_id.clone());
-                    }
+                } if job_kind == COMPACTION_JOB_KIND_SUMMARIZER_V1 && !ended.contains(job_id) => {
+                    return Some(job_id.clone());
                 }
                 _ => {}
             }
@@ -43,10 +43,17 @@ impl ContinuityStore {
                                 .count();
 
                             if tail.complete || message_count >= RECENT_MESSAGES_V1_LIMIT {
+                                let from_seq = from_seq.max(message_seq);
+                                let available_messages = if tail.complete {
+                                    message_count as u64
+                                } else {
+                                    self.message_count_through_seq_v1(continuity_id, from_seq)?
+                                };
                                 return Ok(ContextCompileInput {
                                     continuity_events: tail.events,
-                                    from_seq: from_seq.max(message_seq),
+                                    from_seq,
                                     from_message_id: Some(anchor_message_id.to_string()),
+                                    available_messages,
                                 });
                             }
                         } else if tail.complete {
@@ -73,10 +80,13 @@ impl ContinuityStore {
             anchor_message_id,
             RECENT_MESSAGES_V1_LIMIT,
         ) {
+            let available_messages =
+                self.message_count_through_seq_v1(continuity_id, window.from_seq)?;
             return Ok(ContextCompileInput {
                 continuity_events: window.events,
                 from_seq: window.from_seq,
                 from_message_id: window.from_message_id,
+                available_messages,
             });
         }
 
@@ -89,13 +99,51 @@ impl ContinuityStore {
 
         let (from_seq, from_message_id) =
             resolve_context_compile_cutpoint_full(&continuity_events, anchor_message_id)?;
+        let available_messages = count_messages_through_seq(&continuity_events, from_seq);
         Ok(ContextCompileInput {
             continuity_events,
             from_seq,
             from_message_id,
+            available_messages,
         })
     }
 
+    /// Counts every message in the continuity at or before `seq`, using the
+    /// message ordinal index when it is available and a full replay otherwise.
+    fn message_count_through_seq_v1(&self, continuity_id: &str, seq: u64) -> Result<u64, String> {
+        if let Ok(Some(count)) = self
+            .stream_cache
+            .message_count_messages_runs_v1(continuity_id)
+        {
+            // Ordinals are assigned in seq order, so binary search for the last
+            // ordinal whose message seq is `<= seq`.
+            let (mut lo, mut hi) = (0u64, count);
+            let mut indexed = true;
+            while lo < hi {
+                let mid = lo + (hi - lo).div_ceil(2);
+                match self
+                    .stream_cache
+                    .message_by_ordinal_messages_runs_v1(continuity_id, mid)
+                {
+                    Ok(Some((message_seq, _))) if message_seq <= seq => lo = mid,
+                    Ok(Some(_)) => hi = mid - 1,
+                    _ => {
+                        indexed = false;
+                        break;
+                    }
+                }
+            }
+            if indexed {
+                return Ok(lo);
+            }
+        }
+
+        let events = self
+            .replay_events(continuity_id)
+            .map_err(|err| format!("continuity replay failed: {err}"))?;
+        Ok(count_messages_through_seq(&events, seq))
+    }
+
     pub(crate) fn latest_compaction_checkpoint_for_compile_v1(
         &self,
         continuity_id: &str,
@@ -193,7 +241,7 @@ impl ContinuityStore {
                     to_seq: entry.to_seq,
                 })
                 .collect();
-            out.sort_by(|a, b| a.to_seq.cmp(&b.to_seq));
+            out.sort_by_key(|a| a.to_seq);
             return Ok(out);
         }
 
@@ -240,7 +288,7 @@ impl ContinuityStore {
             .into_values()
             .map(|(_, record)| record)
             .collect();
-        unique.sort_by(|a, b| a.to_seq.cmp(&b.to_seq));
+        unique.sort_by_key(|a| a.to_seq);
 
         let Some(latest) = unique.last().cloned() else {
             return Ok(Vec::new());
@@ -270,7 +318,7 @@ impl ContinuityStore {
             selected.push(candidate.clone());
             current_to_seq = candidate.to_seq;
         }
-        selected.sort_by(|a, b| a.to_seq.cmp(&b.to_seq));
+        selected.sort_by_key(|a| a.to_seq);
         Ok(selected)
     }
 }
@@ -331,3 +379,12 @@ pub(super) fn resolve_context_compile_cutpoint_full(
 
     Ok((from_seq.max(message_seq), Some(message_id.to_string())))
 }
+
+fn count_messages_through_seq(events: &[Event], seq: u64) -> u64 {
+    events
+        .iter()
+        .filter(|event| {
+            event.seq <= seq && matches!(event.kind, EventKind::ContinuityMessageAppended { .. })
+        })
+        .count() as u64
+}
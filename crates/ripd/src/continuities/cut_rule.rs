@@ -0,0 +1,131 @@
+use super::*;
+
+/// Message-level view of a continuity handed to a [`CompactionCutRule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageMeta {
+    /// 1-based position among the continuity's messages.
+    pub ordinal: u64,
+    pub seq: u64,
+    pub message_id: String,
+    pub actor_id: String,
+    pub origin: String,
+}
+
+/// Decides where compaction checkpoints may cut a continuity.
+///
+/// The auto scheduler drops points that are already checkpointed and plans the
+/// rest in the order returned, so rules should list the preferred cuts first.
+pub trait CompactionCutRule: Send + Sync {
+    /// Stable identifier recorded as `cut_rule_id` on planned checkpoints.
+    fn rule_id(&self) -> String;
+
+    fn cut_points(&self, messages: &[MessageMeta]) -> Vec<CompactionPlannedCutPointV1>;
+}
+
+pub(super) struct CompactionPlanV1 {
+    pub(super) message_count: u64,
+    pub(super) cut_rule_id: String,
+    pub(super) planned: Vec<CompactionPlannedCutPointV1>,
+}
+
+impl ContinuityStore {
+    /// Replaces the rule the compaction scheduler uses to pick cut points.
+    /// Without one, the scheduler cuts at every `stride_messages`-th message
+    /// (`stride_messages_v1/<stride>`), read from the message ordinal index.
+    pub fn set_compaction_cut_rule(&self, rule: Arc<dyn CompactionCutRule>) {
+        *self.cut_rule.lock().expect("cut rule lock") = Some(rule);
+    }
+
+    pub(super) fn plan_compaction_cut_points_v1(
+        &self,
+        thread_id: &str,
+        stride: u64,
+        max_new: u64,
+    ) -> Result<CompactionPlanV1, String> {
+        let rule = self.cut_rule.lock().expect("cut rule lock").clone();
+        match rule {
+            Some(rule) => self.plan_with_custom_cut_rule_v1(thread_id, rule.as_ref(), max_new),
+            None => self.plan_with_stride_cut_rule_v1(thread_id, stride, max_new),
+        }
+    }
+
+    fn plan_with_stride_cut_rule_v1(
+        &self,
+        thread_id: &str,
+        stride: u64,
+        max_new: u64,
+    ) -> Result<CompactionPlanV1, String> {
+        let cut_points = self.compaction_cut_points_v1(
+            thread_id,
+            CompactionCutPointsV1Request {
+                stride_messages: Some(stride),
+                limit: Some(32),
+            },
+        )?;
+
+        let planned = cut_points
+            .cut_points
+            .iter()
+            .filter(|cp| !cp.already_checkpointed)
+            .take(max_new as usize)
+            .map(|cp| CompactionPlannedCutPointV1 {
+                target_message_ordinal: cp.target_message_ordinal,
+                to_seq: cp.to_seq,
+                to_message_id: cp.to_message_id.clone(),
+            })
+            .collect();
+
+        Ok(CompactionPlanV1 {
+            message_count: cut_points.message_count,
+            cut_rule_id: cut_points.cut_rule_id,
+            planned,
+        })
+    }
+
+    fn plan_with_custom_cut_rule_v1(
+        &self,
+        thread_id: &str,
+        rule: &dyn CompactionCutRule,
+        max_new: u64,
+    ) -> Result<CompactionPlanV1, String> {
+        let events = self
+            .replay_events(thread_id)
+            .map_err(|err| format!("continuity replay failed: {err}"))?;
+        if events.is_empty() {
+            return Err("thread_not_found".to_string());
+        }
+
+        let mut messages: Vec<MessageMeta> = Vec::new();
+        let mut checkpointed: HashSet<u64> = HashSet::new();
+        for event in &events {
+            match &event.kind {
+                EventKind::ContinuityMessageAppended {
+                    actor_id, origin, ..
+                } => messages.push(MessageMeta {
+                    ordinal: messages.len() as u64 + 1,
+                    seq: event.seq,
+                    message_id: event.id.clone(),
+                    actor_id: actor_id.clone(),
+                    origin: origin.clone(),
+                }),
+                EventKind::ContinuityCompactionCheckpointCreated { to_seq, .. } => {
+                    checkpointed.insert(*to_seq);
+                }
+                _ => {}
+            }
+        }
+
+        let planned = rule
+            .cut_points(&messages)
+            .into_iter()
+            .filter(|cp| !checkpointed.contains(&cp.to_seq))
+            .take(max_new as usize)
+            .collect();
+
+        Ok(CompactionPlanV1 {
+            message_count: messages.len() as u64,
+            cut_rule_id: rule.rule_id(),
+            planned,
+        })
+    }
+}
@@ -43,6 +43,50 @@ fn ensure_default_creates_and_is_idempotent() {
     }
 }
 
+#[test]
+fn messages_from_ordinal_match_with_and_without_sidecars() {
+    let dir = tempdir().expect("tmp");
+    let (_event_log, store, data_dir) = store_for(&dir);
+
+    let thread_id = store.ensure_default().expect("ensure");
+    let ids: Vec<String> = ["one", "two", "three"]
+        .into_iter()
+        .map(|content| {
+            store
+                .append_message(
+                    &thread_id,
+                    "user".to_string(),
+                    "cli".to_string(),
+                    content.to_string(),
+                )
+                .expect("append")
+        })
+        .collect();
+
+    let page = |store: &ContinuityStore| {
+        store
+            .messages_from_ordinal(&thread_id, 2)
+            .expect("messages")
+            .into_iter()
+            .map(|(ordinal, event)| (ordinal, event.id))
+            .collect::<Vec<_>>()
+    };
+    let expected = vec![(2, ids[1].clone()), (3, ids[2].clone())];
+    assert_eq!(page(&store), expected);
+
+    fs::remove_dir_all(data_dir.join("continuity_streams")).expect("remove sidecars");
+    assert_eq!(page(&store), expected);
+
+    assert!(store
+        .messages_from_ordinal(&thread_id, 4)
+        .expect("messages")
+        .is_empty());
+    let missing = store
+        .messages_from_ordinal("missing", 1)
+        .expect_err("missing thread");
+    assert_eq!(missing.kind(), io::ErrorKind::NotFound);
+}
+
 #[test]
 fn provider_cursor_status_survives_sidecar_rotation() {
     let dir = tempdir().expect("tmp");
@@ -404,6 +448,9 @@ fn continuity_sidecar_contains_appended_frames_and_is_preferred_for_replay() {
                 compiler_strategy: "recent_messages_v1".to_string(),
                 from_seq: 1,
                 from_message_id: Some(message_id.clone()),
+                included_messages: None,
+                included_tokens: None,
+                dropped_messages: None,
                 actor_id: "alice".to_string(),
                 origin: "cli".to_string(),
             },
@@ -1019,6 +1066,89 @@ fn compaction_auto_schedule_is_replay_safe_under_concurrent_calls() {
     );
 }
 
+#[test]
+fn compaction_auto_uses_registered_custom_cut_rule() {
+    struct AfterActorCutRule(&'static str);
+
+    impl CompactionCutRule for AfterActorCutRule {
+        fn rule_id(&self) -> String {
+            format!("after_actor_v1/{}", self.0)
+        }
+
+        fn cut_points(&self, messages: &[MessageMeta]) -> Vec<CompactionPlannedCutPointV1> {
+            messages
+                .iter()
+                .filter(|message| message.actor_id == self.0)
+                .map(|message| CompactionPlannedCutPointV1 {
+                    target_message_ordinal: message.ordinal,
+                    to_seq: message.seq,
+                    to_message_id: message.message_id.clone(),
+                })
+                .collect()
+        }
+    }
+
+    let dir = tempdir().expect("tmp");
+    let (_event_log, store, _data_dir) = store_for(&dir);
+    let continuity_id = store.ensure_default().expect("ensure");
+    let mut message_ids = Vec::new();
+    for actor in ["user", "bot", "user", "bot", "user"] {
+        let id = store
+            .append_message(
+                &continuity_id,
+                actor.to_string(),
+                "cli".to_string(),
+                "hi".to_string(),
+            )
+            .expect("append");
+        message_ids.push(id);
+    }
+    store.set_compaction_cut_rule(Arc::new(AfterActorCutRule("bot")));
+
+    let request = CompactionAutoV1Request {
+        stride_messages: None,
+        max_new_checkpoints: Some(8),
+        dry_run: Some(true),
+        actor_id: "alice".to_string(),
+        origin: "test".to_string(),
+    };
+    let response = store
+        .compaction_auto_v1(&continuity_id, request.clone())
+        .expect("auto");
+    assert_eq!(response.status, "noop");
+    assert_eq!(response.cut_rule_id, "after_actor_v1/bot");
+    assert_eq!(response.message_count, 5);
+    let planned: Vec<(u64, &str)> = response
+        .planned
+        .iter()
+        .map(|cp| (cp.target_message_ordinal, cp.to_message_id.as_str()))
+        .collect();
+    assert_eq!(
+        planned,
+        vec![(2, message_ids[1].as_str()), (4, message_ids[3].as_str())]
+    );
+
+    let executed = store
+        .compaction_auto_v1(
+            &continuity_id,
+            CompactionAutoV1Request {
+                max_new_checkpoints: Some(1),
+                dry_run: Some(false),
+                ..request.clone()
+            },
+        )
+        .expect("auto");
+    assert_eq!(executed.status, "completed");
+    assert_eq!(executed.result.len(), 1);
+    assert_eq!(executed.result[0].cut_rule_id, "after_actor_v1/bot");
+
+    let remaining = store
+        .compaction_auto_v1(&continuity_id, request)
+        .expect("auto");
+    assert_eq!(remaining.planned.len(), 1);
+    assert_eq!(remaining.planned[0].target_message_ordinal, 4);
+}
+
 #[test]
 fn compaction_status_v1_reports_next_cut_point_and_latest_checkpoint() {
     let dir = tempdir().expect("tmp");
@@ -1849,6 +1979,8 @@ fn tail_context_compile_input_matches_full_replay_for_recent_messages_v1() {
                 seq: 1,
                 kind: EventKind::OutputTextDelta {
                     delta: format!("a{idx}"),
+                    actor_id: None,
+                    origin: None,
                 },
             },
             Event {
@@ -1882,6 +2014,9 @@ fn tail_context_compile_input_matches_full_replay_for_recent_messages_v1() {
                     compiler_strategy: "recent_messages_v1".to_string(),
                     from_seq: 0,
                     from_message_id: Some(message_id.clone()),
+                    included_messages: None,
+                    included_tokens: None,
+                    dropped_messages: None,
                     actor_id: "user".to_string(),
                     origin: "cli".to_string(),
                 },
@@ -1987,6 +2122,8 @@ fn window_context_compile_input_matches_full_replay_for_recent_messages_v1_non_t
                 seq: 1,
                 kind: EventKind::OutputTextDelta {
                     delta: format!("a{idx}"),
+                    actor_id: None,
+                    origin: None,
                 },
             },
             Event {
@@ -2020,6 +2157,9 @@ fn window_context_compile_input_matches_full_replay_for_recent_messages_v1_non_t
                     compiler_strategy: "recent_messages_v1".to_string(),
                     from_seq: 0,
                     from_message_id: Some(message_id.clone()),
+                    included_messages: None,
+                    included_tokens: None,
+                    dropped_messages: None,
                     actor_id: "user".to_string(),
                     origin: "cli".to_string(),
                 },
@@ -2127,6 +2267,8 @@ fn window_context_compile_input_matches_full_replay_with_dense_tool_side_effects
                 seq: 1,
                 kind: EventKind::OutputTextDelta {
                     delta: format!("a{idx}"),
+                    actor_id: None,
+                    origin: None,
                 },
             },
             Event {
@@ -2276,6 +2418,8 @@ fn window_context_compile_input_matches_full_replay_with_dense_tool_side_effects
                 seq: 1,
                 kind: EventKind::OutputTextDelta {
                     delta: format!("a{idx}"),
+                    actor_id: None,
+                    origin: None,
                 },
             },
             Event {
@@ -2484,6 +2628,8 @@ fn window_context_compile_input_works_when_global_log_is_corrupt() {
                 seq: 1,
                 kind: EventKind::OutputTextDelta {
                     delta: format!("a{idx}"),
+                    actor_id: None,
+                    origin: None,
                 },
             },
             Event {
@@ -2517,6 +2663,9 @@ fn window_context_compile_input_works_when_global_log_is_corrupt() {
                     compiler_strategy: "recent_messages_v1".to_string(),
                     from_seq: 0,
                     from_message_id: Some(message_id.clone()),
+                    included_messages: None,
+                    included_tokens: None,
+                    dropped_messages: None,
                     actor_id: "user".to_string(),
                     origin: "cli".to_string(),
                 },
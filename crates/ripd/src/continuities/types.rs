@@ -26,6 +26,9 @@ pub(crate) struct ContextCompiledPayload {
     pub(crate) compiler_strategy: String,
     pub(crate) from_seq: u64,
     pub(crate) from_message_id: Option<String>,
+    pub(crate) included_messages: Option<u64>,
+    pub(crate) included_tokens: Option<u64>,
+    pub(crate) dropped_messages: Option<u64>,
     pub(crate) actor_id: String,
     pub(crate) origin: String,
 }
@@ -50,6 +53,9 @@ pub(crate) struct ContextCompileInput {
     pub(crate) continuity_events: Vec<Event>,
     pub(crate) from_seq: u64,
     pub(crate) from_message_id: Option<String>,
+    /// Messages in the whole continuity at or before `from_seq`, which may
+    /// exceed those present in `continuity_events`.
+    pub(crate) available_messages: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -81,6 +81,70 @@ impl ContinuityStreamCache {
         Ok(Some((record.seq, message_id)))
     }
 
+    /// `ContinuityMessageAppended` events from the 1-based `from_ordinal` on, read
+    /// from that message's offset in the messages+runs sidecar.
+    ///
+    /// Returns `Ok(None)` when the ordinal index or sidecar doesn't exist, so callers
+    /// can fall back to the truth log.
+    pub(crate) fn messages_from_ordinal_messages_runs_v1(
+        &self,
+        continuity_id: &str,
+        from_ordinal: u64,
+    ) -> io::Result<Option<Vec<Event>>> {
+        let Some(count) = self.message_count_messages_runs_v1(continuity_id)? else {
+            return Ok(None);
+        };
+        let from_ordinal = from_ordinal.max(1);
+        if from_ordinal > count {
+            return Ok(Some(Vec::new()));
+        }
+        let Some((_, message_id)) =
+            self.message_by_ordinal_messages_runs_v1(continuity_id, from_ordinal)?
+        else {
+            return Ok(None);
+        };
+        let Some(sidecar_path) = self.ensure_messages_runs_sidecar_best_effort_v1(continuity_id)?
+        else {
+            return Ok(None);
+        };
+        let Some((anchor_seq, anchor_offset)) =
+            self.lookup_message_anchor_messages_runs_v1(continuity_id, &sidecar_path, &message_id)?
+        else {
+            return Ok(None);
+        };
+
+        let mut file = File::open(&sidecar_path)?;
+        file.seek(SeekFrom::Start(anchor_offset))?;
+        let mut messages = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let event: Event = serde_json::from_str(&line)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            if event.stream_kind() != StreamKind::Continuity || event.stream_id() != continuity_id {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "continuity mr sidecar contains non-continuity event",
+                ));
+            }
+            if matches!(event.kind, EventKind::ContinuityMessageAppended { .. }) {
+                messages.push(event);
+            }
+        }
+
+        if messages.first().map(|event| event.seq) != Some(anchor_seq)
+            || messages.len() as u64 != count - from_ordinal + 1
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "message ordinal index out of sync with messages+runs sidecar",
+            ));
+        }
+        Ok(Some(messages))
+    }
+
     /// Returns `Ok(None)` when the cache file doesn't exist.
     ///
     /// Any validation/parsing error is surfaced via `Err` so callers can fall back to the truth log.
@@ -341,7 +405,7 @@ impl ContinuityStreamCache {
             current_to_seq = candidate.to_seq;
         }
 
-        selected.sort_by(|a, b| a.to_seq.cmp(&b.to_seq));
+        selected.sort_by_key(|a| a.to_seq);
         Ok(Some(selected))
     }
 
@@ -226,6 +226,23 @@ fn rebuild_best_effort_supports_replay_message_counts_and_windows() {
         .expect("ordinal")
         .is_none());
 
+    let from_second = cache
+        .messages_from_ordinal_messages_runs_v1(cid, 2)
+        .expect("messages")
+        .expect("present");
+    assert_eq!(
+        from_second
+            .iter()
+            .map(|event| event.seq)
+            .collect::<Vec<_>>(),
+        vec![4, 6]
+    );
+    assert!(cache
+        .messages_from_ordinal_messages_runs_v1(cid, 4)
+        .expect("messages")
+        .expect("present")
+        .is_empty());
+
     let tail = cache
         .scan_tail_messages_runs_v1(cid, 10, 64 * 1024)
         .expect("tail")
@@ -28,11 +28,12 @@ pub use continuities::{
     CompactionAutoResultCheckpointV1, CompactionAutoScheduleV1Request,
     CompactionAutoScheduleV1Response, CompactionAutoV1Request, CompactionAutoV1Response,
     CompactionCheckpointCumulativeV1Request, CompactionCutPointV1, CompactionCutPointsV1Request,
-    CompactionCutPointsV1Response, CompactionPlannedCutPointV1, CompactionStatusV1Request,
-    CompactionStatusV1Response, ContextSelectionStatusDecisionV1, ContextSelectionStatusV1Request,
-    ContextSelectionStatusV1Response, ContinuityMeta, ContinuityRunLink, ContinuityStore,
-    ProviderCursorRotateV1Request, ProviderCursorRotateV1Response, ProviderCursorStatusCursorV1,
-    ProviderCursorStatusV1Request, ProviderCursorStatusV1Response, ToolSideEffects,
+    CompactionCutPointsV1Response, CompactionCutRule, CompactionPlannedCutPointV1,
+    CompactionStatusV1Request, CompactionStatusV1Response, ContextSelectionStatusDecisionV1,
+    ContextSelectionStatusV1Request, ContextSelectionStatusV1Response, ContinuityMeta,
+    ContinuityRunLink, ContinuityStore, MessageMeta, ProviderCursorRotateV1Request,
+    ProviderCursorRotateV1Response, ProviderCursorStatusCursorV1, ProviderCursorStatusV1Request,
+    ProviderCursorStatusV1Response, ToolSideEffects,
 };
 pub use local_authority::{
     authority_dir, authority_lock_path, authority_meta_path, pid_liveness,
@@ -52,7 +53,7 @@ pub use provider_openresponses::{
     OpenResponsesInclude, OpenResponsesReasoningConfig, OpenResponsesWebSearchConfig,
     OpenResponsesWebSearchOverride, ReasoningEffort, ReasoningSummary, SearchContextSize,
 };
-pub use runner::{SessionEngine, SessionHandle};
+pub use runner::{SessionEndReason, SessionEngine, SessionHandle, SessionStatus};
 
 #[cfg(not(test))]
 pub async fn serve_default() {
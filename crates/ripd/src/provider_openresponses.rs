@@ -287,6 +287,32 @@ pub struct OpenResponsesConfig {
     pub followup_user_message: Option<String>,
     pub stateless_history: bool,
     pub parallel_tool_calls: bool,
+    /// When set, only tools whose `type` is listed are offered to the model.
+    pub allowed_tool_types: Option<Vec<String>>,
+    /// Fail the run instead of dropping tools excluded by `allowed_tool_types`.
+    pub reject_disallowed_tool_types: bool,
+}
+
+/// No endpoint, `tool_choice: auto`, and every optional control unset.
+impl Default for OpenResponsesConfig {
+    fn default() -> Self {
+        Self {
+            provider_id: None,
+            endpoint: String::new(),
+            api_key: None,
+            model: None,
+            headers: Vec::new(),
+            tool_choice: ToolChoiceParam::auto(),
+            include: Vec::new(),
+            web_search: None,
+            reasoning: None,
+            followup_user_message: None,
+            stateless_history: false,
+            parallel_tool_calls: false,
+            allowed_tool_types: None,
+            reject_disallowed_tool_types: false,
+        }
+    }
 }
 
 impl OpenResponsesConfig {
@@ -342,6 +368,8 @@ impl OpenResponsesConfig {
             followup_user_message,
             stateless_history,
             parallel_tool_calls,
+            allowed_tool_types: None,
+            reject_disallowed_tool_types: false,
         })
     }
 }
@@ -438,6 +466,14 @@ fn base_streaming_builder(config: &OpenResponsesConfig) -> CreateResponseBuilder
 }
 
 fn tools_for_request(config: &OpenResponsesConfig) -> Vec<Value> {
+    filter_tools_by_type(
+        candidate_tools(config),
+        config.allowed_tool_types.as_deref(),
+    )
+    .0
+}
+
+fn candidate_tools(config: &OpenResponsesConfig) -> Vec<Value> {
     let mut tools = builtin_function_tools();
     if let Some(web_search) = effective_web_search_tool(config) {
         tools.push(web_search_tool_value(&web_search));
@@ -445,6 +481,42 @@ fn tools_for_request(config: &OpenResponsesConfig) -> Vec<Value> {
     tools
 }
 
+/// Tool types the request would offer but `allowed_tool_types` excludes
+/// (deduplicated, in offer order).
+pub fn disallowed_tool_types(config: &OpenResponsesConfig) -> Vec<String> {
+    filter_tools_by_type(
+        candidate_tools(config),
+        config.allowed_tool_types.as_deref(),
+    )
+    .1
+}
+
+/// Keeps tools whose `type` is in `allowed` (all of them when `allowed` is
+/// `None`) and returns the removed types alongside.
+pub fn filter_tools_by_type(
+    tools: Vec<Value>,
+    allowed: Option<&[String]>,
+) -> (Vec<Value>, Vec<String>) {
+    let Some(allowed) = allowed else {
+        return (tools, Vec::new());
+    };
+    let mut removed: Vec<String> = Vec::new();
+    let kept = tools
+        .into_iter()
+        .filter(|tool| {
+            let tool_type = tool.get("type").and_then(|v| v.as_str()).unwrap_or("");
+            if allowed.iter().any(|allowed| allowed == tool_type) {
+                return true;
+            }
+            if !removed.iter().any(|seen| seen == tool_type) {
+                removed.push(tool_type.to_string());
+            }
+            false
+        })
+        .collect();
+    (kept, removed)
+}
+
 fn effective_include(config: &OpenResponsesConfig) -> Vec<OpenResponsesInclude> {
     crate::openresponses_compat::resolve_openresponses_compat_profile(
         config.provider_id.as_deref(),
@@ -914,6 +986,7 @@ mod tests {
             followup_user_message,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         }
     }
 
@@ -1084,6 +1157,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: true,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "hi");
         let body = payload.body();
@@ -1117,6 +1191,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "hi");
         assert_eq!(
@@ -1140,6 +1215,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "hi");
         assert_eq!(
@@ -1214,6 +1290,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "hi");
         let body = payload.body();
@@ -1249,6 +1326,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "hi");
         let body = payload.body();
@@ -1282,6 +1360,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: true,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "hi");
         let body = payload.body();
@@ -1322,6 +1401,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "what happened today?");
         let tools = payload
@@ -1376,6 +1456,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "plain run");
         let tools = payload
@@ -1403,6 +1484,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "plain run");
         let tools = payload
@@ -1430,6 +1512,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "search");
         let web_search = payload
@@ -1473,6 +1556,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "search");
         let location = payload
@@ -1528,6 +1612,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "plain run");
         let tools = payload
@@ -1565,6 +1650,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: true,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "what happened today?");
         assert!(
@@ -1606,6 +1692,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn allowed_tool_types_strips_disallowed_tools() {
+        let tools = vec![
+            json!({ "type": "function", "name": "read" }),
+            json!({ "type": "shell" }),
+            json!({ "type": "web_search" }),
+        ];
+        let allowed = vec!["function".to_string(), "web_search".to_string()];
+        let (kept, removed) = filter_tools_by_type(tools.clone(), Some(&allowed));
+        assert_eq!(removed, vec!["shell".to_string()]);
+        assert!(kept
+            .iter()
+            .all(|tool| tool.get("type").and_then(|v| v.as_str()) != Some("shell")));
+        assert_eq!(kept.len(), 2);
+        assert_eq!(filter_tools_by_type(tools, None).0.len(), 3);
+
+        let mut config = config_with_followup(None);
+        config.allowed_tool_types = Some(vec!["web_search".to_string()]);
+        assert_eq!(disallowed_tool_types(&config), vec!["function".to_string()]);
+        let payload = build_streaming_request(&config, "hi");
+        let tools = payload
+            .body()
+            .get("tools")
+            .and_then(|v| v.as_array())
+            .expect("tools");
+        assert!(tools.is_empty());
+    }
+
     #[test]
     fn builtin_function_tools_are_strict_false() {
         let tools = builtin_function_tools();
@@ -1748,6 +1862,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "search");
         let location = payload
@@ -1838,6 +1953,7 @@ mod tests {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         };
         let payload = build_streaming_request(&config, "hi");
         assert_eq!(
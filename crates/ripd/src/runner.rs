@@ -1,12 +1,14 @@
 use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use rip_kernel::{Event, EventKind, Runtime};
 use rip_log::{write_snapshot, EventLog};
+use rip_provider_openresponses::ItemParam;
 use rip_tools::{register_builtin_tools, BuiltinToolConfig, ToolRegistry, ToolRunner};
-use tokio::sync::{broadcast, watch, Mutex};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, watch, Mutex, OwnedSemaphorePermit, Semaphore};
 use uuid::Uuid;
 
 use crate::checkpoints::WorkspaceCheckpointHook;
@@ -19,12 +21,34 @@ use crate::workspace_lock::WorkspaceLock;
 const EVENT_CHANNEL_CAPACITY: usize = 16_384;
 const TOOL_MAX_CONCURRENCY: usize = 4;
 
+/// Lifecycle of a session run as seen by the engine. Sessions wait in `Queued`
+/// until a concurrency slot frees (see `max_concurrent_sessions`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionStatus {
+    Queued,
+    Running,
+    Ended,
+}
+
+/// Why [`SessionEngine::run_to_completion`] produced no output text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionEndReason {
+    Cancelled,
+    /// Any other non-`completed` `session_ended` reason (or `unknown` if the
+    /// session never emitted one).
+    Failed(String),
+}
+
 #[derive(Clone)]
 pub struct SessionHandle {
     pub session_id: String,
     sender: broadcast::Sender<Event>,
     events: Arc<Mutex<Vec<Event>>>,
     cancel_tx: watch::Sender<bool>,
+    status_tx: watch::Sender<SessionStatus>,
+    run_link: Arc<OnceLock<ContinuityRunLink>>,
+    started_at_ms: Arc<OnceLock<u64>>,
 }
 
 impl SessionHandle {
@@ -32,6 +56,25 @@ impl SessionHandle {
         self.sender.subscribe()
     }
 
+    pub fn status(&self) -> SessionStatus {
+        *self.status_tx.borrow()
+    }
+
+    pub fn watch_status(&self) -> watch::Receiver<SessionStatus> {
+        self.status_tx.subscribe()
+    }
+
+    /// The continuity/message this session runs for, once spawned with one.
+    pub fn run_link(&self) -> Option<&ContinuityRunLink> {
+        self.run_link.get()
+    }
+
+    /// When the session left the queue and started running; `None` while it
+    /// is still queued (or was cancelled before it ran).
+    pub fn started_at_ms(&self) -> Option<u64> {
+        self.started_at_ms.get().copied()
+    }
+
     pub(crate) async fn events_snapshot(&self) -> Vec<Event> {
         self.events.lock().await.clone()
     }
@@ -51,6 +94,9 @@ pub struct SessionEngine {
     task_engine: Arc<TaskEngine>,
     continuity_store: Arc<ContinuityStore>,
     workspace_lock: Arc<WorkspaceLock>,
+    session_slots: Option<SessionSlots>,
+    preamble: Vec<ItemParam>,
+    stream_resume_retries: u32,
 }
 
 impl SessionEngine {
@@ -68,12 +114,17 @@ impl SessionEngine {
         register_builtin_tools(&registry, builtin_config.clone());
 
         let checkpoint_hook = WorkspaceCheckpointHook::new(workspace_root.clone())
-            .map_err(|err| format!("workspace checkpoint hook init failed: {err}"))?;
-        let tool_runner = Arc::new(ToolRunner::with_checkpoint_hook(
-            registry,
-            TOOL_MAX_CONCURRENCY,
-            Arc::new(checkpoint_hook),
-        ));
+            .map_err(|err| format!("workspace checkpoint hook init failed: {err}"))?
+            .with_dry_run(checkpoint_dry_run_from_env());
+        let runtime = Arc::new(Runtime::new());
+        let tool_runner = Arc::new(
+            ToolRunner::with_checkpoint_hook(
+                registry,
+                TOOL_MAX_CONCURRENCY,
+                Arc::new(checkpoint_hook),
+            )
+            .with_hooks(runtime.hooks()),
+        );
 
         let event_log = Arc::new(
             EventLog::new(data_dir.join("events.jsonl"))
@@ -98,7 +149,7 @@ impl SessionEngine {
         )?);
 
         Ok(Self {
-            runtime: Arc::new(Runtime::new()),
+            runtime,
             tool_runner,
             http_client: reqwest::Client::new(),
             openresponses,
@@ -107,12 +158,51 @@ impl SessionEngine {
             task_engine,
             continuity_store,
             workspace_lock,
+            session_slots: max_concurrent_sessions_from_env()
+                .or_else(|| {
+                    crate::config::load_effective_config(&workspace_root)
+                        .config
+                        .max_concurrent_sessions
+                        .filter(|limit| *limit > 0)
+                })
+                .map(SessionSlots::new),
+            preamble: Vec::new(),
+            stream_resume_retries: stream_resume_retries_from_env(),
         })
     }
 
+    /// Caps how many sessions run at once; further spawns stay `Queued` until a
+    /// running session ends. A limit of 0 is treated as 1.
+    pub fn with_max_concurrent_sessions(mut self, limit: usize) -> Self {
+        self.session_slots = Some(SessionSlots::new(limit));
+        self
+    }
+
+    /// Prepends `items` to the input of every provider run spawned by this
+    /// engine. Each item must be a valid `system` or `developer` message.
+    pub fn with_preamble(mut self, items: Vec<ItemParam>) -> Result<Self, String> {
+        for (index, item) in items.iter().enumerate() {
+            validate_preamble_item(item).map_err(|err| format!("preamble[{index}]: {err}"))?;
+        }
+        self.preamble = items;
+        Ok(self)
+    }
+
+    /// How many times a provider stream that drops before its terminal event is
+    /// resumed from the last seen `sequence_number`. Defaults to 0 (no resume).
+    pub fn with_stream_resume_retries(mut self, retries: u32) -> Self {
+        self.stream_resume_retries = retries;
+        self
+    }
+
     pub fn new_default() -> Result<Self, String> {
+        Self::new_default_with_workspace_root(default_workspace_root())
+    }
+
+    /// Like [`SessionEngine::new_default`], with `workspace_root` in place of
+    /// `RIP_WORKSPACE_ROOT`.
+    pub fn new_default_with_workspace_root(workspace_root: PathBuf) -> Result<Self, String> {
         let data_dir = default_data_dir();
-        let workspace_root = default_workspace_root();
         let openresponses = openresponses_from_env();
         Self::new(data_dir, workspace_root, openresponses)
     }
@@ -121,11 +211,15 @@ impl SessionEngine {
         let session_id = Uuid::new_v4().to_string();
         let (sender, _receiver) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
         let (cancel_tx, _cancel_rx) = watch::channel(false);
+        let (status_tx, _status_rx) = watch::channel(SessionStatus::Queued);
         SessionHandle {
             session_id,
             sender,
             events: Arc::new(Mutex::new(Vec::new())),
             cancel_tx,
+            status_tx,
+            run_link: Arc::new(OnceLock::new()),
+            started_at_ms: Arc::new(OnceLock::new()),
         }
     }
 
@@ -139,8 +233,32 @@ impl SessionEngine {
         input: String,
         continuity: Option<ContinuityRunLink>,
         openresponses_override: Option<OpenResponsesConfig>,
+    ) {
+        self.spawn_session_with_request_dump(
+            handle,
+            input,
+            continuity,
+            openresponses_override,
+            false,
+        );
+    }
+
+    /// Like [`SessionEngine::spawn_session`], but with `request_dump` set every
+    /// provider request body is stored as a workspace artifact and announced
+    /// by an `openresponses_request` frame, regardless of
+    /// `RIP_OPENRESPONSES_DUMP_REQUEST`.
+    pub fn spawn_session_with_request_dump(
+        &self,
+        handle: SessionHandle,
+        input: String,
+        continuity: Option<ContinuityRunLink>,
+        openresponses_override: Option<OpenResponsesConfig>,
+        request_dump: bool,
     ) {
         let openresponses = openresponses_override.or_else(|| self.openresponses.clone());
+        if let Some(link) = continuity.as_ref() {
+            let _ = handle.run_link.set(link.clone());
+        }
         let runtime = self.runtime.clone();
         let tool_runner = self.tool_runner.clone();
         let workspace_lock = self.workspace_lock.clone();
@@ -152,8 +270,31 @@ impl SessionEngine {
         let continuities = self.continuity_store.clone();
         let continuities_for_cancel = continuities.clone();
         let continuity_for_cleanup = continuity.clone();
+        let session_slots = self.session_slots.clone();
+        let preamble = self.preamble.clone();
+        let stream_resume_retries = self.stream_resume_retries;
         let mut cancel_rx = handle.cancel_tx.subscribe();
         tokio::spawn(async move {
+            let context = SessionContext {
+                runtime,
+                tool_runner,
+                workspace_lock,
+                http_client,
+                openresponses,
+                sender: handle.sender.clone(),
+                events: handle.events.clone(),
+                event_log,
+                snapshot_dir,
+                continuities,
+                continuity_run: continuity,
+                server_session_id: handle.session_id.clone(),
+                preamble,
+                stream_resume_retries,
+                request_dump,
+                input,
+            };
+            let status_tx = handle.status_tx.clone();
+            let started_at_ms = handle.started_at_ms.clone();
             tokio::select! {
                 _ = cancel_rx.changed() => {
                     finalize_cancelled_session(
@@ -164,25 +305,55 @@ impl SessionEngine {
                         continuity_for_cleanup.as_ref(),
                     ).await;
                 }
-                _ = run_session(SessionContext {
-                    runtime,
-                    tool_runner,
-                    workspace_lock,
-                    http_client,
-                    openresponses,
-                    sender: handle.sender.clone(),
-                    events: handle.events.clone(),
-                    event_log,
-                    snapshot_dir,
-                    continuities,
-                    continuity_run: continuity,
-                    server_session_id: handle.session_id.clone(),
-                    input,
-                }) => {}
+                _ = async move {
+                    let _permit = match session_slots {
+                        Some(slots) => Some(slots.acquire(&context).await),
+                        None => None,
+                    };
+                    started_at_ms.get_or_init(now_ms);
+                    status_tx.send_replace(SessionStatus::Running);
+                    run_session(context).await;
+                } => {}
             }
+            handle.status_tx.send_replace(SessionStatus::Ended);
         });
     }
 
+    /// Runs `prompt` in a fresh session and resolves to the concatenated
+    /// `output_text_delta` text once it ends with `completed`.
+    pub async fn run_to_completion(
+        &self,
+        prompt: impl Into<String>,
+    ) -> Result<String, SessionEndReason> {
+        let handle = self.create_session();
+        let mut status_rx = handle.watch_status();
+        self.spawn_session(handle.clone(), prompt.into(), None, None);
+        let _ = status_rx
+            .wait_for(|status| *status == SessionStatus::Ended)
+            .await;
+
+        let events = handle.events_snapshot().await;
+        let reason = events
+            .iter()
+            .rev()
+            .find_map(|event| match &event.kind {
+                EventKind::SessionEnded { reason } => Some(reason.as_str()),
+                _ => None,
+            })
+            .unwrap_or("unknown");
+        match reason {
+            "completed" => Ok(events
+                .iter()
+                .filter_map(|event| match &event.kind {
+                    EventKind::OutputTextDelta { delta, .. } => Some(delta.as_str()),
+                    _ => None,
+                })
+                .collect()),
+            "cancelled" => Err(SessionEndReason::Cancelled),
+            other => Err(SessionEndReason::Failed(other.to_string())),
+        }
+    }
+
     pub fn cancel_session(sessions: &mut HashMap<String, SessionHandle>, session_id: &str) -> bool {
         match sessions.remove(session_id) {
             Some(handle) => {
@@ -202,6 +373,48 @@ impl SessionEngine {
     }
 }
 
+/// Concurrency limit shared by every session an engine spawns.
+#[derive(Clone)]
+struct SessionSlots {
+    limit: usize,
+    semaphore: Arc<Semaphore>,
+}
+
+impl SessionSlots {
+    fn new(limit: usize) -> Self {
+        let limit = limit.max(1);
+        Self {
+            limit,
+            semaphore: Arc::new(Semaphore::new(limit)),
+        }
+    }
+
+    /// Takes a slot, first emitting `session_queued` at seq 0 when none is
+    /// free so clients can tell a waiting session from a stalled one.
+    async fn acquire(&self, context: &SessionContext) -> OwnedSemaphorePermit {
+        if let Ok(permit) = self.semaphore.clone().try_acquire_owned() {
+            return permit;
+        }
+        let event = Event {
+            id: Uuid::new_v4().to_string(),
+            session_id: context.server_session_id.clone(),
+            timestamp_ms: now_ms(),
+            seq: 0,
+            kind: EventKind::SessionQueued {
+                max_concurrent_sessions: self.limit as u64,
+            },
+        };
+        let _ = context.sender.send(event.clone());
+        context.events.lock().await.push(event.clone());
+        let _ = context.event_log.append(&event);
+        self.semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("session slots")
+    }
+}
+
 fn default_data_dir() -> PathBuf {
     if let Ok(value) = std::env::var("RIP_DATA_DIR") {
         return PathBuf::from(value);
@@ -216,6 +429,44 @@ fn default_workspace_root() -> PathBuf {
     std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
 }
 
+fn max_concurrent_sessions_from_env() -> Option<usize> {
+    std::env::var("RIP_MAX_CONCURRENT_SESSIONS")
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|limit| *limit > 0)
+}
+
+fn stream_resume_retries_from_env() -> u32 {
+    std::env::var("RIP_OPENRESPONSES_STREAM_RESUME_RETRIES")
+        .ok()
+        .and_then(|value| value.trim().parse::<u32>().ok())
+        .unwrap_or(0)
+}
+
+fn validate_preamble_item(item: &ItemParam) -> Result<(), String> {
+    if !item.errors().is_empty() {
+        return Err(item.errors().join("; "));
+    }
+    let value = item.value();
+    if value.get("type").and_then(|value| value.as_str()) != Some("message") {
+        return Err("item must be a message".to_string());
+    }
+    match value.get("role").and_then(|value| value.as_str()) {
+        Some("system" | "developer") => Ok(()),
+        _ => Err("message role must be system or developer".to_string()),
+    }
+}
+
+fn checkpoint_dry_run_from_env() -> bool {
+    let Ok(value) = std::env::var("RIP_CHECKPOINT_DRY_RUN") else {
+        return false;
+    };
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
 fn openresponses_from_env() -> Option<OpenResponsesConfig> {
     #[cfg(not(test))]
     {
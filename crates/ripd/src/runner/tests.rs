@@ -102,6 +102,7 @@ async fn cancel_session_emits_cancelled_terminal_event_and_snapshot() {
             followup_user_message: None,
             stateless_history: true,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         }),
     )
     .expect("engine");
@@ -139,6 +140,60 @@ async fn cancel_session_emits_cancelled_terminal_event_and_snapshot() {
     verify_snapshot(&log, snapshot_path).expect("snapshot");
 }
 
+#[tokio::test]
+async fn cancel_session_aborts_in_flight_provider_stream() {
+    use rip_provider_openresponses::ToolChoiceParam;
+
+    let addr = spawn_stalled_provider().await;
+
+    let dir = tempdir().expect("tmp");
+    let workspace_dir = dir.path().join("workspace");
+    std::fs::create_dir_all(&workspace_dir).expect("workspace");
+    let engine = SessionEngine::new(
+        dir.path().join("data"),
+        workspace_dir,
+        Some(OpenResponsesConfig {
+            provider_id: Some("openrouter".to_string()),
+            endpoint: format!("http://{addr}/v1/responses"),
+            api_key: None,
+            model: Some("fixture-model".to_string()),
+            headers: Vec::new(),
+            tool_choice: ToolChoiceParam::auto(),
+            include: Vec::new(),
+            reasoning: None,
+            web_search: None,
+            followup_user_message: None,
+            stateless_history: true,
+            parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
+        }),
+    )
+    .expect("engine");
+
+    let handle = engine.create_session();
+    let session_id = handle.session_id.clone();
+    let mut receiver = handle.subscribe();
+    let mut sessions = HashMap::new();
+    sessions.insert(session_id.clone(), handle.clone());
+    engine.spawn_session(handle.clone(), "hello".to_string(), None, None);
+
+    let _ = wait_for_event(&mut receiver, |kind| {
+        matches!(kind, EventKind::OpenResponsesResponseFirstByte { .. })
+    })
+    .await;
+    assert!(SessionEngine::cancel_session(&mut sessions, &session_id));
+
+    let ended = wait_for_event(&mut receiver, |kind| {
+        matches!(kind, EventKind::SessionEnded { .. })
+    })
+    .await;
+    assert!(
+        matches!(&ended.kind, EventKind::SessionEnded { reason } if reason == "cancelled"),
+        "unexpected end: {:?}",
+        ended.kind
+    );
+}
+
 #[tokio::test]
 async fn cancelled_session_appends_cancelled_continuity_run_end_once() {
     use axum::routing::post;
@@ -181,6 +236,7 @@ async fn cancelled_session_appends_cancelled_continuity_run_end_once() {
             followup_user_message: None,
             stateless_history: true,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         }),
     )
     .expect("engine");
@@ -306,6 +362,360 @@ async fn engine_emits_session_lifecycle_events() {
     assert!(saw_ended);
 }
 
+#[tokio::test]
+async fn max_concurrent_sessions_queues_until_slot_frees() {
+    let addr = spawn_stalled_provider().await;
+    let dir = tempdir().expect("tmp");
+    let workspace_dir = dir.path().join("workspace");
+    std::fs::create_dir_all(&workspace_dir).expect("workspace");
+    let engine = SessionEngine::new(
+        dir.path().join("data"),
+        workspace_dir,
+        Some(OpenResponsesConfig {
+            endpoint: format!("http://{addr}/v1/responses"),
+            model: Some("fixture-model".to_string()),
+            stateless_history: true,
+            ..OpenResponsesConfig::default()
+        }),
+    )
+    .expect("engine")
+    .with_max_concurrent_sessions(1);
+
+    let handle_one = engine.create_session();
+    let mut rx_one = handle_one.subscribe();
+    let handle_two = engine.create_session();
+    let mut rx_two = handle_two.subscribe();
+    let mut sessions = HashMap::new();
+    sessions.insert(handle_one.session_id.clone(), handle_one.clone());
+    sessions.insert(handle_two.session_id.clone(), handle_two.clone());
+
+    engine.spawn_session(handle_one.clone(), "one".to_string(), None, None);
+    let _ = wait_for_event(&mut rx_one, |kind| {
+        matches!(kind, EventKind::OpenResponsesResponseFirstByte { .. })
+    })
+    .await;
+    assert_eq!(handle_one.status(), SessionStatus::Running);
+
+    engine.spawn_session(handle_two.clone(), "two".to_string(), None, None);
+    let queued = wait_for_event(&mut rx_two, |kind| {
+        matches!(kind, EventKind::SessionQueued { .. })
+    })
+    .await;
+    assert_eq!(queued.seq, 0);
+    assert!(matches!(
+        queued.kind,
+        EventKind::SessionQueued {
+            max_concurrent_sessions: 1
+        }
+    ));
+    assert_eq!(handle_two.status(), SessionStatus::Queued);
+
+    assert!(SessionEngine::cancel_session(
+        &mut sessions,
+        &handle_one.session_id
+    ));
+    let started = wait_for_event(&mut rx_two, |kind| {
+        matches!(kind, EventKind::SessionStarted { .. })
+    })
+    .await;
+    assert_eq!(started.seq, 1);
+    assert_eq!(handle_two.status(), SessionStatus::Running);
+
+    assert!(SessionEngine::cancel_session(
+        &mut sessions,
+        &handle_two.session_id
+    ));
+    let mut status_two = handle_two.watch_status();
+    timeout(
+        Duration::from_secs(2),
+        status_two.wait_for(|status| *status == SessionStatus::Ended),
+    )
+    .await
+    .expect("timeout")
+    .expect("status");
+}
+
+#[test]
+fn max_concurrent_sessions_is_read_from_config() {
+    let dir = tempdir().expect("tmp");
+    let workspace_dir = dir.path().join("workspace");
+    std::fs::create_dir_all(workspace_dir.join(".git")).expect("workspace");
+    std::fs::write(
+        workspace_dir.join("rip.json"),
+        r#"{"max_concurrent_sessions":3}"#,
+    )
+    .expect("config");
+
+    let engine = SessionEngine::new(dir.path().join("data"), workspace_dir, None).expect("engine");
+    assert_eq!(engine.session_slots.map(|slots| slots.limit), Some(3));
+}
+
+#[tokio::test]
+async fn run_ends_before_any_request_when_disallowed_tool_types_are_rejected() {
+    let dir = tempdir().expect("tmp");
+    let workspace_dir = dir.path().join("workspace");
+    std::fs::create_dir_all(&workspace_dir).expect("workspace");
+    let engine = SessionEngine::new(
+        dir.path().join("data"),
+        workspace_dir,
+        Some(OpenResponsesConfig {
+            // Nothing listens here: reaching the provider would end the run
+            // with a transport error instead.
+            endpoint: "http://127.0.0.1:9/v1/responses".to_string(),
+            allowed_tool_types: Some(vec!["web_search".to_string()]),
+            reject_disallowed_tool_types: true,
+            ..OpenResponsesConfig::default()
+        }),
+    )
+    .expect("engine");
+
+    let result = engine.run_to_completion("hello").await;
+    assert!(
+        matches!(&result, Err(SessionEndReason::Failed(reason)) if reason == "disallowed_tool_types"),
+        "unexpected result: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn run_to_completion_returns_local_driver_output() {
+    let dir = tempdir().expect("tmp");
+    let data_dir = dir.path().join("data");
+    let workspace_dir = dir.path().join("workspace");
+    std::fs::create_dir_all(&workspace_dir).expect("workspace");
+    let engine = SessionEngine::new(data_dir, workspace_dir, None).expect("engine");
+
+    let output = engine.run_to_completion("hello").await.expect("completed");
+    assert_eq!(output, "ack: hello");
+}
+
+#[tokio::test]
+async fn spawned_session_handle_reports_run_link() {
+    let dir = tempdir().expect("tmp");
+    let data_dir = dir.path().join("data");
+    let workspace_dir = dir.path().join("workspace");
+    std::fs::create_dir_all(&workspace_dir).expect("workspace");
+    let engine = SessionEngine::new(data_dir, workspace_dir, None).expect("engine");
+
+    let store = engine.continuities();
+    let thread_id = store.ensure_default().expect("thread");
+    let message_id = store
+        .append_message(
+            &thread_id,
+            "alice".to_string(),
+            "cli".to_string(),
+            "hi".to_string(),
+        )
+        .expect("append message");
+
+    let handle = engine.create_session();
+    assert!(handle.run_link().is_none());
+    let observer = handle.clone();
+    let mut rx = handle.subscribe();
+    engine.spawn_session(
+        handle,
+        "hi".to_string(),
+        Some(ContinuityRunLink {
+            continuity_id: thread_id.clone(),
+            message_id: message_id.clone(),
+            actor_id: "alice".to_string(),
+            origin: "cli".to_string(),
+        }),
+        None,
+    );
+
+    let link = observer.run_link().expect("run link");
+    assert_eq!(link.continuity_id, thread_id);
+    assert_eq!(link.message_id, message_id);
+    let _ = wait_for_event(&mut rx, |kind| {
+        matches!(kind, EventKind::SessionEnded { .. })
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn run_linked_session_output_carries_actor_attribution() {
+    let dir = tempdir().expect("tmp");
+    let data_dir = dir.path().join("data");
+    let workspace_dir = dir.path().join("workspace");
+    std::fs::create_dir_all(&workspace_dir).expect("workspace");
+    let engine = SessionEngine::new(data_dir, workspace_dir, None).expect("engine");
+
+    let store = engine.continuities();
+    let thread_id = store.ensure_default().expect("thread");
+    let message_id = store
+        .append_message(
+            &thread_id,
+            "alice".to_string(),
+            "cli".to_string(),
+            "hi".to_string(),
+        )
+        .expect("append message");
+
+    let handle = engine.create_session();
+    let mut rx = handle.subscribe();
+    engine.spawn_session(
+        handle,
+        "hi".to_string(),
+        Some(ContinuityRunLink {
+            continuity_id: thread_id,
+            message_id,
+            actor_id: "alice".to_string(),
+            origin: "cli".to_string(),
+        }),
+        None,
+    );
+
+    let event = wait_for_event(&mut rx, |kind| {
+        matches!(kind, EventKind::OutputTextDelta { .. })
+    })
+    .await;
+    match event.kind {
+        EventKind::OutputTextDelta {
+            delta,
+            actor_id,
+            origin,
+        } => {
+            assert_eq!(delta, "ack: hi");
+            assert_eq!(actor_id.as_deref(), Some("alice"));
+            assert_eq!(origin.as_deref(), Some("cli"));
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
+
+#[test]
+fn with_preamble_rejects_non_system_messages() {
+    use rip_provider_openresponses::ItemParam;
+
+    let dir = tempdir().expect("tmp");
+    let data_dir = dir.path().join("data");
+    let workspace_dir = dir.path().join("workspace");
+    std::fs::create_dir_all(&workspace_dir).expect("workspace");
+    let engine = SessionEngine::new(data_dir, workspace_dir, None).expect("engine");
+
+    let err = engine
+        .with_preamble(vec![
+            ItemParam::developer_message_text("be terse"),
+            ItemParam::user_message_text("hi"),
+        ])
+        .err()
+        .expect("user preamble rejected");
+    assert!(err.starts_with("preamble[1]"), "{err}");
+}
+
+#[tokio::test]
+async fn preamble_is_prepended_to_provider_input() {
+    use axum::extract::{Json as AxumJson, State};
+    use axum::http::header::CONTENT_TYPE;
+    use axum::routing::post;
+    use axum::Router as AxumRouter;
+    use rip_provider_openresponses::{ItemParam, ToolChoiceParam};
+    use tokio::net::TcpListener;
+
+    async fn handler(
+        State(requests): State<Arc<Mutex<Vec<serde_json::Value>>>>,
+        AxumJson(payload): AxumJson<serde_json::Value>,
+    ) -> impl axum::response::IntoResponse {
+        requests.lock().await.push(payload);
+        let body = "data: {\"type\":\"response.output_text.delta\",\"delta\":\"ok\"}\n\n\
+data: [DONE]\n\n";
+        ([(CONTENT_TYPE, "text/event-stream")], body.to_string())
+    }
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let provider_app = AxumRouter::new()
+        .route("/v1/responses", post(handler))
+        .with_state(requests.clone());
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    tokio::spawn(async move {
+        axum::serve(listener, provider_app).await.expect("serve");
+    });
+
+    let dir = tempdir().expect("tmp");
+    let data_dir = dir.path().join("data");
+    let workspace_dir = dir.path().join("workspace");
+    std::fs::create_dir_all(&workspace_dir).expect("workspace");
+    let engine = SessionEngine::new(
+        data_dir,
+        workspace_dir,
+        Some(OpenResponsesConfig {
+            provider_id: None,
+            endpoint: format!("http://{addr}/v1/responses"),
+            api_key: None,
+            model: Some("fixture-model".to_string()),
+            headers: Vec::new(),
+            tool_choice: ToolChoiceParam::auto(),
+            include: Vec::new(),
+            reasoning: None,
+            web_search: None,
+            followup_user_message: None,
+            stateless_history: false,
+            parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
+        }),
+    )
+    .expect("engine")
+    .with_preamble(vec![ItemParam::system_message_text("you are rip")])
+    .expect("preamble");
+
+    let handle = engine.create_session();
+    let mut rx = handle.subscribe();
+    engine.spawn_session(handle, "hi".to_string(), None, None);
+    let _ = wait_for_event(&mut rx, |kind| {
+        matches!(kind, EventKind::SessionEnded { .. })
+    })
+    .await;
+
+    let requests = requests.lock().await;
+    let input = requests
+        .first()
+        .and_then(|payload| payload.get("input"))
+        .and_then(|value| value.as_array())
+        .expect("input items");
+    assert_eq!(input.len(), 2);
+    assert_eq!(
+        input[0].get("role").and_then(|v| v.as_str()),
+        Some("system")
+    );
+    assert_eq!(
+        input[0].get("content").and_then(|v| v.as_str()),
+        Some("you are rip")
+    );
+    assert_eq!(input[1].get("role").and_then(|v| v.as_str()), Some("user"));
+}
+
+/// Serves `/v1/responses` with a `response.created` event and then never
+/// finishes the stream, so a run stays in flight until it is cancelled.
+async fn spawn_stalled_provider() -> std::net::SocketAddr {
+    use axum::body::{Body, Bytes};
+    use axum::routing::post;
+    use axum::Router as AxumRouter;
+    use futures_util::StreamExt;
+    use tokio::net::TcpListener;
+
+    let provider_app = AxumRouter::new().route(
+        "/v1/responses",
+        post(|| async {
+            let head: Result<Bytes, std::io::Error> = Ok(Bytes::from(
+                "data: {\"type\":\"response.created\",\"sequence_number\":0,\"response\":{\"id\":\"resp_1\"}}\n\n",
+            ));
+            (
+                [(axum::http::header::CONTENT_TYPE, "text/event-stream")],
+                Body::from_stream(
+                    futures_util::stream::iter([head]).chain(futures_util::stream::pending()),
+                ),
+            )
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    tokio::spawn(async move {
+        axum::serve(listener, provider_app).await.expect("serve");
+    });
+    addr
+}
+
 async fn wait_for_event<F>(receiver: &mut broadcast::Receiver<Event>, predicate: F) -> Event
 where
     F: Fn(&EventKind) -> bool,
@@ -389,6 +799,7 @@ data: [DONE]\n\n";
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         }),
     )
     .expect("engine");
@@ -619,6 +1030,7 @@ data: [DONE]\n\n";
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         }),
     )
     .expect("engine");
@@ -766,6 +1178,7 @@ data: [DONE]\n\n";
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         }),
     )
     .expect("engine");
@@ -1099,6 +1512,7 @@ async fn workspace_mutations_are_serialized_with_tasks() {
                 | EventKind::ToolTaskCancelled { .. }
                 | EventKind::ToolTaskOutputDelta { .. }
                 | EventKind::ToolTaskStdinWritten { .. }
+                | EventKind::ToolTaskStdinClosed { .. }
                 | EventKind::ToolTaskResized { .. }
                 | EventKind::ToolTaskSignalled { .. }
         ) {
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use axum::{
-    extract::State,
+    extract::{DefaultBodyLimit, State},
     http::{header::CONTENT_TYPE, StatusCode},
     routing::get,
     Json, Router,
@@ -9,7 +9,10 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 use utoipa::{OpenApi, ToSchema};
-use utoipa_axum::{router::OpenApiRouter, routes};
+use utoipa_axum::{
+    router::{OpenApiRouter, UtoipaMethodRouterExt},
+    routes,
+};
 
 use crate::openresponses_compat::{
     ConversationStrategy, OpenResponsesModelCompatProfile, OpenResponsesProviderCompatProfile,
@@ -20,7 +23,7 @@ use crate::provider_openresponses::{
     OpenResponsesConfig, OpenResponsesInclude, OpenResponsesReasoningConfig,
     OpenResponsesWebSearchConfig, OpenResponsesWebSearchOverride,
 };
-use crate::runner::{SessionEngine, SessionHandle};
+use crate::runner::{SessionEngine, SessionHandle, SessionStatus};
 use crate::tasks::TaskHandle;
 #[cfg(not(test))]
 use crate::AuthorityLockGuard;
@@ -35,6 +38,7 @@ use tokio::net::TcpListener;
 use rip_provider_openresponses::ToolChoiceParam;
 
 mod bootstrap;
+mod idempotency;
 mod routes_impl;
 
 #[cfg(not(test))]
@@ -47,6 +51,7 @@ pub(crate) struct AppState {
     engine: Arc<SessionEngine>,
     openapi_json: Arc<String>,
     allow_pty_tasks: bool,
+    message_idempotency: Arc<Mutex<idempotency::MessageIdempotencyKeys>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -54,6 +59,40 @@ pub(crate) struct SessionCreated {
     pub(crate) session_id: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub(crate) struct CheckpointImportResponse {
+    pub(crate) session_id: String,
+    /// Restored checkpoints, oldest first.
+    pub(crate) checkpoints: Vec<CheckpointImportEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub(crate) struct CheckpointImportEntry {
+    pub(crate) id: String,
+    pub(crate) label: String,
+    pub(crate) created_at_ms: u64,
+    /// Workspace-relative paths captured by the checkpoint.
+    pub(crate) files: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub(crate) struct SessionListQuery {
+    /// Only list sessions in this status.
+    pub(crate) status: Option<SessionStatus>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub(crate) struct SessionListEntry {
+    pub(crate) session_id: String,
+    /// Continuity (thread) the session runs for; absent for bare `POST /sessions` runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) continuity_id: Option<String>,
+    /// When the session left the queue and started running; absent while queued.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) started_at_ms: Option<u64>,
+    pub(crate) status: SessionStatus,
+}
+
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub(crate) struct ThreadEnsureResponse {
     pub(crate) thread_id: String,
@@ -67,7 +106,7 @@ pub(crate) struct ThreadMeta {
     pub(crate) archived: bool,
 }
 
-#[derive(Debug, Deserialize, ToSchema)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub(crate) struct ThreadPostMessagePayload {
     pub(crate) content: String,
     pub(crate) actor_id: Option<String>,
@@ -94,9 +133,18 @@ pub(crate) struct ThreadOpenResponsesOverrides {
     pub(crate) web_search: Option<OpenResponsesWebSearchOverride>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) reasoning: Option<OpenResponsesReasoningConfig>,
+    /// Local debugging: record each provider request body for this run as a
+    /// workspace artifact (as `RIP_OPENRESPONSES_DUMP_REQUEST` does for all
+    /// runs) and emit an `openresponses_request` frame pointing at it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) dump_request: Option<bool>,
 }
 
 impl ThreadOpenResponsesOverrides {
+    pub(crate) fn request_dump(&self) -> bool {
+        self.dump_request.unwrap_or(false)
+    }
+
     pub(crate) fn to_override_input(&self) -> crate::config::OpenResponsesOverrideInput {
         crate::config::OpenResponsesOverrideInput {
             endpoint: self.endpoint.clone(),
@@ -111,13 +159,36 @@ impl ThreadOpenResponsesOverrides {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub(crate) struct ThreadPostMessageResponse {
     pub(crate) thread_id: String,
     pub(crate) message_id: String,
     pub(crate) session_id: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub(crate) struct ThreadCancelResponse {
+    pub(crate) thread_id: String,
+    pub(crate) cancelled_session_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub(crate) struct ThreadMessagesQuery {
+    /// First 1-based message ordinal to return (defaults to the first message).
+    pub(crate) from_ordinal: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub(crate) struct ThreadMessage {
+    pub(crate) message_id: String,
+    pub(crate) ordinal: u64,
+    pub(crate) seq: u64,
+    pub(crate) timestamp_ms: u64,
+    pub(crate) actor_id: String,
+    pub(crate) origin: String,
+    pub(crate) content: String,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub(crate) struct ThreadBranchPayload {
     pub(crate) title: Option<String>,
@@ -324,6 +395,7 @@ pub(crate) fn build_app_with_workspace_root_and_provider_and_task_policy(
         engine,
         openapi_json: Arc::new(openapi_json),
         allow_pty_tasks,
+        message_idempotency: Arc::new(Mutex::new(Default::default())),
     };
 
     router
@@ -356,6 +428,8 @@ pub(crate) fn resolve_session_openresponses_override(
             followup_user_message: cfg.followup_user_message,
             stateless_history: cfg.stateless_history,
             parallel_tool_calls: cfg.parallel_tool_calls,
+            allowed_tool_types: cfg.allowed_tool_types,
+            reject_disallowed_tool_types: cfg.reject_disallowed_tool_types,
         });
     }
 
@@ -439,49 +513,62 @@ fn infer_provider_id_from_endpoint(endpoint: &str) -> Option<String> {
 }
 
 pub(crate) fn build_openapi_router() -> (Router<AppState>, String) {
-    let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
-        .routes(routes!(routes_impl::config::config_doctor))
-        .routes(routes!(routes_impl::sessions::create_session))
-        .routes(routes!(routes_impl::sessions::send_input))
-        .routes(routes!(routes_impl::sessions::stream_events))
-        .routes(routes!(routes_impl::sessions::cancel_session))
-        .routes(routes!(routes_impl::threads::thread_ensure))
-        .routes(routes!(routes_impl::threads::thread_list))
-        .routes(routes!(routes_impl::threads::thread_get))
-        .routes(routes!(routes_impl::threads::thread_post_message))
-        .routes(routes!(routes_impl::threads::thread_branch))
-        .routes(routes!(routes_impl::threads::thread_handoff))
-        .routes(routes!(
-            routes_impl::threads_compaction::thread_compaction_checkpoint
-        ))
-        .routes(routes!(
-            routes_impl::threads_compaction::thread_compaction_cut_points
-        ))
-        .routes(routes!(
-            routes_impl::threads_compaction::thread_compaction_status
-        ))
-        .routes(routes!(routes_impl::threads::thread_provider_cursor_status))
-        .routes(routes!(routes_impl::threads::thread_provider_cursor_rotate))
-        .routes(routes!(
-            routes_impl::threads::thread_context_selection_status
-        ))
-        .routes(routes!(
-            routes_impl::threads_compaction::thread_compaction_auto
-        ))
-        .routes(routes!(
-            routes_impl::threads_compaction::thread_compaction_auto_schedule
-        ))
-        .routes(routes!(routes_impl::threads::thread_stream_events))
-        .routes(routes!(routes_impl::tasks::create_task))
-        .routes(routes!(routes_impl::tasks::list_tasks))
-        .routes(routes!(routes_impl::tasks::task_status))
-        .routes(routes!(routes_impl::tasks::task_output))
-        .routes(routes!(routes_impl::tasks::stream_task_events))
-        .routes(routes!(routes_impl::tasks::cancel_task))
-        .routes(routes!(routes_impl::tasks::task_write_stdin))
-        .routes(routes!(routes_impl::tasks::task_resize))
-        .routes(routes!(routes_impl::tasks::task_signal))
-        .split_for_parts();
+    let (router, api) =
+        OpenApiRouter::with_openapi(ApiDoc::openapi())
+            .routes(routes!(routes_impl::config::config_doctor))
+            .routes(routes!(
+                routes_impl::sessions::create_session,
+                routes_impl::sessions::list_sessions
+            ))
+            .routes(routes!(routes_impl::sessions::send_input))
+            .routes(routes!(routes_impl::sessions::stream_events))
+            .routes(routes!(routes_impl::sessions::cancel_session))
+            .routes(routes!(routes_impl::checkpoints::export_checkpoints))
+            .routes(routes!(routes_impl::checkpoints::import_checkpoints).layer(
+                DefaultBodyLimit::max(rip_workspace::MAX_CHECKPOINT_ARCHIVE_BYTES),
+            ))
+            .routes(routes!(routes_impl::threads::thread_ensure))
+            .routes(routes!(routes_impl::threads::thread_list))
+            .routes(routes!(routes_impl::threads::thread_get))
+            .routes(routes!(routes_impl::threads::thread_cancel))
+            .routes(routes!(
+                routes_impl::threads::thread_post_message,
+                routes_impl::threads::thread_list_messages
+            ))
+            .routes(routes!(routes_impl::threads::thread_branch))
+            .routes(routes!(routes_impl::threads::thread_handoff))
+            .routes(routes!(
+                routes_impl::threads_compaction::thread_compaction_checkpoint
+            ))
+            .routes(routes!(
+                routes_impl::threads_compaction::thread_compaction_cut_points
+            ))
+            .routes(routes!(
+                routes_impl::threads_compaction::thread_compaction_status
+            ))
+            .routes(routes!(routes_impl::threads::thread_provider_cursor_status))
+            .routes(routes!(routes_impl::threads::thread_provider_cursor_rotate))
+            .routes(routes!(
+                routes_impl::threads::thread_context_selection_status
+            ))
+            .routes(routes!(
+                routes_impl::threads_compaction::thread_compaction_auto
+            ))
+            .routes(routes!(
+                routes_impl::threads_compaction::thread_compaction_auto_schedule
+            ))
+            .routes(routes!(routes_impl::threads::thread_stream_events))
+            .routes(routes!(routes_impl::tasks::create_task))
+            .routes(routes!(routes_impl::tasks::list_tasks))
+            .routes(routes!(routes_impl::tasks::task_status))
+            .routes(routes!(routes_impl::tasks::task_output))
+            .routes(routes!(routes_impl::tasks::stream_task_events))
+            .routes(routes!(routes_impl::tasks::cancel_task))
+            .routes(routes!(routes_impl::tasks::task_write_stdin))
+            .routes(routes!(routes_impl::tasks::task_close_stdin))
+            .routes(routes!(routes_impl::tasks::task_resize))
+            .routes(routes!(routes_impl::tasks::task_signal))
+            .split_for_parts();
     let json = api
         .to_pretty_json()
         .map(|value| format!("{value}\n"))
@@ -15,6 +15,15 @@ pub(crate) async fn serve(data_dir: std::path::PathBuf) {
         .await
         .unwrap_or_else(|err| panic!("{err}"));
 
+    // Compile the OpenResponses schemas off the request path.
+    tokio::task::spawn_blocking(rip_provider_openresponses::warm_validators);
+    eprintln!(
+        "ripd openresponses schema: {} {} ({} components)",
+        rip_provider_openresponses::openapi_title(),
+        rip_provider_openresponses::openapi_version(),
+        rip_provider_openresponses::schema_component_names().len()
+    );
+
     let app = build_app_with_workspace_root_and_provider(
         data_dir.clone(),
         workspace_root.clone(),
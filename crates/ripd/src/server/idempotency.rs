@@ -0,0 +1,95 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use tokio::sync::Mutex;
+
+use super::ThreadPostMessageResponse;
+
+/// How long an accepted `Idempotency-Key` keeps replaying its original run.
+pub(crate) const MESSAGE_IDEMPOTENCY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// Most keys remembered at once; the oldest are evicted first past this.
+pub(crate) const MESSAGE_IDEMPOTENCY_MAX_KEYS: usize = 10_000;
+
+/// What a key was first accepted with: the request body (to reject reuse with
+/// a different payload) and the response to replay.
+#[derive(Clone)]
+pub(crate) struct AcceptedMessage {
+    pub(crate) payload: Value,
+    pub(crate) response: ThreadPostMessageResponse,
+}
+
+/// Per-key in-flight slot. Requests hold its lock while they run, so a retry
+/// waits for the first attempt without blocking other keys. It stays `None`
+/// until an attempt is accepted.
+pub(crate) type IdempotencySlot = Arc<Mutex<Option<AcceptedMessage>>>;
+
+type SlotKey = (String, String);
+
+/// `POST /threads/{id}/messages` slots keyed by `(thread_id, Idempotency-Key)`,
+/// bounded by [`MESSAGE_IDEMPOTENCY_MAX_KEYS`] and expired after
+/// [`MESSAGE_IDEMPOTENCY_TTL`]. Slots are queued in creation order so eviction
+/// only ever looks at the front.
+#[derive(Default)]
+pub(crate) struct MessageIdempotencyKeys {
+    slots: HashMap<SlotKey, IdempotencySlot>,
+    order: VecDeque<(Instant, SlotKey)>,
+}
+
+impl MessageIdempotencyKeys {
+    pub(crate) fn slot(&mut self, thread_id: &str, key: &str, now: Instant) -> IdempotencySlot {
+        self.evict(now);
+        let slot_key = (thread_id.to_string(), key.to_string());
+        if let Some(slot) = self.slots.get(&slot_key) {
+            return slot.clone();
+        }
+        let slot = IdempotencySlot::default();
+        self.slots.insert(slot_key.clone(), slot.clone());
+        self.order.push_back((now, slot_key));
+        slot
+    }
+
+    fn evict(&mut self, now: Instant) {
+        while let Some((created_at, _)) = self.order.front() {
+            let expired = now.duration_since(*created_at) >= MESSAGE_IDEMPOTENCY_TTL;
+            if !expired && self.order.len() < MESSAGE_IDEMPOTENCY_MAX_KEYS {
+                break;
+            }
+            if let Some((_, slot_key)) = self.order.pop_front() {
+                self.slots.remove(&slot_key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_are_shared_per_key_and_expire_after_ttl() {
+        let mut keys = MessageIdempotencyKeys::default();
+        let start = Instant::now();
+        let first = keys.slot("t1", "k", start);
+        assert!(Arc::ptr_eq(&first, &keys.slot("t1", "k", start)));
+        assert!(!Arc::ptr_eq(&first, &keys.slot("t2", "k", start)));
+
+        let later = start + MESSAGE_IDEMPOTENCY_TTL;
+        assert!(!Arc::ptr_eq(&first, &keys.slot("t1", "k", later)));
+        assert_eq!(keys.slots.len(), 1);
+    }
+
+    #[test]
+    fn oldest_slots_are_evicted_past_the_key_limit() {
+        let mut keys = MessageIdempotencyKeys::default();
+        let now = Instant::now();
+        let oldest = keys.slot("t1", "k0", now);
+        for index in 1..=MESSAGE_IDEMPOTENCY_MAX_KEYS {
+            keys.slot("t1", &format!("k{index}"), now);
+        }
+        assert_eq!(keys.slots.len(), MESSAGE_IDEMPOTENCY_MAX_KEYS);
+        assert!(!Arc::ptr_eq(&oldest, &keys.slot("t1", "k0", now)));
+        assert_eq!(keys.slots.len(), MESSAGE_IDEMPOTENCY_MAX_KEYS);
+    }
+}
@@ -0,0 +1,103 @@
+use super::super::*;
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use rip_workspace::Workspace;
+
+#[utoipa::path(
+    get,
+    path = "/sessions/{id}/checkpoints/export",
+    params(
+        ("id" = String, Path, description = "Session id the checkpoints were recorded for")
+    ),
+    responses(
+        (status = 200, description = "Tar archive of the session's checkpoint metadata and file bodies", content_type = "application/x-tar", body = Vec<u8>),
+        (status = 404, description = "Session has no checkpoints"),
+        (status = 413, description = "Archive would exceed the checkpoint archive size cap")
+    )
+)]
+pub(crate) async fn export_checkpoints(
+    Path(session_id): Path<String>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let workspace = match Workspace::new(state.engine.continuities().workspace_root()) {
+        Ok(workspace) => workspace,
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+    let mut archive = Vec::new();
+    match workspace.export_checkpoints(&session_id, &mut archive) {
+        Ok(_) => (
+            StatusCode::OK,
+            [(CONTENT_TYPE, "application/x-tar")],
+            archive,
+        )
+            .into_response(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            StatusCode::NOT_FOUND.into_response()
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::FileTooLarge => {
+            StatusCode::PAYLOAD_TOO_LARGE.into_response()
+        }
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/sessions/{id}/checkpoints/import",
+    params(
+        ("id" = String, Path, description = "Session id to restore the checkpoints under")
+    ),
+    request_body(content = Vec<u8>, content_type = "application/x-tar", description = "Archive produced by the export endpoint"),
+    responses(
+        (status = 200, description = "Checkpoints restored", body = CheckpointImportResponse),
+        (status = 400, description = "Archive is malformed, names paths outside the workspace, or is missing a stored file body"),
+        (status = 409, description = "A checkpoint id in the archive already exists in the session"),
+        (status = 413, description = "Archive exceeds the checkpoint archive size cap")
+    )
+)]
+pub(crate) async fn import_checkpoints(
+    Path(session_id): Path<String>,
+    State(state): State<AppState>,
+    body: Bytes,
+) -> impl IntoResponse {
+    let workspace = match Workspace::new(state.engine.continuities().workspace_root()) {
+        Ok(workspace) => workspace,
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+    match workspace.import_checkpoints(&session_id, body.as_ref()) {
+        Ok(imported) => {
+            let checkpoints = imported
+                .into_iter()
+                .map(|checkpoint| CheckpointImportEntry {
+                    id: checkpoint.id,
+                    label: checkpoint.label,
+                    created_at_ms: checkpoint.created_at_ms,
+                    files: checkpoint.files.into_iter().map(|file| file.path).collect(),
+                })
+                .collect();
+            Json(CheckpointImportResponse {
+                session_id,
+                checkpoints,
+            })
+            .into_response()
+        }
+        Err(err)
+            if matches!(
+                err.kind(),
+                std::io::ErrorKind::InvalidData | std::io::ErrorKind::NotFound
+            ) =>
+        {
+            StatusCode::BAD_REQUEST.into_response()
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+            StatusCode::CONFLICT.into_response()
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::FileTooLarge => {
+            StatusCode::PAYLOAD_TOO_LARGE.into_response()
+        }
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod checkpoints;
 pub(crate) mod config;
 pub(crate) mod sessions;
 pub(crate) mod tasks;
@@ -1,6 +1,6 @@
 use super::super::*;
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     response::{sse::Event as SseEvent, IntoResponse, Sse},
 };
 use futures_util::StreamExt;
@@ -23,6 +23,48 @@ pub(crate) async fn create_session(State(state): State<AppState>) -> impl IntoRe
     (StatusCode::CREATED, Json(SessionCreated { session_id }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/sessions",
+    params(
+        ("status" = Option<SessionStatus>, Query, description = "Only list sessions in this status")
+    ),
+    responses(
+        (status = 200, description = "Known sessions, oldest first", body = [SessionListEntry])
+    )
+)]
+pub(crate) async fn list_sessions(
+    Query(query): Query<SessionListQuery>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let mut out = {
+        let sessions = state.sessions.lock().await;
+        sessions
+            .values()
+            .filter_map(|handle| {
+                let status = handle.status();
+                if query.status.is_some_and(|wanted| wanted != status) {
+                    return None;
+                }
+                Some(SessionListEntry {
+                    continuity_id: handle.run_link().map(|link| link.continuity_id.clone()),
+                    session_id: handle.session_id.clone(),
+                    started_at_ms: handle.started_at_ms(),
+                    status,
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    out.sort_by(|a, b| {
+        (a.started_at_ms.is_none(), a.started_at_ms, &a.session_id).cmp(&(
+            b.started_at_ms.is_none(),
+            b.started_at_ms,
+            &b.session_id,
+        ))
+    });
+    Json(out).into_response()
+}
+
 #[utoipa::path(
     post,
     path = "/sessions/{id}/input",
@@ -57,9 +99,15 @@ pub(crate) async fn send_input(
         state.engine.default_openresponses().as_ref(),
     );
 
-    state
-        .engine
-        .spawn_session(handle, input, None, openresponses_override);
+    state.engine.spawn_session_with_request_dump(
+        handle,
+        input,
+        None,
+        openresponses_override,
+        openresponses
+            .as_ref()
+            .is_some_and(ThreadOpenResponsesOverrides::request_dump),
+    );
 
     StatusCode::ACCEPTED.into_response()
 }
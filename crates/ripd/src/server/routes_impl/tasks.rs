@@ -119,13 +119,27 @@ pub(crate) async fn task_output(
     };
 
     let engine: Arc<TaskEngine> = state.engine.tasks();
-    let offset = query.offset_bytes.unwrap_or(0);
     let max_bytes = query.max_bytes.unwrap_or(engine.config().max_bytes);
 
-    match handle
-        .output(engine.config(), query.stream, offset, max_bytes)
-        .await
-    {
+    let output = match (query.tail_bytes, query.offset_bytes) {
+        (Some(_), Some(_)) => return StatusCode::BAD_REQUEST.into_response(),
+        (Some(tail), None) => {
+            handle
+                .output_tail(engine.config(), query.stream, tail, max_bytes)
+                .await
+        }
+        (None, offset) => {
+            handle
+                .output(
+                    engine.config(),
+                    query.stream,
+                    offset.unwrap_or(0),
+                    max_bytes,
+                )
+                .await
+        }
+    };
+    match output {
         Ok(output) => Json(output).into_response(),
         Err(_) => StatusCode::BAD_REQUEST.into_response(),
     }
@@ -248,6 +262,36 @@ pub(crate) async fn task_write_stdin(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/tasks/{id}/stdin/close",
+    params(
+        ("id" = String, Path, description = "Task id")
+    ),
+    responses(
+        (status = 202, description = "Stdin close accepted"),
+        (status = 400, description = "Invalid stdin close request"),
+        (status = 404, description = "Task not found")
+    )
+)]
+pub(crate) async fn task_close_stdin(
+    Path(task_id): Path<String>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let handle = {
+        let tasks = state.tasks.lock().await;
+        match tasks.get(&task_id) {
+            Some(handle) => handle.clone(),
+            None => return StatusCode::NOT_FOUND.into_response(),
+        }
+    };
+
+    match handle.close_stdin().await {
+        Ok(()) => StatusCode::ACCEPTED.into_response(),
+        Err(_) => StatusCode::BAD_REQUEST.into_response(),
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/tasks/{id}/resize",
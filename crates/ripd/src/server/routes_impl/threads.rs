@@ -1,6 +1,7 @@
 use super::super::*;
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::HeaderMap,
     response::{sse::Event as SseEvent, IntoResponse, Sse},
 };
 use futures_util::StreamExt;
@@ -73,21 +74,95 @@ pub(crate) async fn thread_get(
 
 #[utoipa::path(
     post,
-    path = "/threads/{id}/messages",
+    path = "/threads/{id}/cancel",
     params(
         ("id" = String, Path, description = "Thread id")
     ),
+    responses(
+        (status = 200, description = "Active runs of the thread cancelled", body = ThreadCancelResponse),
+        (status = 404, description = "Thread not found")
+    )
+)]
+pub(crate) async fn thread_cancel(
+    Path(thread_id): Path<String>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    if state.engine.continuities().get(&thread_id).is_none() {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    let mut sessions = state.sessions.lock().await;
+    let mut linked = sessions
+        .values()
+        .filter(|handle| handle.status() != SessionStatus::Ended)
+        .filter(|handle| {
+            handle
+                .run_link()
+                .is_some_and(|link| link.continuity_id == thread_id)
+        })
+        .map(|handle| handle.session_id.clone())
+        .collect::<Vec<_>>();
+    linked.sort();
+    linked.retain(|session_id| {
+        crate::runner::SessionEngine::cancel_session(&mut sessions, session_id)
+    });
+
+    Json(ThreadCancelResponse {
+        thread_id,
+        cancelled_session_ids: linked,
+    })
+    .into_response()
+}
+
+#[utoipa::path(
+    post,
+    path = "/threads/{id}/messages",
+    params(
+        ("id" = String, Path, description = "Thread id"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the original response for a repeated key instead of starting another run")
+    ),
     request_body = ThreadPostMessagePayload,
     responses(
         (status = 202, description = "Message accepted and run started", body = ThreadPostMessageResponse),
-        (status = 404, description = "Thread not found")
+        (status = 404, description = "Thread not found"),
+        (status = 422, description = "Idempotency-Key was already used with a different request body")
     )
 )]
 pub(crate) async fn thread_post_message(
     Path(thread_id): Path<String>,
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(payload): Json<ThreadPostMessagePayload>,
 ) -> impl IntoResponse {
+    let idempotency_key = headers
+        .get("idempotency-key")
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(str::to_string);
+    // The key's slot is held for the whole request so a concurrent retry waits
+    // for the first attempt instead of racing it.
+    let mut idempotency = match idempotency_key.as_deref() {
+        Some(key) => {
+            let slot = state.message_idempotency.lock().await.slot(
+                &thread_id,
+                key,
+                std::time::Instant::now(),
+            );
+            let fingerprint = serde_json::to_value(&payload).unwrap_or_default();
+            Some((slot.lock_owned().await, fingerprint))
+        }
+        None => None,
+    };
+    if let Some((slot, fingerprint)) = idempotency.as_ref() {
+        if let Some(accepted) = slot.as_ref() {
+            if accepted.payload != *fingerprint {
+                return StatusCode::UNPROCESSABLE_ENTITY.into_response();
+            }
+            return (StatusCode::ACCEPTED, Json(accepted.response.clone())).into_response();
+        }
+    }
+
     let ThreadPostMessagePayload {
         content,
         actor_id,
@@ -133,19 +208,78 @@ pub(crate) async fn thread_post_message(
     {
         return StatusCode::INTERNAL_SERVER_ERROR.into_response();
     }
-    state
-        .engine
-        .spawn_session(handle, content, Some(run_link), openresponses_override);
+    state.engine.spawn_session_with_request_dump(
+        handle,
+        content,
+        Some(run_link),
+        openresponses_override,
+        openresponses
+            .as_ref()
+            .is_some_and(ThreadOpenResponsesOverrides::request_dump),
+    );
+
+    let response = ThreadPostMessageResponse {
+        thread_id,
+        message_id,
+        session_id,
+    };
+    if let Some((slot, fingerprint)) = idempotency.as_mut() {
+        **slot = Some(idempotency::AcceptedMessage {
+            payload: std::mem::take(fingerprint),
+            response: response.clone(),
+        });
+    }
+    (StatusCode::ACCEPTED, Json(response)).into_response()
+}
 
-    (
-        StatusCode::ACCEPTED,
-        Json(ThreadPostMessageResponse {
-            thread_id,
-            message_id,
-            session_id,
-        }),
+#[utoipa::path(
+    get,
+    path = "/threads/{id}/messages",
+    params(
+        ("id" = String, Path, description = "Thread id"),
+        ("from_ordinal" = Option<u64>, Query, description = "First 1-based message ordinal to return")
+    ),
+    responses(
+        (status = 200, description = "Thread messages in append order", body = [ThreadMessage]),
+        (status = 404, description = "Thread not found")
     )
-        .into_response()
+)]
+pub(crate) async fn thread_list_messages(
+    Path(thread_id): Path<String>,
+    Query(query): Query<ThreadMessagesQuery>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let store = state.engine.continuities();
+    let from_ordinal = query.from_ordinal.unwrap_or(1);
+    let messages = match store.messages_from_ordinal(&thread_id, from_ordinal) {
+        Ok(messages) => messages,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return StatusCode::NOT_FOUND.into_response();
+        }
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+
+    let mut out = Vec::with_capacity(messages.len());
+    for (ordinal, event) in messages {
+        let rip_kernel::EventKind::ContinuityMessageAppended {
+            actor_id,
+            origin,
+            content,
+        } = event.kind
+        else {
+            continue;
+        };
+        out.push(ThreadMessage {
+            message_id: event.id,
+            ordinal,
+            seq: event.seq,
+            timestamp_ms: event.timestamp_ms,
+            actor_id,
+            origin,
+            content,
+        });
+    }
+    Json(out).into_response()
 }
 
 #[utoipa::path(
@@ -369,6 +503,11 @@ pub(crate) async fn thread_stream_events(
     Path(thread_id): Path<String>,
     State(state): State<AppState>,
 ) -> impl IntoResponse {
+    thread_events_sse(&state, thread_id)
+}
+
+/// Replays the thread's stream, then follows live appends for it.
+fn thread_events_sse(state: &AppState, thread_id: String) -> axum::response::Response {
     let store = state.engine.continuities();
     let receiver = store.subscribe();
 
@@ -389,11 +528,8 @@ pub(crate) async fn thread_stream_events(
         Some(Ok::<SseEvent, Infallible>(SseEvent::default().data(json)))
     });
 
-    let thread_id_live = thread_id.clone();
-    let last_seq_live = last_seq;
     let live_stream = BroadcastStream::new(receiver).filter_map(move |result| {
-        let last_seq = last_seq_live;
-        let thread_id = thread_id_live.clone();
+        let thread_id = thread_id.clone();
         async move {
             match result {
                 Ok(event) => {
@@ -14,8 +14,10 @@ use crate::provider_openresponses::{parse_tool_choice_env, OpenResponsesConfig};
 use crate::server::{
     build_app_with_workspace_root, build_app_with_workspace_root_and_provider,
     build_app_with_workspace_root_and_provider_and_task_policy, build_openapi_router,
-    workspace_root, SessionCreated, ThreadBranchResponse, ThreadCompactionCheckpointResponse,
-    ThreadEnsureResponse, ThreadHandoffResponse, ThreadMeta, ThreadPostMessageResponse,
+    workspace_root, CheckpointImportResponse, SessionCreated, SessionListEntry,
+    ThreadBranchResponse, ThreadCancelResponse, ThreadCompactionCheckpointResponse,
+    ThreadEnsureResponse, ThreadHandoffResponse, ThreadMessage, ThreadMeta,
+    ThreadPostMessageResponse,
 };
 
 fn build_test_app(dir: &tempfile::TempDir) -> Router {
@@ -70,6 +72,7 @@ fn build_test_app_with_openresponses_provider_profile(
             followup_user_message: None,
             stateless_history,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         }),
     )
 }
@@ -97,16 +100,49 @@ async fn create_session_id(app: &Router) -> String {
     payload.session_id
 }
 
+async fn list_sessions(app: &Router, query: &str) -> Vec<SessionListEntry> {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/sessions{query}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    let body = response
+        .into_body()
+        .collect()
+        .await
+        .expect("body")
+        .to_bytes();
+    serde_json::from_slice(&body).expect("json")
+}
+
 async fn create_task_id(app: &Router, command: &str) -> String {
     create_task_id_with_mode(app, command, "pipes").await
 }
 
 async fn create_task_id_with_mode(app: &Router, command: &str, execution_mode: &str) -> String {
+    create_task_id_with_args(
+        app,
+        serde_json::json!({ "command": command }),
+        execution_mode,
+    )
+    .await
+}
+
+async fn create_task_id_with_args(
+    app: &Router,
+    args: serde_json::Value,
+    execution_mode: &str,
+) -> String {
     let payload = serde_json::json!({
         "tool": "bash",
-        "args": {
-            "command": command
-        },
+        "args": args,
         "title": "test-task",
         "execution_mode": execution_mode,
     });
@@ -455,6 +491,7 @@ fn extract_data_json(message: &str) -> Option<serde_json::Value> {
     serde_json::from_str(json).ok()
 }
 
+mod checkpoints;
 mod config_openapi;
 mod openresponses_sessions;
 mod openresponses_threads;
@@ -0,0 +1,158 @@
+use super::*;
+use rip_workspace::Workspace;
+
+#[tokio::test]
+async fn checkpoints_export_then_import_into_fresh_workspace() {
+    let source_dir = tempdir().expect("tmp");
+    let source_app = build_test_app(&source_dir);
+    let source_root = source_dir.path().join("workspace");
+    let file = source_root.join("a.txt");
+    fs::write(&file, "one").expect("write");
+    let checkpoint = Workspace::new(&source_root)
+        .expect("workspace")
+        .create_checkpoint("s1", "manual", &[file])
+        .expect("checkpoint");
+
+    let response = source_app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri("/sessions/s1/checkpoints/export")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/x-tar"
+    );
+    let archive = response.into_body().collect().await.unwrap().to_bytes();
+
+    let target_dir = tempdir().expect("tmp");
+    let target_app = build_test_app(&target_dir);
+    let response = target_app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/sessions/s2/checkpoints/import")
+                .header("content-type", "application/x-tar")
+                .body(Body::from(archive))
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let imported: CheckpointImportResponse = serde_json::from_slice(&body).expect("json");
+    assert_eq!(imported.session_id, "s2");
+    assert_eq!(imported.checkpoints.len(), 1);
+    assert_eq!(imported.checkpoints[0].id, checkpoint.id);
+    assert_eq!(imported.checkpoints[0].label, "manual");
+    assert_eq!(imported.checkpoints[0].files, vec!["a.txt".to_string()]);
+
+    let listed = Workspace::new(target_dir.path().join("workspace"))
+        .expect("workspace")
+        .list_checkpoints("s2")
+        .expect("list");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, checkpoint.id);
+}
+
+#[tokio::test]
+async fn checkpoints_export_unknown_session_404() {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app(&dir);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri("/sessions/unknown/checkpoints/export")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn checkpoints_import_rejects_malformed_archive() {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app(&dir);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/sessions/s1/checkpoints/import")
+                .header("content-type", "application/x-tar")
+                .body(Body::from(vec![7u8; 1024]))
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn checkpoints_import_accepts_archives_above_default_body_limit() {
+    let source_dir = tempdir().expect("tmp");
+    let source_root = source_dir.path().join("workspace");
+    fs::create_dir_all(&source_root).expect("mkdir");
+    let file = source_root.join("big.bin");
+    fs::write(&file, vec![b'x'; 3 * 1024 * 1024]).expect("write");
+    let source = Workspace::new(&source_root).expect("workspace");
+    source
+        .create_checkpoint("s1", "big", &[file])
+        .expect("checkpoint");
+    let mut archive = Vec::new();
+    source
+        .export_checkpoints("s1", &mut archive)
+        .expect("export");
+    assert!(archive.len() > 2 * 1024 * 1024);
+
+    let target_dir = tempdir().expect("tmp");
+    let response = build_test_app(&target_dir)
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/sessions/s2/checkpoints/import")
+                .header("content-type", "application/x-tar")
+                .body(Body::from(archive))
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+}
+
+#[tokio::test]
+async fn checkpoints_import_existing_id_conflicts() {
+    let dir = tempdir().expect("tmp");
+    let root = dir.path().join("workspace");
+    fs::create_dir_all(&root).expect("mkdir");
+    let file = root.join("a.txt");
+    fs::write(&file, "one").expect("write");
+    let workspace = Workspace::new(&root).expect("workspace");
+    workspace
+        .create_checkpoint("s1", "manual", &[file])
+        .expect("checkpoint");
+    let mut archive = Vec::new();
+    workspace
+        .export_checkpoints("s1", &mut archive)
+        .expect("export");
+
+    let response = build_test_app(&dir)
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/sessions/s1/checkpoints/import")
+                .header("content-type", "application/x-tar")
+                .body(Body::from(archive))
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::CONFLICT);
+}
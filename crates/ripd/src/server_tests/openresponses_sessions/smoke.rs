@@ -381,6 +381,7 @@ async fn live_openresponses_smoke() {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         }),
     );
 
@@ -478,15 +479,14 @@ async fn live_openresponses_smoke() {
                         }
                     }
                     Some("tool_started") => saw_tool_started = true,
-                    Some("tool_stdout") => {
+                    Some("tool_stdout")
                         if value
                             .get("chunk")
                             .and_then(|chunk| chunk.as_str())
                             .unwrap_or("")
-                            .contains("RIP_LIVE_TEST_OK")
-                        {
-                            saw_tool_stdout_marker = true;
-                        }
+                            .contains("RIP_LIVE_TEST_OK") =>
+                    {
+                        saw_tool_stdout_marker = true;
                     }
                     Some("tool_ended") => saw_tool_ended = true,
                     Some("session_ended") => {
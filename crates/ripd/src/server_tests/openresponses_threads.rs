@@ -264,3 +264,131 @@ data: [DONE]\n\n";
         Some(posted.session_id.as_str())
     );
 }
+
+#[tokio::test]
+async fn thread_post_message_dump_request_stores_provider_request_artifact() {
+    use axum::extract::State;
+    use axum::http::header::CONTENT_TYPE;
+    use axum::routing::post;
+    use axum::{response::IntoResponse, Json, Router as AxumRouter};
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+
+    #[derive(Clone)]
+    struct ProviderState {
+        requests: Arc<Mutex<Vec<Value>>>,
+    }
+
+    let state = ProviderState {
+        requests: Arc::new(Mutex::new(Vec::new())),
+    };
+
+    let provider_app = AxumRouter::new()
+        .route(
+            "/v1/responses",
+            post(
+                |State(state): State<ProviderState>, Json(body): Json<Value>| async move {
+                    state.requests.lock().expect("requests").push(body);
+                    let sse =
+                        "data: {\"type\":\"response.output_text.delta\",\"delta\":\"ok\"}\n\n\
+data: [DONE]\n\n";
+                    ([(CONTENT_TYPE, "text/event-stream")], sse).into_response()
+                },
+            ),
+        )
+        .with_state(state.clone());
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    tokio::spawn(async move {
+        axum::serve(listener, provider_app).await.expect("serve");
+    });
+    let endpoint = format!("http://{addr}/v1/responses");
+
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app_with_openresponses_provider(&dir, endpoint, false);
+    let thread_id = ensure_thread_id(&app).await;
+
+    // A path is no longer accepted: the server never writes outside its workspace artifacts.
+    let path_payload = serde_json::json!({
+        "content": "hello",
+        "openresponses": { "dump_request": dir.path().join("request.json") },
+    });
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/threads/{thread_id}/messages"))
+                .header("content-type", "application/json")
+                .body(Body::from(path_payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert!(response.status().is_client_error());
+
+    let payload = serde_json::json!({
+        "content": "hello",
+        "openresponses": {
+            "model": "dump-model",
+            "dump_request": true,
+        },
+    });
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/threads/{thread_id}/messages"))
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::ACCEPTED);
+
+    let sent = timeout(Duration::from_secs(2), async {
+        loop {
+            if let Some(sent) = state.requests.lock().expect("requests").first().cloned() {
+                return sent;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("provider request");
+
+    let blobs_dir = dir
+        .path()
+        .join("workspace")
+        .join(".rip")
+        .join("artifacts")
+        .join("blobs");
+    let dumped: Value = timeout(Duration::from_secs(2), async {
+        loop {
+            // Context bundles share the blobs dir; pick the request body.
+            let request = std::fs::read_dir(&blobs_dir)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .filter_map(|entry| std::fs::read(entry.path()).ok())
+                .filter_map(|bytes| serde_json::from_slice::<Value>(&bytes).ok())
+                .find(|body| body.get("model").and_then(Value::as_str) == Some("dump-model"));
+            if let Some(request) = request {
+                return request;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("request artifact");
+    assert_eq!(dumped, sent);
+    assert_eq!(
+        dumped.get("model").and_then(|value| value.as_str()),
+        Some("dump-model")
+    );
+    assert!(dumped.to_string().contains("hello"));
+}
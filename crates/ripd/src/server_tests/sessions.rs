@@ -426,3 +426,52 @@ async fn tool_input_emits_checkpoint_events() {
 
     assert!(saw_checkpoint, "expected checkpoint_created event");
 }
+
+#[tokio::test]
+async fn list_sessions_reports_started_sessions_and_filters_by_status() {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app(&dir);
+    let thread_id = ensure_thread_id(&app).await;
+    let first = post_thread_message(&app, &thread_id, "first").await;
+    let second = post_thread_message(&app, &thread_id, "second").await;
+    let queued = create_session_id(&app).await;
+
+    let listed = list_sessions(&app, "").await;
+    assert_eq!(listed.len(), 3);
+    for session_id in [&first.session_id, &second.session_id] {
+        let entry = listed
+            .iter()
+            .find(|entry| &entry.session_id == session_id)
+            .unwrap_or_else(|| panic!("missing {session_id}"));
+        assert_eq!(entry.continuity_id.as_deref(), Some(thread_id.as_str()));
+    }
+
+    timeout(Duration::from_secs(5), async {
+        while list_sessions(&app, "?status=ended").await.len() < 2 {
+            sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("thread sessions end");
+    let ended = list_sessions(&app, "?status=ended").await;
+    assert!(ended.iter().all(|entry| entry.started_at_ms.is_some()));
+
+    let queued_only = list_sessions(&app, "?status=queued").await;
+    assert_eq!(queued_only.len(), 1);
+    assert_eq!(queued_only[0].session_id, queued);
+    assert_eq!(queued_only[0].continuity_id, None);
+    assert_eq!(queued_only[0].started_at_ms, None);
+
+    let bad = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri("/sessions?status=bogus")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(bad.status(), axum::http::StatusCode::BAD_REQUEST);
+}
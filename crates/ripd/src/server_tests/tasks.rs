@@ -114,15 +114,14 @@ async fn pty_task_supports_control_operations() {
             if let Some(value) = extract_data_json(&message) {
                 match value.get("type").and_then(|value| value.as_str()) {
                     Some("tool_task_stdin_written") => saw_stdin = true,
-                    Some("tool_task_output_delta") => {
+                    Some("tool_task_output_delta")
                         if value
                             .get("chunk")
                             .and_then(|value| value.as_str())
                             .unwrap_or("")
-                            .contains("hi")
-                        {
-                            saw_output = true;
-                        }
+                            .contains("hi") =>
+                    {
+                        saw_output = true;
                     }
                     Some("tool_task_status") => {
                         let status = value.get("status").and_then(|value| value.as_str());
@@ -257,6 +256,143 @@ async fn pty_task_supports_control_operations() {
     .expect("pty output timeout");
 }
 
+#[cfg(not(windows))]
+#[tokio::test]
+async fn pty_task_close_stdin_delivers_eof() {
+    assert_close_stdin_delivers_eof("pty").await;
+}
+
+#[cfg(not(windows))]
+#[tokio::test]
+async fn pipes_task_close_stdin_delivers_eof() {
+    assert_close_stdin_delivers_eof("pipes").await;
+}
+
+#[cfg(not(windows))]
+async fn assert_close_stdin_delivers_eof(mode: &str) {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app_with_task_policy(&dir, true);
+    let args = serde_json::json!({ "command": "cat >/dev/null", "interactive_stdin": true });
+    let task_id = create_task_id_with_args(&app, args, mode).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/tasks/{task_id}/events"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    let mut reader = TestSseReader::new(response.into_body());
+
+    timeout(Duration::from_secs(5), async {
+        while let Some(message) = reader.next_data_message().await {
+            if let Some(value) = extract_data_json(&message) {
+                if value.get("type").and_then(|value| value.as_str()) == Some("tool_task_status")
+                    && value.get("status").and_then(|value| value.as_str()) == Some("running")
+                {
+                    break;
+                }
+            }
+        }
+    })
+    .await
+    .expect("timeout");
+
+    let close = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/tasks/{task_id}/stdin/close"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(close.status(), axum::http::StatusCode::ACCEPTED);
+
+    let mut saw_closed = false;
+    let mut terminal_status = None;
+    timeout(Duration::from_secs(5), async {
+        while let Some(message) = reader.next_data_message().await {
+            if let Some(value) = extract_data_json(&message) {
+                match value.get("type").and_then(|value| value.as_str()) {
+                    Some("tool_task_stdin_closed") => saw_closed = true,
+                    Some("tool_task_status") => {
+                        let status = value.get("status").and_then(|value| value.as_str());
+                        if matches!(status, Some("exited") | Some("cancelled") | Some("failed")) {
+                            terminal_status = status.map(str::to_string);
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    })
+    .await
+    .expect("timeout");
+
+    assert!(saw_closed, "expected tool_task_stdin_closed");
+    assert_eq!(terminal_status.as_deref(), Some("exited"));
+}
+
+#[cfg(not(windows))]
+#[tokio::test]
+async fn pipes_task_stdin_reads_eof_without_close() {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app(&dir);
+    let task_id = create_task_id(&app, "cat >/dev/null").await;
+
+    wait_for_task_terminal(&app, &task_id).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/tasks/{task_id}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    let body = response
+        .into_body()
+        .collect()
+        .await
+        .expect("body")
+        .to_bytes();
+    let status: serde_json::Value = serde_json::from_slice(&body).expect("json");
+    assert_eq!(status["status"], "exited");
+    assert_eq!(status["exit_code"], 0);
+}
+
+#[cfg(not(windows))]
+#[tokio::test]
+async fn pipes_task_close_stdin_rejected_without_interactive_stdin() {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app(&dir);
+    let task_id = create_task_id(&app, "sleep 1").await;
+
+    let close = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/tasks/{task_id}/stdin/close"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(close.status(), axum::http::StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn list_tasks_includes_created_task() {
     let dir = tempdir().expect("tmp");
@@ -394,15 +530,14 @@ async fn task_events_stream_emits_output_and_termination() {
             if let Some(value) = extract_data_json(&message) {
                 match value.get("type").and_then(|value| value.as_str()) {
                     Some("tool_task_spawned") => saw_spawned = true,
-                    Some("tool_task_output_delta") => {
+                    Some("tool_task_output_delta")
                         if value
                             .get("chunk")
                             .and_then(|value| value.as_str())
                             .unwrap_or("")
-                            .contains("hello-task")
-                        {
-                            saw_output = true;
-                        }
+                            .contains("hello-task") =>
+                    {
+                        saw_output = true;
                     }
                     Some("tool_task_status") => {
                         let status = value.get("status").and_then(|value| value.as_str());
@@ -506,6 +641,95 @@ async fn task_output_supports_offset_range_reads_from_offset() {
     .expect("stdout output timeout");
 }
 
+#[tokio::test]
+async fn task_output_tail_bytes_returns_final_bytes_on_char_boundary() {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app(&dir);
+    let task_id = create_task_id(&app, "printf '0123456789abcdefghij'").await;
+    let utf8_task_id = create_task_id(&app, "printf 'x\\303\\251\\303\\251'").await;
+
+    wait_for_task_terminal(&app, &task_id).await;
+    wait_for_task_terminal(&app, &utf8_task_id).await;
+
+    async fn fetch(app: &axum::Router, uri: String) -> Option<serde_json::Value> {
+        let output = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("response");
+        if output.status() != axum::http::StatusCode::OK {
+            return None;
+        }
+        let body = output.into_body().collect().await.expect("body").to_bytes();
+        Some(serde_json::from_slice(&body).expect("json"))
+    }
+
+    timeout(Duration::from_secs(2), async {
+        loop {
+            let uri = format!("/tasks/{task_id}/output?stream=stdout&tail_bytes=10");
+            if let Some(value) = fetch(&app, uri).await {
+                if value.get("total_bytes").and_then(|value| value.as_u64()) == Some(20) {
+                    assert_eq!(
+                        value.get("content").and_then(|value| value.as_str()),
+                        Some("abcdefghij")
+                    );
+                    assert_eq!(
+                        value.get("offset_bytes").and_then(|value| value.as_u64()),
+                        Some(10)
+                    );
+                    break;
+                }
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("tail output timeout");
+
+    timeout(Duration::from_secs(2), async {
+        loop {
+            let uri = format!("/tasks/{utf8_task_id}/output?stream=stdout&tail_bytes=3");
+            if let Some(value) = fetch(&app, uri).await {
+                if value.get("total_bytes").and_then(|value| value.as_u64()) == Some(5) {
+                    assert_eq!(
+                        value.get("content").and_then(|value| value.as_str()),
+                        Some("\u{e9}")
+                    );
+                    assert_eq!(
+                        value.get("offset_bytes").and_then(|value| value.as_u64()),
+                        Some(3)
+                    );
+                    break;
+                }
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("utf8 tail output timeout");
+
+    let both = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!(
+                    "/tasks/{task_id}/output?stream=stdout&tail_bytes=10&offset_bytes=0"
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(both.status(), axum::http::StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn task_output_fetches_stderr_stream() {
     let dir = tempdir().expect("tmp");
@@ -612,7 +836,7 @@ async fn task_output_returns_bad_request_when_artifact_missing() {
 async fn cancel_task_emits_cancelled_status() {
     let dir = tempdir().expect("tmp");
     let app = build_test_app(&dir);
-    let task_id = create_task_id(&app, "sleep 5").await;
+    let task_id = create_task_id(&app, "sleep 1").await;
 
     let response = app
         .clone()
@@ -669,12 +893,12 @@ async fn cancel_task_emits_cancelled_status() {
                 match value.get("type").and_then(|value| value.as_str()) {
                     Some("tool_task_cancel_requested") => saw_cancel_requested = true,
                     Some("tool_task_cancelled") => saw_cancelled = true,
-                    Some("tool_task_status") => {
-                        if value.get("status").and_then(|value| value.as_str()) == Some("cancelled")
-                        {
-                            saw_terminal = true;
-                            break;
-                        }
+                    Some("tool_task_status")
+                        if value.get("status").and_then(|value| value.as_str())
+                            == Some("cancelled") =>
+                    {
+                        saw_terminal = true;
+                        break;
                     }
                     _ => {}
                 }
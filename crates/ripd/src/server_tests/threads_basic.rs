@@ -203,6 +203,14 @@ async fn thread_stream_events_emits_created_and_message() {
             let Some(value) = extract_data_json(&message) else {
                 continue;
             };
+            assert_eq!(
+                value.get("stream_kind").and_then(|value| value.as_str()),
+                Some("continuity")
+            );
+            assert_eq!(
+                value.get("stream_id").and_then(|value| value.as_str()),
+                Some(thread_id.as_str())
+            );
             if value.get("type").and_then(|value| value.as_str())
                 == Some("continuity_message_appended")
                 && value.get("content").and_then(|value| value.as_str()) == Some("hello")
@@ -349,3 +357,201 @@ async fn thread_post_message_preserves_actor_and_origin() {
     assert!(saw_run_spawned, "expected continuity_run_spawned");
     assert!(saw_run_ended, "expected continuity_run_ended");
 }
+
+#[tokio::test]
+async fn thread_list_messages_returns_ordered_messages_with_ordinals() {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app(&dir);
+    let thread_id = ensure_thread_id(&app).await;
+
+    let first = post_thread_message(&app, &thread_id, "first").await;
+    let second = post_thread_message(&app, &thread_id, "second").await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/threads/{thread_id}/messages"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    let body = response
+        .into_body()
+        .collect()
+        .await
+        .expect("body")
+        .to_bytes();
+    let messages: Vec<ThreadMessage> = serde_json::from_slice(&body).expect("json");
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].message_id, first.message_id);
+    assert_eq!(messages[0].ordinal, 1);
+    assert_eq!(messages[0].content, "first");
+    assert_eq!(messages[0].actor_id, "user");
+    assert_eq!(messages[0].origin, "server");
+    assert_eq!(messages[1].message_id, second.message_id);
+    assert_eq!(messages[1].ordinal, 2);
+    assert_eq!(messages[1].content, "second");
+    assert!(messages[0].seq < messages[1].seq);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/threads/{thread_id}/messages?from_ordinal=2"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    let body = response
+        .into_body()
+        .collect()
+        .await
+        .expect("body")
+        .to_bytes();
+    let messages: Vec<ThreadMessage> = serde_json::from_slice(&body).expect("json");
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].ordinal, 2);
+    assert_eq!(messages[0].content, "second");
+}
+
+#[tokio::test]
+async fn thread_cancel_cancels_every_active_linked_session() {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app(&dir);
+    let thread_id = ensure_thread_id(&app).await;
+    let sleeper = r#"{"tool":"bash","args":{"command":"sleep 5"}}"#;
+    let first = post_thread_message(&app, &thread_id, sleeper).await;
+    let second = post_thread_message(&app, &thread_id, sleeper).await;
+    let unlinked = create_session_id(&app).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/threads/{thread_id}/cancel"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    let body = response
+        .into_body()
+        .collect()
+        .await
+        .expect("body")
+        .to_bytes();
+    let cancelled: ThreadCancelResponse = serde_json::from_slice(&body).expect("json");
+    assert_eq!(cancelled.thread_id, thread_id);
+    let mut expected = vec![first.session_id, second.session_id];
+    expected.sort();
+    assert_eq!(cancelled.cancelled_session_ids, expected);
+
+    let remaining = list_sessions(&app, "").await;
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].session_id, unlinked);
+
+    let missing = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/threads/missing/cancel")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(missing.status(), axum::http::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn thread_post_message_replays_idempotency_key() {
+    let dir = tempdir().expect("tmp");
+    let app = build_test_app(&dir);
+    let thread_id = ensure_thread_id(&app).await;
+
+    let post_with_key = |key: &'static str| {
+        let app = app.clone();
+        let thread_id = thread_id.clone();
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("/threads/{thread_id}/messages"))
+                        .header("content-type", "application/json")
+                        .header("idempotency-key", key)
+                        .body(Body::from("{\"content\":\"hello\"}"))
+                        .unwrap(),
+                )
+                .await
+                .expect("response");
+            assert_eq!(response.status(), axum::http::StatusCode::ACCEPTED);
+            let body = response
+                .into_body()
+                .collect()
+                .await
+                .expect("body")
+                .to_bytes();
+            serde_json::from_slice::<ThreadPostMessageResponse>(&body).expect("json")
+        }
+    };
+
+    let first = post_with_key("retry-1").await;
+    let retried = post_with_key("retry-1").await;
+    assert_eq!(retried.session_id, first.session_id);
+    assert_eq!(retried.message_id, first.message_id);
+    assert_eq!(list_sessions(&app, "").await.len(), 1);
+
+    let other = post_with_key("retry-2").await;
+    assert_ne!(other.session_id, first.session_id);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/threads/{thread_id}/messages"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    let body = response
+        .into_body()
+        .collect()
+        .await
+        .expect("body")
+        .to_bytes();
+    let messages: Vec<ThreadMessage> = serde_json::from_slice(&body).expect("json");
+    assert_eq!(messages.len(), 2);
+    assert_eq!(list_sessions(&app, "").await.len(), 2);
+
+    let mismatched = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/threads/{thread_id}/messages"))
+                .header("content-type", "application/json")
+                .header("idempotency-key", "retry-1")
+                .body(Body::from("{\"content\":\"something else\"}"))
+                .unwrap(),
+        )
+        .await
+        .expect("response");
+    assert_eq!(
+        mismatched.status(),
+        axum::http::StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(list_sessions(&app, "").await.len(), 2);
+}
@@ -216,30 +216,30 @@ async fn thread_compaction_auto_spawns_job_and_emits_checkpoint_and_job_ended()
                 continue;
             };
             match value.get("type").and_then(|value| value.as_str()) {
-                Some("continuity_job_spawned") => {
-                    if value.get("job_id").and_then(|v| v.as_str()) == Some(job_id) {
-                        assert_eq!(
-                            value.get("actor_id").and_then(|v| v.as_str()),
-                            Some("alice")
-                        );
-                        assert_eq!(value.get("origin").and_then(|v| v.as_str()), Some("cli"));
-                        saw_spawned = true;
-                    }
+                Some("continuity_job_spawned")
+                    if value.get("job_id").and_then(|v| v.as_str()) == Some(job_id) =>
+                {
+                    assert_eq!(
+                        value.get("actor_id").and_then(|v| v.as_str()),
+                        Some("alice")
+                    );
+                    assert_eq!(value.get("origin").and_then(|v| v.as_str()), Some("cli"));
+                    saw_spawned = true;
                 }
-                Some("continuity_compaction_checkpoint_created") => {
-                    if value.get("to_message_id").and_then(|v| v.as_str()) == Some(m2.as_str()) {
-                        saw_checkpoint = true;
-                    }
+                Some("continuity_compaction_checkpoint_created")
+                    if value.get("to_message_id").and_then(|v| v.as_str()) == Some(m2.as_str()) =>
+                {
+                    saw_checkpoint = true;
                 }
-                Some("continuity_job_ended") => {
-                    if value.get("job_id").and_then(|v| v.as_str()) == Some(job_id) {
-                        assert_eq!(
-                            value.get("status").and_then(|v| v.as_str()),
-                            Some("completed")
-                        );
-                        saw_job_ended = true;
-                        break;
-                    }
+                Some("continuity_job_ended")
+                    if value.get("job_id").and_then(|v| v.as_str()) == Some(job_id) =>
+                {
+                    assert_eq!(
+                        value.get("status").and_then(|v| v.as_str()),
+                        Some("completed")
+                    );
+                    saw_job_ended = true;
+                    break;
                 }
                 _ => {}
             }
@@ -483,39 +483,39 @@ async fn thread_compaction_auto_schedule_spawns_job_emits_decision_and_completes
                 continue;
             };
             match value.get("type").and_then(|value| value.as_str()) {
-                Some("continuity_compaction_auto_schedule_decided") => {
-                    if value.get("decision_id").and_then(|v| v.as_str()) == Some(decision_id) {
-                        assert_eq!(
-                            value.get("actor_id").and_then(|v| v.as_str()),
-                            Some("alice")
-                        );
-                        assert_eq!(value.get("origin").and_then(|v| v.as_str()), Some("cli"));
-                        assert_eq!(
-                            value.get("decision").and_then(|v| v.as_str()),
-                            Some("scheduled")
-                        );
-                        saw_decision = true;
-                    }
+                Some("continuity_compaction_auto_schedule_decided")
+                    if value.get("decision_id").and_then(|v| v.as_str()) == Some(decision_id) =>
+                {
+                    assert_eq!(
+                        value.get("actor_id").and_then(|v| v.as_str()),
+                        Some("alice")
+                    );
+                    assert_eq!(value.get("origin").and_then(|v| v.as_str()), Some("cli"));
+                    assert_eq!(
+                        value.get("decision").and_then(|v| v.as_str()),
+                        Some("scheduled")
+                    );
+                    saw_decision = true;
                 }
-                Some("continuity_job_spawned") => {
-                    if value.get("job_id").and_then(|v| v.as_str()) == Some(job_id) {
-                        saw_spawned = true;
-                    }
+                Some("continuity_job_spawned")
+                    if value.get("job_id").and_then(|v| v.as_str()) == Some(job_id) =>
+                {
+                    saw_spawned = true;
                 }
-                Some("continuity_compaction_checkpoint_created") => {
-                    if value.get("to_message_id").and_then(|v| v.as_str()) == Some(m2.as_str()) {
-                        saw_checkpoint = true;
-                    }
+                Some("continuity_compaction_checkpoint_created")
+                    if value.get("to_message_id").and_then(|v| v.as_str()) == Some(m2.as_str()) =>
+                {
+                    saw_checkpoint = true;
                 }
-                Some("continuity_job_ended") => {
-                    if value.get("job_id").and_then(|v| v.as_str()) == Some(job_id) {
-                        assert_eq!(
-                            value.get("status").and_then(|v| v.as_str()),
-                            Some("completed")
-                        );
-                        saw_job_ended = true;
-                        break;
-                    }
+                Some("continuity_job_ended")
+                    if value.get("job_id").and_then(|v| v.as_str()) == Some(job_id) =>
+                {
+                    assert_eq!(
+                        value.get("status").and_then(|v| v.as_str()),
+                        Some("completed")
+                    );
+                    saw_job_ended = true;
+                    break;
                 }
                 _ => {}
             }
@@ -35,7 +35,7 @@ use crate::continuities::{
 };
 use crate::provider_openresponses::{
     build_streaming_followup_request, build_streaming_request, build_streaming_request_items,
-    OpenResponsesConfig, DEFAULT_MAX_TOOL_CALLS,
+    disallowed_tool_types, OpenResponsesConfig, DEFAULT_MAX_TOOL_CALLS,
 };
 use crate::workspace_lock::{requires_workspace_lock, WorkspaceLock};
 
@@ -97,6 +97,10 @@ pub struct SessionContext {
     pub continuities: Arc<ContinuityStore>,
     pub continuity_run: Option<ContinuityRunLink>,
     pub server_session_id: String,
+    pub preamble: Vec<ItemParam>,
+    pub stream_resume_retries: u32,
+    /// Force the OpenResponses request artifact dump on for this run.
+    pub request_dump: bool,
     pub input: String,
 }
 
@@ -114,9 +118,14 @@ pub async fn run_session(context: SessionContext) {
         continuities,
         continuity_run,
         server_session_id,
+        preamble,
+        stream_resume_retries,
+        request_dump,
         input,
     } = context;
     let mut session = runtime.start_session_with_id(server_session_id.clone(), input.clone());
+    // A queued session already holds `session_queued` at seq 0.
+    session.resume_from_events(&events.lock().await);
     let action = parse_action(&input);
     let runtime_session_id = session.id().to_string();
     let mut skip_runtime_loop = false;
@@ -176,7 +185,8 @@ pub async fn run_session(context: SessionContext) {
         InputAction::Prompt => {
             if let Some(config) = &openresponses {
                 let mut seq = session.seq();
-                let sink = EventSink::new(&sender, &events, event_log.as_ref());
+                let sink = EventSink::new(&sender, &events, event_log.as_ref())
+                    .with_run_link(continuity_run.as_ref());
                 let mut initial_items: Option<Vec<ItemParam>> = None;
                 if let Some(link) = continuity_run.as_ref() {
                     match compile_context_bundle_for_run(
@@ -214,6 +224,9 @@ pub async fn run_session(context: SessionContext) {
                                     compiler_strategy: decision.compiler_strategy,
                                     from_seq: compiled.from_seq,
                                     from_message_id: compiled.from_message_id,
+                                    included_messages: Some(compiled.included_messages),
+                                    included_tokens: Some(compiled.included_tokens),
+                                    dropped_messages: Some(compiled.dropped_messages),
                                     actor_id: link.actor_id.clone(),
                                     origin: link.origin.clone(),
                                 },
@@ -240,6 +253,12 @@ pub async fn run_session(context: SessionContext) {
                         }
                     }
                 }
+                if !skip_runtime_loop && !preamble.is_empty() {
+                    let items = initial_items
+                        .take()
+                        .unwrap_or_else(|| vec![ItemParam::user_message_text(input.as_str())]);
+                    initial_items = Some(preamble.into_iter().chain(items).collect());
+                }
                 if !skip_runtime_loop {
                     let outcome = run_openresponses_agent_loop(OpenResponsesRunContext {
                         http: &http_client,
@@ -253,6 +272,8 @@ pub async fn run_session(context: SessionContext) {
                         prompt: &input,
                         seq: &mut seq,
                         sink,
+                        stream_resume_retries,
+                        request_dump,
                     })
                     .await;
                     let OpenResponsesLoopOutcome {
@@ -303,6 +324,7 @@ pub async fn run_session(context: SessionContext) {
 
     if !skip_runtime_loop {
         while let Some(event) = session.next_event() {
+            let event = attribute_output(event, continuity_run.as_ref());
             emit_event(event, &sender, &events, &event_log).await;
         }
     }
@@ -353,6 +375,22 @@ fn parse_action(input: &str) -> InputAction {
     InputAction::Prompt
 }
 
+/// Stamps the run link's actor/origin onto output frames so UIs can label
+/// output by agent in multi-actor continuities.
+fn attribute_output(mut event: Event, link: Option<&ContinuityRunLink>) -> Event {
+    if let (
+        Some(link),
+        EventKind::OutputTextDelta {
+            actor_id, origin, ..
+        },
+    ) = (link, &mut event.kind)
+    {
+        actor_id.get_or_insert_with(|| link.actor_id.clone());
+        origin.get_or_insert_with(|| link.origin.clone());
+    }
+    event
+}
+
 async fn emit_events(
     events: Vec<Event>,
     sender: &broadcast::Sender<Event>,
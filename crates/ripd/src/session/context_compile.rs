@@ -31,6 +31,29 @@ pub(super) struct CompiledContextForRun {
     pub(super) items: Vec<ItemParam>,
     pub(super) from_seq: u64,
     pub(super) from_message_id: Option<String>,
+    pub(super) included_messages: u64,
+    pub(super) included_tokens: u64,
+    pub(super) dropped_messages: u64,
+}
+
+/// Rough provider-agnostic token estimate (~4 chars per token) for the text
+/// of compiled message items. Content may be a plain string or an array of
+/// content parts; only their `text` fields are counted.
+fn estimate_item_tokens(items: &[ItemParam]) -> u64 {
+    let chars: usize = items
+        .iter()
+        .filter_map(|item| item.value().get("content"))
+        .map(|content| match content {
+            Value::String(text) => text.chars().count(),
+            Value::Array(parts) => parts
+                .iter()
+                .filter_map(|part| part.get("text").and_then(Value::as_str))
+                .map(|text| text.chars().count())
+                .sum(),
+            _ => 0,
+        })
+        .sum();
+    (chars as u64).div_ceil(4)
 }
 
 pub(super) struct ContextSelectionDecisionForRun {
@@ -199,6 +222,11 @@ pub(super) fn compile_context_bundle_for_run(
 
     let artifact_id = write_bundle_v1(continuities.workspace_root(), &bundle)?;
     let items = openresponses_items_from_context_bundle(continuities.workspace_root(), &bundle)?;
+    let included_messages = bundle
+        .items()
+        .iter()
+        .filter(|item| matches!(item, ContextBundleItemV1::Message { .. }))
+        .count() as u64;
     Ok(ContextCompileOutcomeForRun {
         decision: ContextSelectionDecisionForRun {
             compiler_id: CONTEXT_COMPILER_ID_V1.to_string(),
@@ -211,9 +239,12 @@ pub(super) fn compile_context_bundle_for_run(
         },
         compiled: CompiledContextForRun {
             bundle_artifact_id: artifact_id,
-            items,
             from_seq: input.from_seq,
             from_message_id: input.from_message_id,
+            included_messages,
+            included_tokens: estimate_item_tokens(&items),
+            dropped_messages: input.available_messages.saturating_sub(included_messages),
+            items,
         },
     })
 }
@@ -348,6 +379,114 @@ mod tests {
         assert!(err.contains("artifact read failed"));
     }
 
+    #[test]
+    fn compile_context_bundle_for_run_reports_included_and_dropped_counts() {
+        let dir = tempdir().expect("tmp");
+        let (event_log, store, _data_dir) = continuity_store_for_context_compile(&dir);
+        let snapshot_dir = dir.path().join("snapshots");
+        std::fs::create_dir_all(&snapshot_dir).expect("snapshots");
+
+        let continuity_id = store.ensure_default().expect("ensure");
+        let total = RECENT_MESSAGES_V1_LIMIT + 2;
+        let mut last_message_id = String::new();
+        for _ in 0..total {
+            last_message_id = store
+                .append_message(
+                    &continuity_id,
+                    "alice".to_string(),
+                    "cli".to_string(),
+                    "abcdefgh".to_string(),
+                )
+                .expect("append");
+        }
+
+        let outcome = compile_context_bundle_for_run(
+            &store,
+            &event_log,
+            &snapshot_dir,
+            &ContinuityRunLink {
+                continuity_id,
+                message_id: last_message_id,
+                actor_id: "alice".to_string(),
+                origin: "cli".to_string(),
+            },
+            "run-1",
+        )
+        .expect("compile");
+
+        assert_eq!(outcome.decision.compiler_strategy, "recent_messages_v1");
+        let compiled = &outcome.compiled;
+        assert_eq!(compiled.items.len(), RECENT_MESSAGES_V1_LIMIT);
+        assert_eq!(compiled.included_messages, RECENT_MESSAGES_V1_LIMIT as u64);
+        assert_eq!(compiled.dropped_messages, 2);
+        assert_eq!(
+            compiled.included_tokens,
+            2 * RECENT_MESSAGES_V1_LIMIT as u64
+        );
+    }
+
+    #[test]
+    fn compile_context_bundle_for_run_counts_dropped_messages_beyond_the_tail_window() {
+        let dir = tempdir().expect("tmp");
+        let (event_log, store, _data_dir) = continuity_store_for_context_compile(&dir);
+        let snapshot_dir = dir.path().join("snapshots");
+        std::fs::create_dir_all(&snapshot_dir).expect("snapshots");
+
+        // ~1 MiB of messages: more than the initial tail scan reads, so the
+        // compile input only holds a suffix of the continuity.
+        let continuity_id = store.ensure_default().expect("ensure");
+        let total = 256;
+        let body = "x".repeat(4096);
+        let mut last_message_id = String::new();
+        for _ in 0..total {
+            last_message_id = store
+                .append_message(
+                    &continuity_id,
+                    "alice".to_string(),
+                    "cli".to_string(),
+                    body.clone(),
+                )
+                .expect("append");
+        }
+
+        let outcome = compile_context_bundle_for_run(
+            &store,
+            &event_log,
+            &snapshot_dir,
+            &ContinuityRunLink {
+                continuity_id,
+                message_id: last_message_id,
+                actor_id: "alice".to_string(),
+                origin: "cli".to_string(),
+            },
+            "run-1",
+        )
+        .expect("compile");
+
+        let compiled = &outcome.compiled;
+        assert_eq!(compiled.included_messages, RECENT_MESSAGES_V1_LIMIT as u64);
+        assert_eq!(
+            compiled.dropped_messages,
+            (total - RECENT_MESSAGES_V1_LIMIT) as u64
+        );
+    }
+
+    #[test]
+    fn estimate_item_tokens_counts_array_content_parts() {
+        let items = vec![
+            ItemParam::message_text("user", "abcd"),
+            ItemParam::new(serde_json::json!({
+                "type": "message",
+                "role": "user",
+                "content": [
+                    { "type": "input_text", "text": "abcdefgh" },
+                    { "type": "input_image", "image_url": "https://example.com/a.png" },
+                ],
+            })),
+        ];
+        assert_eq!(estimate_item_tokens(&items), 3);
+    }
+
     #[test]
     fn compile_context_bundle_for_run_ignores_unsupported_checkpoint_kind_and_records_reset() {
         let dir = tempdir().expect("tmp");
@@ -17,6 +17,8 @@ pub(super) struct OpenResponsesRunContext<'a> {
     pub(super) prompt: &'a str,
     pub(super) seq: &'a mut u64,
     pub(super) sink: EventSink<'a>,
+    pub(super) stream_resume_retries: u32,
+    pub(super) request_dump: bool,
 }
 
 pub(super) struct OpenResponsesLoopOutcome {
@@ -145,6 +147,8 @@ pub(super) async fn run_openresponses_agent_loop(
         prompt,
         seq,
         sink,
+        stream_resume_retries,
+        request_dump,
     } = ctx;
     let mut previous_response_id: Option<String> = None;
     let mut followup_tool_outputs: Option<Vec<ItemParam>> = None;
@@ -159,7 +163,7 @@ pub(super) async fn run_openresponses_agent_loop(
     let include = compat.include(&config.include);
     let reasoning = compat.reasoning(config.reasoning.as_ref());
     let web_search = compat.web_search(config.web_search.as_ref());
-    let compat_warnings: Vec<String> = conversation
+    let mut compat_warnings: Vec<String> = conversation
         .warnings
         .iter()
         .chain(include.warnings.iter())
@@ -167,7 +171,25 @@ pub(super) async fn run_openresponses_agent_loop(
         .chain(web_search.warnings.iter())
         .cloned()
         .collect();
+    let removed_tool_types = disallowed_tool_types(config);
+    if !removed_tool_types.is_empty() {
+        let verb = if config.reject_disallowed_tool_types {
+            "rejected"
+        } else {
+            "dropped"
+        };
+        compat_warnings.push(format!(
+            "{verb} tool types not in allowed_tool_types: {}",
+            removed_tool_types.join(", ")
+        ));
+    }
     emit_compat_warnings(sink, session_id, seq, compat_warnings).await;
+    if config.reject_disallowed_tool_types && !removed_tool_types.is_empty() {
+        return OpenResponsesLoopOutcome {
+            reason: "disallowed_tool_types".to_string(),
+            last_response_id: None,
+        };
+    }
     let stateless_history = matches!(
         conversation.effective,
         crate::openresponses_compat::ConversationStrategy::StatelessHistory
@@ -235,6 +257,8 @@ pub(super) async fn run_openresponses_agent_loop(
             seq,
             sink,
             collector: &mut collector,
+            resume_retries: stream_resume_retries,
+            request_dump,
         })
         .await;
         request_index = request_index.saturating_add(1);
@@ -345,18 +369,16 @@ async fn emit_compat_warnings(
             session_id: session_id.to_string(),
             timestamp_ms: super::now_ms(),
             seq: *seq,
-            kind: EventKind::ProviderEvent {
-                provider: "openresponses.compat".to_string(),
-                status: rip_kernel::ProviderEventStatus::Event,
-                event_name: Some("rip.compat.warning".to_string()),
-                data: Some(serde_json::json!({
-                    "type": "rip.compat.warning",
-                    "message": warning,
-                })),
-                raw: None,
-                errors: Vec::new(),
-                response_errors: Vec::new(),
-            },
+            kind: rip_kernel::ProviderEventBuilder::new(
+                "openresponses.compat",
+                rip_kernel::ProviderEventStatus::Event,
+            )
+            .event_name("rip.compat.warning")
+            .data(serde_json::json!({
+                "type": "rip.compat.warning",
+                "message": warning,
+            }))
+            .build(),
         })
         .await;
         *seq += 1;
@@ -374,6 +396,21 @@ pub(super) struct OpenResponsesStreamRequest<'a> {
     pub(super) seq: &'a mut u64,
     pub(super) sink: EventSink<'a>,
     pub(super) collector: &'a mut ToolCallCollector,
+    pub(super) resume_retries: u32,
+    /// Dump the request body even when the env config leaves dumping off.
+    pub(super) request_dump: bool,
+}
+
+/// Wait before resuming a dropped stream when the provider sent no `retry:` hint.
+const DEFAULT_RESUME_BACKOFF_MS: u64 = 250;
+/// Ceiling on a provider-supplied `retry:` hint so a hostile or buggy value
+/// cannot park the run indefinitely.
+pub(super) const MAX_RESUME_BACKOFF_MS: u64 = 5_000;
+
+pub(super) fn resume_backoff_ms(retry_hint_ms: Option<u64>) -> u64 {
+    retry_hint_ms
+        .unwrap_or(DEFAULT_RESUME_BACKOFF_MS)
+        .min(MAX_RESUME_BACKOFF_MS)
 }
 
 pub(super) async fn stream_openresponses_request<'a>(
@@ -388,22 +425,21 @@ pub(super) async fn stream_openresponses_request<'a>(
                 session_id: req.session_id.to_string(),
                 timestamp_ms: super::now_ms(),
                 seq: *req.seq,
-                kind: rip_kernel::EventKind::ProviderEvent {
-                    provider: "openresponses".to_string(),
-                    status: rip_kernel::ProviderEventStatus::Event,
-                    event_name: None,
-                    data: None,
-                    raw: Some(req.payload.body().to_string()),
-                    errors: req.payload.errors().to_vec(),
-                    response_errors: Vec::new(),
-                },
+                kind: rip_kernel::ProviderEventBuilder::new(
+                    "openresponses",
+                    rip_kernel::ProviderEventStatus::Event,
+                )
+                .raw(req.payload.body().to_string())
+                .errors(req.payload.errors().to_vec())
+                .build(),
             })
             .await;
         *req.seq += 1;
         return Err("invalid_request".to_string());
     }
 
-    let request_dump_cfg = crate::openresponses_observability::request_dump_config_from_env();
+    let mut request_dump_cfg = crate::openresponses_observability::request_dump_config_from_env();
+    request_dump_cfg.enabled |= req.request_dump;
     if let Some(event) = crate::openresponses_observability::maybe_dump_openresponses_request(
         request_dump_cfg,
         crate::openresponses_observability::OpenResponsesRequestDumpInput {
@@ -538,18 +574,70 @@ pub(super) async fn stream_openresponses_request<'a>(
         validation,
     );
     let mut saw_done = pipe.push_bytes(&mut utf8_buf, &first_chunk).await;
-    while !saw_done {
-        let Some(next) = stream.next().await else {
+    let mut resumes_left = req.resume_retries;
+    loop {
+        let mut disconnect: Option<String> = None;
+        while !saw_done {
+            let Some(next) = stream.next().await else {
+                break;
+            };
+            match next {
+                Ok(chunk) => saw_done = pipe.push_bytes(&mut utf8_buf, &chunk).await,
+                Err(err) => {
+                    disconnect = Some(err.to_string());
+                    break;
+                }
+            }
+        }
+        if saw_done || pipe.saw_terminal_response() {
+            break;
+        }
+
+        let resume_from = pipe.response_id().map(|id| id.to_string());
+        let (Some(response_id), true) = (resume_from, resumes_left > 0) else {
+            if let Some(err) = disconnect {
+                pipe.emit_transport_error(err).await;
+                return Err("provider_error".to_string());
+            }
             break;
         };
-        let chunk = match next {
-            Ok(chunk) => chunk,
+        resumes_left -= 1;
+        let delay_ms = resume_backoff_ms(pipe.suggested_retry_ms());
+        tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+
+        let mut resume = req
+            .http
+            .get(format!(
+                "{}/{response_id}",
+                req.config.endpoint.trim_end_matches('/')
+            ))
+            .query(&[("stream", "true")]);
+        if let Some(after) = pipe.last_sequence_number() {
+            resume = resume.query(&[("starting_after", after)]);
+        }
+        if let Some(key) = req.config.api_key.as_deref() {
+            resume = resume.bearer_auth(key);
+        }
+        for (name, value) in &req.config.headers {
+            resume = resume.header(name, value);
+        }
+        let resumed = match resume.send().await {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                pipe.emit_transport_error(format!("provider resume http error: {status}: {body}"))
+                    .await;
+                return Err("provider_error".to_string());
+            }
             Err(err) => {
                 pipe.emit_transport_error(err.to_string()).await;
                 return Err("provider_error".to_string());
             }
         };
-        saw_done = pipe.push_bytes(&mut utf8_buf, &chunk).await;
+        utf8_buf.clear();
+        pipe.prepare_resume();
+        stream = resumed.bytes_stream();
     }
 
     if !saw_done {
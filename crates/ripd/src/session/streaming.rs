@@ -1,10 +1,13 @@
 use super::*;
 
+pub(super) use rip_provider_openresponses::tool_events_to_function_call_output;
+
 #[derive(Clone, Copy)]
 pub(super) struct EventSink<'a> {
     sender: &'a broadcast::Sender<Event>,
     buffer: &'a Arc<Mutex<Vec<Event>>>,
     event_log: &'a EventLog,
+    run_link: Option<&'a ContinuityRunLink>,
 }
 
 impl<'a> EventSink<'a> {
@@ -17,14 +20,26 @@ impl<'a> EventSink<'a> {
             sender,
             buffer,
             event_log,
+            run_link: None,
         }
     }
 
+    /// Attributes emitted output frames to the linked continuity actor.
+    pub(super) fn with_run_link(mut self, run_link: Option<&'a ContinuityRunLink>) -> Self {
+        self.run_link = run_link;
+        self
+    }
+
     pub(super) async fn emit(self, event: Event) {
+        let event = super::attribute_output(event, self.run_link);
         super::emit_event(event, self.sender, self.buffer, self.event_log).await;
     }
 
     pub(super) async fn emit_all(self, events: Vec<Event>) {
+        let events = events
+            .into_iter()
+            .map(|event| super::attribute_output(event, self.run_link))
+            .collect();
         super::emit_events(events, self.sender, self.buffer, self.event_log).await;
     }
 }
@@ -37,6 +52,11 @@ pub(super) struct OpenResponsesSsePipe<'a> {
     seq: &'a mut u64,
     sink: EventSink<'a>,
     collector: Option<&'a mut ToolCallCollector>,
+    validation: ValidationOptions,
+    last_sequence_number: Option<u64>,
+    resume_after: Option<u64>,
+    saw_terminal_response: bool,
+    retry_ms: Option<u64>,
 }
 
 impl<'a> OpenResponsesSsePipe<'a> {
@@ -55,9 +75,46 @@ impl<'a> OpenResponsesSsePipe<'a> {
             seq,
             sink,
             collector,
+            validation,
+            last_sequence_number: None,
+            resume_after: None,
+            saw_terminal_response: false,
+            retry_ms: None,
         }
     }
 
+    /// Highest provider `sequence_number` observed so far.
+    pub(super) fn last_sequence_number(&self) -> Option<u64> {
+        self.last_sequence_number
+    }
+
+    pub(super) fn response_id(&self) -> Option<&str> {
+        self.collector
+            .as_deref()
+            .and_then(|collector| collector.response_id.as_deref())
+    }
+
+    /// Server-suggested reconnect delay (`retry:`), kept across resumes.
+    pub(super) fn suggested_retry_ms(&self) -> Option<u64> {
+        self.decoder.suggested_retry_ms().or(self.retry_ms)
+    }
+
+    /// Whether a `response.completed`/`failed`/`incomplete` event was seen.
+    pub(super) fn saw_terminal_response(&self) -> bool {
+        self.saw_terminal_response
+    }
+
+    /// Prepares the pipe for a resumed stream: drops partially decoded input,
+    /// re-seeds the mapper at the current seq, and skips replayed events at or
+    /// below the last observed `sequence_number`.
+    pub(super) fn prepare_resume(&mut self) {
+        self.retry_ms = self.suggested_retry_ms();
+        self.decoder = SseDecoder::new_with_validation(self.validation);
+        self.mapper = EventFrameMapper::new(self.session_id.clone()).with_seq(*self.seq);
+        self.seq_offset = 0;
+        self.resume_after = self.last_sequence_number;
+    }
+
     pub(super) async fn emit_transport_error(&mut self, error: String) {
         self.sink
             .emit(Event {
@@ -65,15 +122,12 @@ impl<'a> OpenResponsesSsePipe<'a> {
                 session_id: self.session_id.clone(),
                 timestamp_ms: super::now_ms(),
                 seq: *self.seq,
-                kind: rip_kernel::EventKind::ProviderEvent {
-                    provider: "openresponses".to_string(),
-                    status: rip_kernel::ProviderEventStatus::Event,
-                    event_name: None,
-                    data: None,
-                    raw: None,
-                    errors: vec![error],
-                    response_errors: Vec::new(),
-                },
+                kind: rip_kernel::ProviderEventBuilder::new(
+                    "openresponses",
+                    rip_kernel::ProviderEventStatus::Event,
+                )
+                .errors(vec![error])
+                .build(),
             })
             .await;
         *self.seq += 1;
@@ -85,8 +139,37 @@ impl<'a> OpenResponsesSsePipe<'a> {
             return false;
         }
 
+        let frames = self.observe_and_map(&parsed);
+        let frame_count = frames.len();
+
+        self.sink.emit_all(frames).await;
+        *self.seq += frame_count as u64;
+
+        parsed
+            .iter()
+            .any(|event| event.kind == ParsedEventKind::Done)
+    }
+
+    fn observe_and_map(&mut self, parsed: &[ParsedEvent]) -> Vec<Event> {
         let mut frames = Vec::new();
-        for event in &parsed {
+        for event in parsed {
+            let sequence_number = event
+                .data
+                .as_ref()
+                .and_then(|data| data.get("sequence_number"))
+                .and_then(|value| value.as_u64());
+            if let Some(sequence_number) = sequence_number {
+                if self
+                    .resume_after
+                    .is_some_and(|after| sequence_number <= after)
+                {
+                    continue;
+                }
+                self.last_sequence_number = Some(sequence_number);
+            }
+            if event.is_terminal() {
+                self.saw_terminal_response = true;
+            }
             if let Some(collector) = self.collector.as_deref_mut() {
                 collector.observe(event);
             }
@@ -95,14 +178,7 @@ impl<'a> OpenResponsesSsePipe<'a> {
         for frame in &mut frames {
             frame.seq += self.seq_offset;
         }
-        let frame_count = frames.len();
-
-        self.sink.emit_all(frames).await;
-        *self.seq += frame_count as u64;
-
-        parsed
-            .iter()
-            .any(|event| event.kind == ParsedEventKind::Done)
+        frames
     }
 
     pub(super) async fn push_bytes(&mut self, utf8_buf: &mut Vec<u8>, bytes: &[u8]) -> bool {
@@ -166,16 +242,7 @@ impl<'a> OpenResponsesSsePipe<'a> {
             return false;
         }
 
-        let mut frames = Vec::new();
-        for event in &parsed {
-            if let Some(collector) = self.collector.as_deref_mut() {
-                collector.observe(event);
-            }
-            frames.extend(self.mapper.map(event));
-        }
-        for frame in &mut frames {
-            frame.seq += self.seq_offset;
-        }
+        let frames = self.observe_and_map(&parsed);
         let frame_count = frames.len();
         let saw_done = parsed
             .iter()
@@ -377,49 +444,6 @@ impl ToolCallCollector {
     }
 }
 
-pub(super) fn tool_events_to_function_call_output(tool_name: &str, events: &[Event]) -> Value {
-    let mut stdout = String::new();
-    let mut stderr = String::new();
-    let mut exit_code: i32 = 1;
-    let mut artifacts: Option<Value> = None;
-    let mut tool_error: Option<String> = None;
-
-    for event in events {
-        match &event.kind {
-            EventKind::ToolStdout { chunk, .. } => stdout.push_str(chunk),
-            EventKind::ToolStderr { chunk, .. } => stderr.push_str(chunk),
-            EventKind::ToolEnded {
-                exit_code: code,
-                artifacts: tool_artifacts,
-                ..
-            } => {
-                exit_code = *code;
-                artifacts = tool_artifacts.clone();
-            }
-            EventKind::ToolFailed { error, .. } => tool_error = Some(error.clone()),
-            _ => {}
-        }
-    }
-
-    let ok = exit_code == 0 && tool_error.is_none();
-    let mut obj = serde_json::Map::new();
-    obj.insert("tool".to_string(), Value::String(tool_name.to_string()));
-    obj.insert("ok".to_string(), Value::Bool(ok));
-    obj.insert(
-        "exit_code".to_string(),
-        Value::Number(serde_json::Number::from(exit_code as i64)),
-    );
-    obj.insert("stdout".to_string(), Value::String(stdout));
-    obj.insert("stderr".to_string(), Value::String(stderr));
-    if let Some(artifacts) = artifacts {
-        obj.insert("artifacts".to_string(), artifacts);
-    }
-    if let Some(error) = tool_error {
-        obj.insert("error".to_string(), Value::String(error));
-    }
-    Value::Object(obj)
-}
-
 pub(super) fn summarize_continuity_tool_side_effects(events: &[Event]) -> Option<ToolSideEffects> {
     let (tool_id, tool_name) = events.iter().find_map(|event| match &event.kind {
         EventKind::ToolStarted { tool_id, name, .. } => Some((tool_id.clone(), name.clone())),
@@ -39,6 +39,7 @@ fn parsed_event(data: Value) -> ParsedEvent {
     ParsedEvent {
         kind: ParsedEventKind::Event,
         event: event_name,
+        id: None,
         raw: data.to_string(),
         data: Some(data),
         errors: Vec::new(),
@@ -579,7 +580,7 @@ async fn openresponses_pipe_emits_output_text_delta() {
     let events = buffer.lock().await;
     assert_eq!(events.len(), 2);
     match &events[1].kind {
-        EventKind::OutputTextDelta { delta } => assert_eq!(delta, "hi"),
+        EventKind::OutputTextDelta { delta, .. } => assert_eq!(delta, "hi"),
         _ => panic!("expected output_text_delta"),
     }
 }
@@ -650,6 +651,7 @@ async fn stream_openresponses_request_rejects_invalid_payload() {
         followup_user_message: None,
         stateless_history: false,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
     let payload = CreateResponsePayload::new(serde_json::json!({"input": {}}));
     let mut seq = 0;
@@ -666,6 +668,8 @@ async fn stream_openresponses_request_rejects_invalid_payload() {
         seq: &mut seq,
         sink,
         collector: &mut collector,
+        resume_retries: 0,
+        request_dump: false,
     })
     .await
     .unwrap_err();
@@ -696,6 +700,7 @@ fn validation_options_for_stream_uses_openrouter_compat_profile() {
         followup_user_message: None,
         stateless_history: false,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
 
     assert_eq!(
@@ -704,6 +709,15 @@ fn validation_options_for_stream_uses_openrouter_compat_profile() {
     );
 }
 
+#[test]
+fn resume_backoff_clamps_provider_retry_hint() {
+    use super::openresponses::{resume_backoff_ms, MAX_RESUME_BACKOFF_MS};
+
+    assert_eq!(resume_backoff_ms(Some(1_500)), 1_500);
+    assert_eq!(resume_backoff_ms(Some(u64::MAX)), MAX_RESUME_BACKOFF_MS);
+    assert!(resume_backoff_ms(None) <= MAX_RESUME_BACKOFF_MS);
+}
+
 #[test]
 fn validation_options_for_stream_prefers_provider_id_over_endpoint_heuristic() {
     let config = OpenResponsesConfig {
@@ -719,6 +733,7 @@ fn validation_options_for_stream_prefers_provider_id_over_endpoint_heuristic() {
         followup_user_message: None,
         stateless_history: false,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
 
     assert_eq!(
@@ -742,6 +757,7 @@ fn validation_options_for_stream_adds_missing_item_ids_for_stateless_history() {
         followup_user_message: None,
         stateless_history: true,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
 
     assert_eq!(
@@ -797,6 +813,7 @@ fn tool_call_collector_records_response_id_even_without_event_type() {
     let event = ParsedEvent {
         kind: ParsedEventKind::Event,
         event: None,
+        id: None,
         raw: "{\"response\":{\"id\":\"resp_1\"}}".to_string(),
         data: Some(serde_json::json!({"response": {"id": "resp_1"}})),
         errors: Vec::new(),
@@ -892,6 +909,7 @@ async fn stream_openresponses_request_reports_transport_error() {
         followup_user_message: None,
         stateless_history: false,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
     let payload = build_streaming_request(&config, "hi");
     assert!(payload.errors().is_empty());
@@ -909,6 +927,8 @@ async fn stream_openresponses_request_reports_transport_error() {
         seq: &mut seq,
         sink,
         collector: &mut collector,
+        resume_retries: 0,
+        request_dump: false,
     })
     .await
     .unwrap_err();
@@ -926,7 +946,6 @@ async fn stream_openresponses_request_reports_transport_error() {
         _ => panic!("expected provider_event"),
     }
 }
-
 #[tokio::test]
 async fn stream_openresponses_request_reports_http_error() {
     use axum::http::StatusCode;
@@ -962,6 +981,7 @@ async fn stream_openresponses_request_reports_http_error() {
         followup_user_message: None,
         stateless_history: false,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
     let payload = build_streaming_request(&config, "hi");
     assert!(payload.errors().is_empty());
@@ -979,6 +999,8 @@ async fn stream_openresponses_request_reports_http_error() {
         seq: &mut seq,
         sink,
         collector: &mut collector,
+        resume_retries: 0,
+        request_dump: false,
     })
     .await
     .unwrap_err();
@@ -1090,6 +1112,7 @@ data: [DONE]\n\n";
         followup_user_message: None,
         stateless_history: false,
         parallel_tool_calls: true,
+        ..OpenResponsesConfig::default()
     };
     let payload = build_streaming_request(&config, "hi");
     assert!(payload.errors().is_empty());
@@ -1108,6 +1131,8 @@ data: [DONE]\n\n";
         seq: &mut seq,
         sink,
         collector: &mut collector,
+        resume_retries: 0,
+        request_dump: false,
     })
     .await;
     assert_eq!(result, Ok(()));
@@ -1259,6 +1284,7 @@ data: [DONE]\n\n";
         followup_user_message: None,
         stateless_history: true,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
     let mut seq = 0;
     let http = reqwest::Client::new();
@@ -1274,6 +1300,8 @@ data: [DONE]\n\n";
         prompt: "hi",
         seq: &mut seq,
         sink,
+        stream_resume_retries: 0,
+        request_dump: false,
     })
     .await;
     assert_eq!(outcome.reason, "completed");
@@ -1284,6 +1312,129 @@ data: [DONE]\n\n";
         .any(|event| matches!(event.kind, EventKind::ToolStarted { .. })));
 }
 
+#[tokio::test]
+async fn run_openresponses_agent_loop_resumes_dropped_stream_from_last_sequence_number() {
+    use axum::body::{Body, Bytes};
+    use axum::extract::{Path as AxumPath, Query};
+    use axum::http::header::CONTENT_TYPE;
+    use axum::routing::{get, post};
+    use axum::Router as AxumRouter;
+    use tokio::net::TcpListener;
+
+    async fn create() -> impl axum::response::IntoResponse {
+        let head: Result<Bytes, std::io::Error> = Ok(Bytes::from(
+            "data: {\"type\":\"response.created\",\"sequence_number\":0,\"response\":{\"id\":\"resp_1\"}}\n\n\
+data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"delta\":\"he\"}\n\n",
+        ));
+        let dropped = futures_util::stream::once(async {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            Err(std::io::Error::other("connection reset"))
+        });
+        (
+            [(CONTENT_TYPE, "text/event-stream")],
+            Body::from_stream(futures_util::stream::iter([head]).chain(dropped)),
+        )
+    }
+
+    async fn resume(
+        AxumPath(id): AxumPath<String>,
+        Query(query): Query<HashMap<String, String>>,
+    ) -> impl axum::response::IntoResponse {
+        assert_eq!(id, "resp_1");
+        assert_eq!(query.get("stream").map(String::as_str), Some("true"));
+        assert_eq!(query.get("starting_after").map(String::as_str), Some("1"));
+        // Replays the last delivered event to exercise de-duplication.
+        let body = "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"delta\":\"he\"}\n\n\
+data: {\"type\":\"response.output_text.delta\",\"sequence_number\":2,\"delta\":\"llo\"}\n\n\
+data: {\"type\":\"response.completed\",\"sequence_number\":3,\"response\":{\"id\":\"resp_1\"}}\n\n\
+data: [DONE]\n\n";
+        ([(CONTENT_TYPE, "text/event-stream")], body.to_string())
+    }
+
+    let provider_app = AxumRouter::new()
+        .route("/v1/responses", post(create))
+        .route("/v1/responses/{id}", get(resume));
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    tokio::spawn(async move {
+        axum::serve(listener, provider_app).await.expect("serve");
+    });
+
+    let dir = tempdir().expect("tmp");
+    let log = EventLog::new(dir.path().join("events.jsonl")).expect("log");
+    let buffer = Arc::new(Mutex::new(Vec::new()));
+    let (sender, _) = broadcast::channel(64);
+    let sink = EventSink::new(&sender, &buffer, &log);
+
+    let registry = Arc::new(rip_tools::ToolRegistry::default());
+    let tool_runner = ToolRunner::new(registry, 1);
+    let workspace_lock = crate::workspace_lock::WorkspaceLock::new();
+    let continuity_workspace = dir.path().join("workspace");
+    std::fs::create_dir_all(&continuity_workspace).expect("workspace");
+    let continuity_log =
+        Arc::new(EventLog::new(dir.path().join("continuity_events.jsonl")).expect("log"));
+    let continuity_store = ContinuityStore::new(
+        dir.path().join("continuity_data"),
+        continuity_workspace,
+        continuity_log,
+    )
+    .expect("continuities");
+
+    let config = OpenResponsesConfig {
+        provider_id: None,
+        endpoint: format!("http://{addr}/v1/responses"),
+        api_key: None,
+        model: Some("fixture-model".to_string()),
+        headers: Vec::new(),
+        tool_choice: ToolChoiceParam::auto(),
+        include: Vec::new(),
+        reasoning: None,
+        web_search: None,
+        followup_user_message: None,
+        stateless_history: false,
+        parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
+    };
+    let mut seq = 0;
+    let http = reqwest::Client::new();
+    let outcome = run_openresponses_agent_loop(OpenResponsesRunContext {
+        http: &http,
+        config: &config,
+        tool_runner: &tool_runner,
+        workspace_lock: &workspace_lock,
+        continuities: &continuity_store,
+        continuity_run: None,
+        session_id: "s1",
+        initial_items: None,
+        prompt: "hi",
+        seq: &mut seq,
+        sink,
+        stream_resume_retries: 1,
+        request_dump: false,
+    })
+    .await;
+    assert_eq!(outcome.reason, "completed");
+    assert_eq!(outcome.last_response_id.as_deref(), Some("resp_1"));
+
+    let events = buffer.lock().await;
+    for (idx, event) in events.iter().enumerate() {
+        assert_eq!(event.seq, idx as u64, "expected contiguous seq values");
+    }
+    assert_eq!(seq, events.len() as u64);
+    let text: String = events
+        .iter()
+        .filter_map(|event| match &event.kind {
+            EventKind::OutputTextDelta { delta, .. } => Some(delta.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(text, "hello");
+    assert!(!events.iter().any(|event| matches!(
+        &event.kind,
+        EventKind::ProviderEvent { raw: None, data: None, errors, .. } if !errors.is_empty()
+    )));
+}
+
 #[tokio::test]
 async fn run_openresponses_agent_loop_emits_compat_warning_and_coerces_openrouter_to_stateless() {
     use axum::extract::{Json, State};
@@ -1377,6 +1528,7 @@ data: [DONE]\n\n";
         followup_user_message: None,
         stateless_history: false,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
 
     let mut seq = 0;
@@ -1393,6 +1545,8 @@ data: [DONE]\n\n";
         prompt: "hi",
         seq: &mut seq,
         sink,
+        stream_resume_retries: 0,
+        request_dump: false,
     })
     .await;
 
@@ -1558,6 +1712,7 @@ data: [DONE]\n\n";
         followup_user_message: None,
         stateless_history: true,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
     let mut seq = 0;
     let http = reqwest::Client::new();
@@ -1573,6 +1728,8 @@ data: [DONE]\n\n";
         prompt: "hi",
         seq: &mut seq,
         sink,
+        stream_resume_retries: 0,
+        request_dump: false,
     })
     .await;
 
@@ -1686,6 +1843,7 @@ data: [DONE]\n\n";
         followup_user_message: None,
         stateless_history: true,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
 
     let mut seq = 0;
@@ -1702,6 +1860,8 @@ data: [DONE]\n\n";
         prompt: "what happened today?",
         seq: &mut seq,
         sink,
+        stream_resume_retries: 0,
+        request_dump: false,
     })
     .await;
 
@@ -1826,6 +1986,7 @@ data: [DONE]\n\n";
         followup_user_message: None,
         stateless_history: false,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
     let mut seq = 0;
     let http = reqwest::Client::new();
@@ -1841,6 +2002,8 @@ data: [DONE]\n\n";
         prompt: "hi",
         seq: &mut seq,
         sink,
+        stream_resume_retries: 0,
+        request_dump: false,
     })
     .await;
     assert_eq!(outcome.reason, "provider_error");
@@ -1969,6 +2132,7 @@ data: [DONE]\n\n";
         followup_user_message: None,
         stateless_history: true,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
     let mut seq = 0;
     let http = reqwest::Client::new();
@@ -1984,6 +2148,8 @@ data: [DONE]\n\n";
         prompt: "hi",
         seq: &mut seq,
         sink,
+        stream_resume_retries: 0,
+        request_dump: false,
     })
     .await;
 
@@ -2100,6 +2266,7 @@ data: [DONE]\n\n";
         followup_user_message: None,
         stateless_history: false,
         parallel_tool_calls: false,
+        ..OpenResponsesConfig::default()
     };
     let mut seq = 0;
     let http = reqwest::Client::new();
@@ -2115,6 +2282,8 @@ data: [DONE]\n\n";
         prompt: "hi",
         seq: &mut seq,
         sink,
+        stream_resume_retries: 0,
+        request_dump: false,
     })
     .await;
     assert_eq!(outcome.reason, "completed");
@@ -2161,6 +2330,9 @@ async fn run_session_with_tool_invocation_emits_events() {
         continuities,
         continuity_run: None,
         server_session_id: "s1".to_string(),
+        preamble: Vec::new(),
+        stream_resume_retries: 0,
+        request_dump: false,
         input: "{\"tool\":\"noop\",\"args\":{}}".to_string(),
     };
 
@@ -2206,6 +2378,9 @@ async fn run_session_with_prompt_and_no_provider_uses_runtime_loop() {
         continuities,
         continuity_run: None,
         server_session_id: "s1".to_string(),
+        preamble: Vec::new(),
+        stream_resume_retries: 0,
+        request_dump: false,
         input: "hello".to_string(),
     };
 
@@ -2261,6 +2436,7 @@ async fn run_session_ends_when_context_compile_fails_before_provider_loop() {
             followup_user_message: None,
             stateless_history: false,
             parallel_tool_calls: false,
+            ..OpenResponsesConfig::default()
         }),
         sender,
         events: events.clone(),
@@ -2274,6 +2450,9 @@ async fn run_session_ends_when_context_compile_fails_before_provider_loop() {
             origin: "cli".to_string(),
         }),
         server_session_id: "s1".to_string(),
+        preamble: Vec::new(),
+        stream_resume_retries: 0,
+        request_dump: false,
         input: "hello".to_string(),
     };
 
@@ -183,7 +183,10 @@ impl TaskLogWriter {
         }))
     }
 
-    pub(super) fn finish(self) -> TaskLogSummary {
+    /// Flushes pending writes so readers see every stored byte, then reports
+    /// the totals.
+    pub(super) async fn finish(mut self) -> TaskLogSummary {
+        let _ = self.file.flush().await;
         TaskLogSummary {
             artifact_id: self.artifact_id,
             path: self.rel_path,
@@ -275,6 +278,42 @@ pub(super) fn read_artifact_range(
     Ok((content, used_bytes, total_bytes, truncated))
 }
 
+/// Offset of the last `tail_bytes` bytes of an artifact, nudged forward past
+/// any UTF-8 continuation bytes so the read never starts mid-character.
+pub(super) fn artifact_tail_offset(
+    config: &TaskEngineConfig,
+    id: &str,
+    tail_bytes: u64,
+) -> Result<u64, String> {
+    if !is_lower_hex_64(id) {
+        return Err("invalid artifact id".to_string());
+    }
+
+    let path = config.artifacts_blobs_dir().join(id);
+    let meta =
+        std::fs::metadata(&path).map_err(|err| format!("read artifact meta failed: {err}"))?;
+    let total_bytes = meta.len();
+    let offset = total_bytes.saturating_sub(tail_bytes);
+    if offset == 0 {
+        return Ok(0);
+    }
+
+    let mut file =
+        std::fs::File::open(&path).map_err(|err| format!("read artifact failed: {err}"))?;
+    use std::io::{Read, Seek};
+    file.seek(std::io::SeekFrom::Start(offset))
+        .map_err(|err| format!("read artifact failed: {err}"))?;
+    let mut buf = [0u8; 3];
+    let read_bytes = file
+        .read(&mut buf)
+        .map_err(|err| format!("read artifact failed: {err}"))?;
+    let skip = buf[..read_bytes]
+        .iter()
+        .take_while(|b| (**b & 0xC0) == 0x80)
+        .count();
+    Ok(offset + skip as u64)
+}
+
 pub(super) fn is_lower_hex_64(value: &str) -> bool {
     if value.len() != 64 {
         return false;
@@ -467,8 +506,7 @@ mod tests {
             Some(true)
         );
 
-        writer.file.flush().await.expect("flush artifact");
-        let summary = writer.finish();
+        let summary = writer.finish().await;
         let summary_json = summary.as_json();
         assert_eq!(
             summary_json
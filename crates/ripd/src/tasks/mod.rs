@@ -9,7 +9,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use rip_kernel::{Event, EventKind, ToolTaskExecutionMode, ToolTaskStatus};
+use rip_kernel::{Event, EventKind, ToolTaskExecutionMode, ToolTaskSpawnedBuilder, ToolTaskStatus};
 use rip_log::{write_snapshot, EventLog};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -17,7 +17,7 @@ use tokio::sync::{broadcast, mpsc, watch, Mutex, RwLock};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use self::logs::{base64_decode, read_artifact_range, TaskLogs};
+use self::logs::{artifact_tail_offset, base64_decode, read_artifact_range, TaskLogs};
 use crate::workspace_lock::WorkspaceLock;
 
 const EVENT_CHANNEL_CAPACITY: usize = 16_384;
@@ -79,6 +79,8 @@ pub(crate) struct TaskOutputQuery {
     pub(crate) stream: TaskOutputStream,
     pub(crate) offset_bytes: Option<u64>,
     pub(crate) max_bytes: Option<usize>,
+    /// Read the final N bytes of the stream instead of starting at `offset_bytes`.
+    pub(crate) tail_bytes: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -117,6 +119,7 @@ pub(crate) struct TaskHandle {
 #[derive(Debug)]
 enum TaskControl {
     WriteStdin { chunk_b64: String, bytes: Vec<u8> },
+    CloseStdin,
     Resize { rows: u16, cols: u16 },
     Signal { signal: String },
 }
@@ -164,6 +167,23 @@ impl TaskHandle {
         })
     }
 
+    /// Like [`Self::output`], but starts at the last `tail_bytes` bytes of the
+    /// stream; the response's `offset_bytes` is the UTF-8-aligned offset used.
+    pub(crate) async fn output_tail(
+        &self,
+        config: &TaskEngineConfig,
+        stream: TaskOutputStream,
+        tail_bytes: u64,
+        max_bytes: usize,
+    ) -> Result<TaskOutputResponse, String> {
+        let log = self
+            .logs
+            .log_for_output(stream)
+            .ok_or_else(|| "output stream not available for this task".to_string())?;
+        let offset_bytes = artifact_tail_offset(config, &log.artifact_id, tail_bytes)?;
+        self.output(config, stream, offset_bytes, max_bytes).await
+    }
+
     pub(crate) async fn write_stdin(&self, payload: TaskWriteStdinPayload) -> Result<(), String> {
         if self.status.read().await.execution_mode != ApiToolTaskExecutionMode::Pty {
             return Err("write_stdin is only supported for pty tasks".to_string());
@@ -190,6 +210,21 @@ impl TaskHandle {
             .map_err(|_| "task is no longer accepting stdin".to_string())
     }
 
+    /// Closes the child's stdin so its reads see EOF; supported in both
+    /// execution modes.
+    pub(crate) async fn close_stdin(&self) -> Result<(), String> {
+        let sender = self
+            .control_tx
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| "task is not ready for interactive IO".to_string())?;
+        sender
+            .send(TaskControl::CloseStdin)
+            .await
+            .map_err(|_| "task is no longer accepting stdin".to_string())
+    }
+
     pub(crate) async fn resize(&self, payload: TaskResizePayload) -> Result<(), String> {
         if self.status.read().await.execution_mode != ApiToolTaskExecutionMode::Pty {
             return Err("resize is only supported for pty tasks".to_string());
@@ -344,6 +379,9 @@ struct ShellArgs {
     max_bytes: Option<usize>,
     rows: Option<u16>,
     cols: Option<u16>,
+    /// Pipes mode: keep stdin open as a pipe until `POST /tasks/{id}/stdin/close`
+    /// instead of giving the child an immediate EOF.
+    interactive_stdin: Option<bool>,
 }
 
 pub(super) struct TaskRunContext {
@@ -409,22 +447,27 @@ async fn run_task(
     }
 
     let spawn_time_ms = now_ms();
-    emitter
-        .emit(EventKind::ToolTaskSpawned {
-            task_id: handle.task_id.clone(),
-            tool_name: payload.tool.clone(),
-            args: payload.args.clone(),
-            cwd: args.cwd.clone(),
-            title: payload.title.clone(),
-            execution_mode,
-            origin_session_id: payload.origin_session_id.clone(),
-            artifacts: Some(json!({
-                "logs": handle.logs.refs_json(),
-                "artifact_max_bytes": artifact_max_bytes,
-                "max_bytes": max_bytes,
-            })),
-        })
-        .await;
+    let mut spawned = ToolTaskSpawnedBuilder::new(
+        handle.task_id.clone(),
+        payload.tool.clone(),
+        payload.args.clone(),
+    )
+    .execution_mode(execution_mode)
+    .artifacts(json!({
+        "logs": handle.logs.refs_json(),
+        "artifact_max_bytes": artifact_max_bytes,
+        "max_bytes": max_bytes,
+    }));
+    if let Some(cwd) = args.cwd.clone() {
+        spawned = spawned.cwd(cwd);
+    }
+    if let Some(title) = payload.title.clone() {
+        spawned = spawned.title(title);
+    }
+    if let Some(origin_session_id) = payload.origin_session_id.clone() {
+        spawned = spawned.origin_session_id(origin_session_id);
+    }
+    emitter.emit(spawned.build()).await;
 
     let _workspace_guard = workspace_lock.acquire().await;
     match execution_mode {
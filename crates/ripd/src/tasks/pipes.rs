@@ -4,7 +4,9 @@ use tokio::io::AsyncReadExt;
 use tokio::process::Command;
 
 use super::logs::{resolve_path, TaskLogSummary, TaskLogWriter};
-use super::{fail_task, now_ms, ApiToolTaskStatus, TaskEmitter, TaskHandle, TaskRunContext};
+use super::{
+    fail_task, now_ms, ApiToolTaskStatus, TaskControl, TaskEmitter, TaskHandle, TaskRunContext,
+};
 
 #[cfg(unix)]
 fn kill_process_group(pid: u32) {
@@ -81,6 +83,14 @@ pub(super) async fn run_pipes_task(handle: &TaskHandle, ctx: TaskRunContext) {
     let (program, program_args) = super::resolve_shell_program(&args.command);
     let mut cmd = Command::new(program);
     cmd.args(program_args);
+    // Stdin stays null (immediate EOF) unless the task opted into
+    // `interactive_stdin`, which keeps it open until `CloseStdin`.
+    let interactive_stdin = args.interactive_stdin.unwrap_or(false);
+    cmd.stdin(if interactive_stdin {
+        std::process::Stdio::piped()
+    } else {
+        std::process::Stdio::null()
+    });
     cmd.stdout(std::process::Stdio::piped());
     cmd.stderr(std::process::Stdio::piped());
     #[cfg(unix)]
@@ -107,6 +117,18 @@ pub(super) async fn run_pipes_task(handle: &TaskHandle, ctx: TaskRunContext) {
         }
     };
 
+    // Pipes tasks only take `CloseStdin`; writes, resizes and signals are
+    // rejected for this mode before they reach the channel. Without
+    // `interactive_stdin` no channel is installed, so close requests fail.
+    let mut stdin = child.stdin.take();
+    let (control_tx, mut control_rx) = tokio::sync::mpsc::channel::<TaskControl>(16);
+    if interactive_stdin {
+        let mut guard = handle.control_tx.lock().await;
+        *guard = Some(control_tx);
+    } else {
+        drop(control_tx);
+    }
+
     {
         let mut status = handle.status.write().await;
         status.status = ApiToolTaskStatus::Running;
@@ -139,7 +161,7 @@ pub(super) async fn run_pipes_task(handle: &TaskHandle, ctx: TaskRunContext) {
             max_bytes,
         )
         .await;
-        stdout_writer.finish()
+        stdout_writer.finish().await
     });
 
     let stderr_emitter = emitter.clone();
@@ -154,25 +176,41 @@ pub(super) async fn run_pipes_task(handle: &TaskHandle, ctx: TaskRunContext) {
             max_bytes,
         )
         .await;
-        stderr_writer.finish()
+        stderr_writer.finish().await
     });
 
     let mut cancel_reason: Option<String> = None;
-    let exit_status = tokio::select! {
-        status = child.wait() => status,
-        _ = cancel_rx.changed() => {
-            cancel_reason = cancel_rx.borrow().clone();
-            if let Some(reason) = cancel_reason.as_deref() {
-                emitter.emit(EventKind::ToolTaskCancelRequested { task_id: handle.task_id.clone(), reason: reason.to_string() }).await;
+    let exit_status = loop {
+        tokio::select! {
+            status = child.wait() => break status,
+            _ = cancel_rx.changed() => {
+                cancel_reason = cancel_rx.borrow().clone();
+                if let Some(reason) = cancel_reason.as_deref() {
+                    emitter.emit(EventKind::ToolTaskCancelRequested { task_id: handle.task_id.clone(), reason: reason.to_string() }).await;
+                }
+                #[cfg(unix)]
+                if let Some(pid) = child.id() {
+                    kill_process_group(pid);
+                }
+                let _ = child.start_kill();
+                break child.wait().await;
             }
-            #[cfg(unix)]
-            if let Some(pid) = child.id() {
-                kill_process_group(pid);
+            Some(message) = control_rx.recv() => {
+                if matches!(message, TaskControl::CloseStdin) && stdin.take().is_some() {
+                    emitter
+                        .emit(EventKind::ToolTaskStdinClosed {
+                            task_id: handle.task_id.clone(),
+                        })
+                        .await;
+                }
             }
-            let _ = child.start_kill();
-            child.wait().await
         }
     };
+    {
+        let mut guard = handle.control_tx.lock().await;
+        guard.take();
+    }
+    drop(stdin);
 
     let stdout_summary = stdout_handle.await.unwrap_or_else(|_| {
         TaskLogSummary::failed(
@@ -97,7 +97,7 @@ pub(super) async fn run_pty_task(handle: &TaskHandle, ctx: TaskRunContext) {
         }
     };
     let writer = match pair.master.take_writer() {
-        Ok(writer) => Arc::new(StdMutex::new(writer)),
+        Ok(writer) => Arc::new(StdMutex::new(PtyStdin::new(writer))),
         Err(err) => {
             fail_task(handle, &emitter, format!("pty writer failed: {err}")).await;
             return;
@@ -228,7 +228,7 @@ pub(super) async fn run_pty_task(handle: &TaskHandle, ctx: TaskRunContext) {
     };
 
     let pty_summary = match output_join {
-        Ok(_) => pty_writer.finish(),
+        Ok(_) => pty_writer.finish().await,
         Err(_) => TaskLogSummary::failed(
             pty_log.artifact_id.clone(),
             pty_log.path.clone(),
@@ -316,24 +316,92 @@ async fn emit_output(
         .await;
 }
 
+/// The pty master's writer. Closing stdin sends EOF but keeps the writer, so
+/// Ctrl-C/Ctrl-\ signals (which go through it) still apply afterwards.
+struct PtyStdin {
+    writer: Box<dyn Write + Send>,
+    closed: bool,
+    /// Whether the last byte written ended a line; the line discipline only
+    /// reads EOT as EOF at the start of a line.
+    at_line_start: bool,
+}
+
+type SharedPtyStdin = Arc<StdMutex<PtyStdin>>;
+
+impl PtyStdin {
+    fn new(writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            writer,
+            closed: false,
+            at_line_start: true,
+        }
+    }
+
+    fn write_raw(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        self.writer.write_all(bytes)?;
+        self.writer.flush()
+    }
+
+    fn write_input(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        if self.closed {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "stdin closed",
+            ));
+        }
+        self.write_raw(bytes)?;
+        if let Some(last) = bytes.last() {
+            self.at_line_start = matches!(last, b'\n' | b'\r');
+        }
+        Ok(())
+    }
+
+    /// Sends EOT so the child's next read sees EOF; a pending partial line
+    /// needs a first EOT to flush it. The master stays open so output keeps
+    /// flowing.
+    fn close(&mut self) -> std::io::Result<()> {
+        if self.closed {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "stdin closed",
+            ));
+        }
+        let eot: &[u8] = if self.at_line_start {
+            &[0x04]
+        } else {
+            &[0x04, 0x04]
+        };
+        self.write_raw(eot)?;
+        self.closed = true;
+        Ok(())
+    }
+}
+
+/// Runs `op` on the pty stdin off the async runtime; `true` when it succeeded.
+async fn with_pty_stdin(
+    stdin: &SharedPtyStdin,
+    op: impl FnOnce(&mut PtyStdin) -> std::io::Result<()> + Send + 'static,
+) -> bool {
+    let stdin = stdin.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        let mut guard = stdin.lock().expect("stdin writer lock");
+        op(&mut guard)
+    })
+    .await;
+    matches!(result, Ok(Ok(())))
+}
+
 async fn handle_control(
     task_id: &str,
     emitter: &TaskEmitter,
-    stdin: &Arc<StdMutex<Box<dyn Write + Send>>>,
+    stdin: &SharedPtyStdin,
     killer: &Arc<StdMutex<Box<dyn portable_pty::ChildKiller + Send + Sync>>>,
     master: &mut Box<dyn portable_pty::MasterPty + Send>,
     message: TaskControl,
 ) {
     match message {
         TaskControl::WriteStdin { chunk_b64, bytes } => {
-            let stdin = stdin.clone();
-            let write_result = tokio::task::spawn_blocking(move || {
-                let mut guard = stdin.lock().expect("stdin writer lock");
-                guard.write_all(&bytes).and_then(|_| guard.flush())
-            })
-            .await;
-            let wrote = matches!(write_result, Ok(Ok(())));
-            if wrote {
+            if with_pty_stdin(stdin, move |stdin| stdin.write_input(&bytes)).await {
                 emitter
                     .emit(EventKind::ToolTaskStdinWritten {
                         task_id: task_id.to_string(),
@@ -342,6 +410,15 @@ async fn handle_control(
                     .await;
             }
         }
+        TaskControl::CloseStdin => {
+            if with_pty_stdin(stdin, PtyStdin::close).await {
+                emitter
+                    .emit(EventKind::ToolTaskStdinClosed {
+                        task_id: task_id.to_string(),
+                    })
+                    .await;
+            }
+        }
         TaskControl::Resize { rows, cols } => {
             let resized = master
                 .resize(PtySize {
@@ -369,22 +446,10 @@ async fn handle_control(
 
             let applied = match action {
                 SignalAction::CtrlC => {
-                    let stdin = stdin.clone();
-                    let write_result = tokio::task::spawn_blocking(move || {
-                        let mut guard = stdin.lock().expect("stdin writer lock");
-                        guard.write_all(&[0x03]).and_then(|_| guard.flush())
-                    })
-                    .await;
-                    matches!(write_result, Ok(Ok(())))
+                    with_pty_stdin(stdin, |stdin| stdin.write_raw(&[0x03])).await
                 }
                 SignalAction::CtrlBackslash => {
-                    let stdin = stdin.clone();
-                    let write_result = tokio::task::spawn_blocking(move || {
-                        let mut guard = stdin.lock().expect("stdin writer lock");
-                        guard.write_all(&[0x1c]).and_then(|_| guard.flush())
-                    })
-                    .await;
-                    matches!(write_result, Ok(Ok(())))
+                    with_pty_stdin(stdin, |stdin| stdin.write_raw(&[0x1c])).await
                 }
                 SignalAction::Kill => {
                     let killer = killer.clone();
@@ -502,6 +567,7 @@ mod tests {
             max_bytes: None,
             rows: None,
             cols: None,
+            interactive_stdin: None,
         };
         let (_cancel_tx, cancel_rx) = tokio::sync::watch::channel(None);
 
@@ -575,6 +641,7 @@ mod tests {
             max_bytes: None,
             rows: None,
             cols: None,
+            interactive_stdin: None,
         };
         let (_cancel_tx, cancel_rx) = tokio::sync::watch::channel(None);
 